@@ -0,0 +1,322 @@
+//! Allocation of a Money across several parts without losing any minor units.
+//!
+//! Splitting 100 USD three ways can't produce three equal parts, since
+//! 33.33 * 3 is only 99.99. Allocation computes each part's share rounded
+//! down to the currency's minor units, and then distributes the leftover
+//! minor units one at a time, so the parts always sum exactly to the original.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_decimal::Decimal;
+//! use doubloon::{Money, iso_currencies::USD, allocation::AllocationStrategy};
+//!
+//! let parts = Money::new(100, USD).split(3)?;
+//! assert_eq!(
+//!     parts,
+//!     vec![
+//!         Money::new(Decimal::new(3334, 2), USD),
+//!         Money::new(Decimal::new(3333, 2), USD),
+//!         Money::new(Decimal::new(3333, 2), USD),
+//!     ]
+//! );
+//!
+//! // split an invoice 70/30
+//! let parts = Money::new(Decimal::new(1001, 2), USD)
+//!     .allocate([70, 30], AllocationStrategy::LargestRemainder)?;
+//! assert_eq!(
+//!     parts,
+//!     vec![
+//!         Money::new(Decimal::new(701, 2), USD),
+//!         Money::new(Decimal::new(300, 2), USD),
+//!     ]
+//! );
+//! # Ok(())
+//! # }
+//! ```
+use rust_decimal::{Decimal, RoundingStrategy};
+use thiserror::Error;
+
+use crate::{MinorUnits, Money};
+
+/// Strategies for distributing the minor units left over after
+/// each part's share has been rounded down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AllocationStrategy {
+    /// Gives one leftover minor unit each to the parts whose shares
+    /// lost the most when rounded down, with ties going to the earlier
+    /// part. Also known as the Hamilton method.
+    #[default]
+    LargestRemainder,
+    /// Gives one leftover minor unit each to the first parts,
+    /// in order, skipping those with a zero weight.
+    FirstN,
+}
+
+/// Errors that can occur when allocating a Money.
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum AllocationError {
+    #[error("at least one weight is required")]
+    NoWeights,
+    #[error("weights may not be negative")]
+    NegativeWeight,
+    #[error("the sum of the weights must be greater than zero")]
+    ZeroTotalWeight,
+    #[error("the amount is too large to allocate with these weights")]
+    Overflow,
+}
+
+/// Allocation methods, for both statically and dynamically-typed currencies.
+impl<C> Money<C>
+where
+    C: MinorUnits + Copy,
+{
+    /// Allocates this Money across parts in proportion to the provided weights,
+    /// which can be integers or Decimals. The parts are rounded to the currency's
+    /// minor units (or the amount's precision, if that is greater), and any
+    /// leftover minor units are distributed according to the strategy, so
+    /// the parts always sum exactly to this amount. Negative amounts are
+    /// allocated as if positive, and then each part is negated.
+    pub fn allocate<I, W>(
+        &self,
+        weights: I,
+        strategy: AllocationStrategy,
+    ) -> Result<Vec<Self>, AllocationError>
+    where
+        I: IntoIterator<Item = W>,
+        W: Into<Decimal>,
+    {
+        let weights: Vec<Decimal> = weights.into_iter().map(Into::into).collect();
+        if weights.is_empty() {
+            return Err(AllocationError::NoWeights);
+        }
+        if weights.iter().any(|w| w.is_sign_negative() && !w.is_zero()) {
+            return Err(AllocationError::NegativeWeight);
+        }
+        let total_weight = weights
+            .iter()
+            .try_fold(Decimal::ZERO, |sum, w| sum.checked_add(*w))
+            .ok_or(AllocationError::Overflow)?;
+        if total_weight.is_zero() {
+            return Err(AllocationError::ZeroTotalWeight);
+        }
+
+        let scale = self.currency.minor_units().max(self.amount.scale());
+        let unit = Decimal::new(1, scale);
+        let total = self.amount.abs();
+
+        let mut parts = Vec::with_capacity(weights.len());
+        let mut remainders = Vec::with_capacity(weights.len());
+        for weight in weights.iter() {
+            let share = total
+                .checked_mul(*weight)
+                .and_then(|product| product.checked_div(total_weight))
+                .ok_or(AllocationError::Overflow)?;
+            let part = share.round_dp_with_strategy(scale, RoundingStrategy::ToZero);
+            parts.push(part);
+            remainders.push(share - part);
+        }
+
+        let allocated: Decimal = parts.iter().sum();
+        let leftover_units = ((total - allocated) / unit).trunc();
+
+        let mut recipients: Vec<usize> = (0..parts.len())
+            .filter(|index| !weights[*index].is_zero())
+            .collect();
+        if strategy == AllocationStrategy::LargestRemainder {
+            // stable sort, so ties stay in their original order
+            recipients.sort_by(|a, b| remainders[*b].cmp(&remainders[*a]));
+        }
+        let mut leftover = leftover_units;
+        for index in recipients.into_iter().cycle() {
+            if leftover <= Decimal::ZERO {
+                break;
+            }
+            parts[index] += unit;
+            leftover -= Decimal::ONE;
+        }
+
+        let negate = self.amount.is_sign_negative();
+        Ok(parts
+            .into_iter()
+            .map(|part| Money::new(if negate { -part } else { part }, self.currency))
+            .collect())
+    }
+
+    /// Splits this Money into the specified number of parts that are as
+    /// even as possible. Leftover minor units go to the first parts.
+    pub fn split(&self, parts: usize) -> Result<Vec<Self>, AllocationError> {
+        self.allocate(vec![1; parts], AllocationStrategy::FirstN)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::allocation::*;
+    use crate::iso_currencies::{JPY, USD};
+    use crate::Currency;
+
+    fn sum<C: MinorUnits + Copy>(parts: &[Money<C>]) -> Decimal {
+        parts.iter().map(|m| m.amount()).sum()
+    }
+
+    #[test]
+    fn split_evenly() {
+        let parts = Money::new(100, USD).split(3).unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                Money::new(Decimal::new(3334, 2), USD),
+                Money::new(Decimal::new(3333, 2), USD),
+                Money::new(Decimal::new(3333, 2), USD),
+            ]
+        );
+        assert_eq!(sum(&parts), Decimal::ONE_HUNDRED);
+    }
+
+    #[test]
+    fn split_zero_minor_units() {
+        let parts = Money::new(100, JPY).split(3).unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                Money::new(34, JPY),
+                Money::new(33, JPY),
+                Money::new(33, JPY)
+            ]
+        );
+    }
+
+    #[test]
+    fn split_into_zero_parts() {
+        assert_eq!(
+            Money::new(100, USD).split(0),
+            Err(AllocationError::NoWeights)
+        );
+    }
+
+    #[test]
+    fn allocate_strategies() {
+        let m = Money::new(Decimal::new(1, 2), USD);
+        assert_eq!(
+            m.allocate([1, 2], AllocationStrategy::LargestRemainder),
+            Ok(vec![
+                Money::new(Decimal::ZERO, USD),
+                Money::new(Decimal::new(1, 2), USD)
+            ])
+        );
+        assert_eq!(
+            m.allocate([1, 2], AllocationStrategy::FirstN),
+            Ok(vec![
+                Money::new(Decimal::new(1, 2), USD),
+                Money::new(Decimal::ZERO, USD)
+            ])
+        );
+    }
+
+    #[test]
+    fn allocate_decimal_weights() {
+        let m = Money::new(Decimal::new(1000, 2), USD);
+        let parts = m
+            .allocate(
+                [Decimal::new(5, 1), Decimal::new(25, 2), Decimal::new(25, 2)],
+                AllocationStrategy::LargestRemainder,
+            )
+            .unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                Money::new(5, USD),
+                Money::new(Decimal::new(250, 2), USD),
+                Money::new(Decimal::new(250, 2), USD),
+            ]
+        );
+    }
+
+    #[test]
+    fn allocate_zero_weight_gets_nothing() {
+        let parts = Money::new(Decimal::new(10, 2), USD)
+            .allocate([0, 1, 1, 1], AllocationStrategy::FirstN)
+            .unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                Money::new(Decimal::ZERO, USD),
+                Money::new(Decimal::new(4, 2), USD),
+                Money::new(Decimal::new(3, 2), USD),
+                Money::new(Decimal::new(3, 2), USD),
+            ]
+        );
+    }
+
+    #[test]
+    fn allocate_negative_amount() {
+        let parts = Money::new(-100, USD).split(3).unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                Money::new(Decimal::new(-3334, 2), USD),
+                Money::new(Decimal::new(-3333, 2), USD),
+                Money::new(Decimal::new(-3333, 2), USD),
+            ]
+        );
+    }
+
+    #[test]
+    fn allocate_keeps_extra_precision() {
+        // amounts with more precision than the minor units are allocated
+        // at that precision so the parts still sum exactly
+        let m = Money::new(Decimal::new(10001, 3), USD);
+        let parts = m.split(2).unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                Money::new(Decimal::new(5001, 3), USD),
+                Money::new(Decimal::new(5000, 3), USD),
+            ]
+        );
+        assert_eq!(sum(&parts), m.amount());
+    }
+
+    #[test]
+    fn allocate_sums_exactly() {
+        let m = Money::new(Decimal::new(123457, 2), USD);
+        for strategy in [
+            AllocationStrategy::LargestRemainder,
+            AllocationStrategy::FirstN,
+        ] {
+            let parts = m.allocate([7, 11, 13, 17, 19], strategy).unwrap();
+            assert_eq!(sum(&parts), m.amount());
+        }
+    }
+
+    #[test]
+    fn allocate_invalid_weights() {
+        let m = Money::new(100, USD);
+        assert_eq!(
+            m.allocate(Vec::<i32>::new(), AllocationStrategy::FirstN),
+            Err(AllocationError::NoWeights)
+        );
+        assert_eq!(
+            m.allocate([1, -1], AllocationStrategy::FirstN),
+            Err(AllocationError::NegativeWeight)
+        );
+        assert_eq!(
+            m.allocate([0, 0], AllocationStrategy::FirstN),
+            Err(AllocationError::ZeroTotalWeight)
+        );
+    }
+
+    #[test]
+    fn allocate_dynamic() {
+        let currency: &dyn Currency = &USD;
+        let parts = Money::new(100, currency).split(3).unwrap();
+        assert_eq!(
+            parts,
+            vec![
+                Money::new(Decimal::new(3334, 2), currency),
+                Money::new(Decimal::new(3333, 2), currency),
+                Money::new(Decimal::new(3333, 2), currency),
+            ]
+        );
+    }
+}
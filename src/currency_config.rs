@@ -0,0 +1,684 @@
+//! Loads currency definitions from a configuration file.
+//!
+//! Applications often need currencies that aren't in ISO 4217, or want to
+//! use a different number of minor units for an ISO currency. A
+//! [CurrencyConfig] reads those definitions from a TOML or JSON file at
+//! startup, validates them, and builds a [CurrencyMap] containing the ISO
+//! currencies along with the configured ones.
+//!
+//! Each entry has a `code`, `minor_units`, and optional `numeric_code` and
+//! `name`. Entries with an ISO code override that ISO currency, and may omit
+//! the `numeric_code`, keeping the name and other details of the ISO
+//! currency. All other entries must have a `numeric_code` that isn't used by
+//! an ISO currency.
+//!
+//! ```toml
+//! [[currencies]]
+//! code = "PTS"
+//! numeric_code = 9001
+//! minor_units = 0
+//! name = "Loyalty Points"
+//!
+//! # track yen to one decimal place
+//! [[currencies]]
+//! code = "JPY"
+//! minor_units = 1
+//! ```
+//!
+//! The same entries can be written in JSON as
+//! `{"currencies": [{"code": "PTS", "numeric_code": 9001, ...}]}`.
+//! Reading TOML requires the `toml` crate feature, and reading JSON
+//! requires the `json` crate feature. Other top-level keys, such as a
+//! JSON `$schema`, are ignored.
+//!
+//! A syntax error stops the file from being read, but otherwise all problems
+//! with the entries are reported together, each with its line number and the
+//! text of that line:
+//!
+//! ```rust
+//! # #[cfg(feature = "toml")] {
+//! use doubloon::{
+//!     Currency,
+//!     currency_config::{CurrencyConfig, ConfigError},
+//! };
+//!
+//! let config = CurrencyConfig::parse_toml(r#"
+//! [[currencies]]
+//! code = "PTS"
+//! numeric_code = 9001
+//! minor_units = 0
+//! "#).unwrap();
+//! let map = config.currency_map();
+//! assert_eq!(map.get("PTS").map(|c| c.numeric_code()), Some(9001));
+//! assert_eq!(map.get("USD").map(|c| c.minor_units()), Some(2));
+//!
+//! let Err(ConfigError::Invalid(issues)) = CurrencyConfig::parse_toml(r#"
+//! [[currencies]]
+//! code = "pts"
+//! numeric_code = 840
+//! minor_units = 0
+//! "#) else { panic!() };
+//! assert_eq!(issues.len(), 2);
+//! assert_eq!(issues[0].to_string(), r#"line 3: invalid code "pts" (`code = "pts"`)"#);
+//! # }
+//! ```
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    fs,
+    path::Path,
+};
+
+use thiserror::Error;
+
+use crate::{
+    currency_map::CurrencyMap,
+    custom_currency::CustomCurrency,
+    iso_currencies::{ALL_CURRENCIES, CURRENCY_MAP},
+    Currency,
+};
+
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "toml")]
+mod toml;
+
+/// The largest number of minor units that can be represented by a Decimal.
+const MAX_MINOR_UNITS: i64 = 28;
+
+/// A problem found in a currency configuration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    line: usize,
+    context: String,
+    message: String,
+}
+
+impl ConfigIssue {
+    fn new(contents: &str, line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            context: contents
+                .lines()
+                .nth(line.saturating_sub(1))
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Returns the line number on which the problem was found, starting from 1.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the text of the line on which the problem was found.
+    pub fn context(&self) -> &str {
+        &self.context
+    }
+
+    /// Returns a description of the problem.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Display for ConfigIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}: {} (`{}`)",
+            self.line, self.message, self.context
+        )
+    }
+}
+
+/// Finds the line numbers of byte offsets within the configuration file.
+struct LineIndex {
+    /// The byte offset at which each line starts.
+    starts: Vec<usize>,
+}
+
+impl LineIndex {
+    fn new(contents: &str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(contents.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { starts }
+    }
+
+    /// Returns the line containing the byte offset, starting from 1.
+    fn line(&self, offset: usize) -> usize {
+        self.starts.partition_point(|start| *start <= offset)
+    }
+}
+
+fn format_issues(issues: &[ConfigIssue]) -> String {
+    issues
+        .iter()
+        .map(ConfigIssue::to_string)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Errors that can occur when loading a currency configuration.
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum ConfigError {
+    #[error("unable to read currency configuration: {0}")]
+    Io(String),
+    #[error("unsupported currency configuration format {0:?}; expected toml or json")]
+    UnsupportedFormat(String),
+    #[error("invalid currency configuration:\n{}", format_issues(.0))]
+    Invalid(Vec<ConfigIssue>),
+}
+
+/// A value in a configuration entry.
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    String(String),
+    Integer(i64),
+    /// Any other kind of value, none of which are valid for these entries.
+    Other,
+}
+
+/// A `key = value` pair in a configuration entry.
+#[derive(Debug)]
+struct Field {
+    key: String,
+    value: Value,
+    line: usize,
+}
+
+/// A configuration entry as read from the file, before validation.
+#[derive(Debug)]
+struct Entry {
+    line: usize,
+    fields: Vec<Field>,
+}
+
+/// Currency definitions loaded from a configuration file.
+#[derive(Debug, Clone, Default)]
+pub struct CurrencyConfig {
+    currencies: Vec<CustomCurrency>,
+}
+
+impl CurrencyConfig {
+    /// Loads currency definitions from the file at the specified path.
+    /// The format is determined by the file's extension, which must be
+    /// either `toml` or `json`, with the crate feature of the same name enabled.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        let parse: fn(&str) -> Result<Self, ConfigError> = match extension.as_str() {
+            #[cfg(feature = "toml")]
+            "toml" => Self::parse_toml,
+            #[cfg(feature = "json")]
+            "json" => Self::parse_json,
+            _ => return Err(ConfigError::UnsupportedFormat(extension)),
+        };
+        let contents = fs::read_to_string(path).map_err(|e| ConfigError::Io(e.to_string()))?;
+        parse(&contents)
+    }
+
+    /// Loads currency definitions from TOML that has already been read into a string.
+    #[cfg(feature = "toml")]
+    pub fn parse_toml(contents: &str) -> Result<Self, ConfigError> {
+        let mut issues = Vec::new();
+        let entries = toml::parse(contents, &mut issues);
+        Self::validate(contents, entries, issues)
+    }
+
+    /// Loads currency definitions from JSON that has already been read into a string.
+    #[cfg(feature = "json")]
+    pub fn parse_json(contents: &str) -> Result<Self, ConfigError> {
+        let mut issues = Vec::new();
+        let entries = json::parse(contents, &mut issues);
+        Self::validate(contents, entries, issues)
+    }
+
+    /// Returns the configured currencies, in the order they appear in the file.
+    pub fn currencies(&self) -> &[CustomCurrency] {
+        &self.currencies
+    }
+
+    /// Returns a [CurrencyMap] containing all the ISO currencies, with
+    /// the configured currencies added or replacing the ISO ones.
+    pub fn currency_map(&self) -> CurrencyMap<'_> {
+        let mut map = CurrencyMap::from_collection(ALL_CURRENCIES.iter().copied());
+        for currency in &self.currencies {
+            map.insert(currency);
+        }
+        map
+    }
+
+    fn validate(
+        contents: &str,
+        entries: Vec<Entry>,
+        mut issues: Vec<ConfigIssue>,
+    ) -> Result<Self, ConfigError> {
+        let mut currencies = Vec::new();
+        let mut codes: HashMap<String, usize> = HashMap::new();
+        let mut numeric_codes: HashMap<u32, (String, usize)> = HashMap::new();
+
+        for entry in entries {
+            let issue_count = issues.len();
+            let mut issue = |line: usize, message: String| {
+                issues.push(ConfigIssue::new(contents, line, message));
+            };
+
+            let mut keys: Vec<String> = Vec::new();
+            let mut code = None;
+            let mut minor_units = None;
+            let mut numeric_code = None;
+            let mut name = None;
+            for field in entry.fields {
+                if keys.contains(&field.key) {
+                    issue(field.line, format!("duplicate key `{}`", field.key));
+                    continue;
+                }
+                keys.push(field.key.clone());
+                match (field.key.as_str(), field.value) {
+                    ("code", Value::String(value)) => code = Some((value, field.line)),
+                    ("name", Value::String(value)) => name = Some(value),
+                    ("minor_units", Value::Integer(value)) => {
+                        minor_units = Some((value, field.line))
+                    }
+                    ("numeric_code", Value::Integer(value)) => {
+                        numeric_code = Some((value, field.line))
+                    }
+                    ("code" | "name", _) => {
+                        issue(field.line, format!("`{}` must be a string", field.key))
+                    }
+                    ("minor_units" | "numeric_code", _) => {
+                        issue(field.line, format!("`{}` must be an integer", field.key))
+                    }
+                    (key, _) => issue(field.line, format!("unknown key `{key}`")),
+                }
+            }
+
+            let has_key = |key: &str| keys.iter().any(|k| k == key);
+            let Some((code, code_line)) = code else {
+                if !has_key("code") {
+                    issue(entry.line, "missing `code`".to_string());
+                }
+                continue;
+            };
+            if !is_valid_code(&code) {
+                issue(code_line, format!("invalid code {code:?}"));
+            }
+            match codes.get(&code) {
+                Some(first_line) => issue(
+                    code_line,
+                    format!("duplicate code {code:?}, first defined on line {first_line}"),
+                ),
+                None => {
+                    codes.insert(code.clone(), code_line);
+                }
+            }
+
+            let minor_units = match minor_units {
+                Some((value, _)) if (0..=MAX_MINOR_UNITS).contains(&value) => value as u32,
+                Some((value, line)) => {
+                    issue(
+                        line,
+                        format!("minor units must be between 0 and {MAX_MINOR_UNITS}, not {value}"),
+                    );
+                    0
+                }
+                None => {
+                    if !has_key("minor_units") {
+                        issue(entry.line, "missing `minor_units`".to_string());
+                    }
+                    0
+                }
+            };
+
+            let numeric_code = match (CURRENCY_MAP.get(&code), numeric_code) {
+                (Some(iso), None) => iso.numeric_code(),
+                (Some(iso), Some((value, line))) => {
+                    if value != iso.numeric_code() as i64 {
+                        issue(
+                            line,
+                            format!(
+                                "numeric code {value} does not match the ISO numeric code {} for {code}",
+                                iso.numeric_code()
+                            ),
+                        );
+                    }
+                    iso.numeric_code()
+                }
+                (None, None) => {
+                    if !has_key("numeric_code") {
+                        issue(entry.line, "missing `numeric_code`".to_string());
+                    }
+                    0
+                }
+                (None, Some((value, line))) => match u32::try_from(value) {
+                    Ok(value) => {
+                        if let Some(iso) = CURRENCY_MAP.get_by_numeric_code(value) {
+                            issue(
+                                line,
+                                format!(
+                                    "numeric code {value} is already used by the ISO currency {}",
+                                    iso.code()
+                                ),
+                            );
+                        }
+                        value
+                    }
+                    Err(_) => {
+                        issue(line, format!("invalid numeric code {value}"));
+                        0
+                    }
+                },
+            };
+            match numeric_codes.get(&numeric_code) {
+                Some((first_code, first_line)) if *first_code != code => issue(
+                    code_line,
+                    format!(
+                        "numeric code {numeric_code} is also used by {first_code} on line {first_line}"
+                    ),
+                ),
+                Some(_) => {}
+                None => {
+                    numeric_codes.insert(numeric_code, (code.clone(), code_line));
+                }
+            }
+
+            if issues.len() == issue_count {
+                // overrides keep the metadata of the ISO currency
+                let mut currency = CustomCurrency::new(code, minor_units, numeric_code);
+                if let Some(iso) = CURRENCY_MAP.get(currency.code()) {
+                    currency = currency
+                        .with_name(iso.name())
+                        .with_countries(iso.countries())
+                        .with_fund(iso.is_fund())
+                        .with_category(iso.category());
+                }
+                currencies.push(match name {
+                    Some(name) => currency.with_name(name),
+                    None => currency,
+                });
+            }
+        }
+
+        if issues.is_empty() {
+            Ok(Self { currencies })
+        } else {
+            issues.sort_by_key(|issue| issue.line);
+            Err(ConfigError::Invalid(issues))
+        }
+    }
+}
+
+/// Codes must be 3 to 8 uppercase ASCII letters or digits, starting with a letter.
+fn is_valid_code(code: &str) -> bool {
+    (3..=8).contains(&code.len())
+        && code.starts_with(|c: char| c.is_ascii_uppercase())
+        && code
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency_config::*;
+
+    #[cfg(feature = "toml")]
+    const TOML: &str = r#"
+# internal currencies
+[[currencies]]
+code = "PTS"
+numeric_code = 9_001
+minor_units = 0
+name = "Loyalty # Points"
+
+[[currencies]]
+code = "JPY"    # override
+minor_units = 1
+"#;
+
+    #[cfg(feature = "json")]
+    const JSON: &str = r#"{
+  "currencies": [
+    {"code": "PTS", "numeric_code": 9001, "minor_units": 0, "name": "Loyalty # Points"},
+    {
+      "code": "JPY",
+      "minor_units": 1
+    }
+  ]
+}"#;
+
+    fn check_valid(config: CurrencyConfig) {
+        let currencies = config.currencies();
+        assert_eq!(currencies.len(), 2);
+        assert_eq!(currencies[0].code(), "PTS");
+        assert_eq!(currencies[0].numeric_code(), 9001);
+        assert_eq!(currencies[0].minor_units(), 0);
+        assert_eq!(currencies[0].name(), "Loyalty # Points");
+        assert_eq!(currencies[1].code(), "JPY");
+        assert_eq!(currencies[1].numeric_code(), 392);
+        assert_eq!(currencies[1].name(), "Yen");
+        assert_eq!(currencies[1].countries(), &["JP"]);
+
+        let map = config.currency_map();
+        assert_eq!(map.len(), ALL_CURRENCIES.len() + 1);
+        assert_eq!(map.get("JPY").map(|c| c.minor_units()), Some(1));
+        assert_eq!(
+            map.get_by_numeric_code(392).map(|c| c.minor_units()),
+            Some(1)
+        );
+        assert_eq!(map.get_by_numeric_code(9001).map(|c| c.code()), Some("PTS"));
+        assert_eq!(map.get("USD").map(|c| c.minor_units()), Some(2));
+    }
+
+    fn issues(result: Result<CurrencyConfig, ConfigError>) -> Vec<String> {
+        match result {
+            Err(ConfigError::Invalid(issues)) => issues.iter().map(|i| i.to_string()).collect(),
+            other => panic!("expected invalid configuration, got {other:?}"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn parse_toml() {
+        check_valid(CurrencyConfig::parse_toml(TOML).unwrap());
+        assert!(CurrencyConfig::parse_toml("")
+            .unwrap()
+            .currencies()
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn parse_json() {
+        check_valid(CurrencyConfig::parse_json(JSON).unwrap());
+        assert!(CurrencyConfig::parse_json(r#"{"currencies": []}"#)
+            .unwrap()
+            .currencies()
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn toml_syntax() {
+        let toml = r#"
+title = "currencies"
+
+[[ currencies ]]
+'code' = 'PTS'
+numeric_code = 0x2329
+"minor_units" = 0
+name = '''Loyalty "Points"'''
+
+[[currencies]]
+code = "GEM"
+numeric_code = 9_002
+minor_units = 2
+extra.color = "green"
+"#;
+        assert_eq!(
+            issues(CurrencyConfig::parse_toml(toml)),
+            vec!["line 14: unknown key `extra` (`extra.color = \"green\"`)"]
+        );
+
+        let config =
+            CurrencyConfig::parse_toml(&toml.replace("extra.color = \"green\"", "")).unwrap();
+        let currencies = config.currencies();
+        assert_eq!(currencies[0].code(), "PTS");
+        assert_eq!(currencies[0].numeric_code(), 9001);
+        assert_eq!(currencies[0].name(), r#"Loyalty "Points""#);
+        assert_eq!(currencies[1].code(), "GEM");
+
+        let config = CurrencyConfig::parse_toml(
+            r#"currencies = [{ code = "PTS", numeric_code = 9001, minor_units = 0 }]"#,
+        )
+        .unwrap();
+        assert_eq!(config.currencies()[0].code(), "PTS");
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn json_syntax() {
+        let json = r#"{
+  "$schema": "https://example.com/currencies.schema.json",
+  "currencies": [
+    {
+      "code": "P\u0054S",
+      "numeric_code": 9.001e3,
+      "minor_units": 0,
+      "name": null
+    },
+    {"code": "GEM", "numeric_code": 9002, "minor_units": 2, "code": "GEM"}
+  ]
+}"#;
+        assert_eq!(
+            issues(CurrencyConfig::parse_json(json)),
+            vec![
+                r#"line 10: duplicate key `code` (`{"code": "GEM", "numeric_code": 9002, "minor_units": 2, "code": "GEM"}`)"#
+            ]
+        );
+
+        let config = CurrencyConfig::parse_json(&json.replace(r#", "code": "GEM"}"#, "}")).unwrap();
+        let currencies = config.currencies();
+        assert_eq!(currencies[0].code(), "PTS");
+        assert_eq!(currencies[0].numeric_code(), 9001);
+        assert_eq!(currencies[0].name(), "PTS");
+        assert_eq!(currencies[1].code(), "GEM");
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn validation() {
+        let toml = r#"
+[[currencies]]
+code = "pts"
+numeric_code = 9001
+minor_units = 29
+
+[[currencies]]
+code = "GEM"
+numeric_code = 840
+minor_units = 2
+color = "green"
+
+[[currencies]]
+code = "EUR"
+numeric_code = 999
+minor_units = 2
+
+[[currencies]]
+code = "GEM"
+numeric_code = 9001
+minor_units = "2"
+
+[[currencies]]
+numeric_code = 9003
+"#;
+        assert_eq!(
+            issues(CurrencyConfig::parse_toml(toml)),
+            vec![
+                r#"line 3: invalid code "pts" (`code = "pts"`)"#,
+                "line 5: minor units must be between 0 and 28, not 29 (`minor_units = 29`)",
+                "line 9: numeric code 840 is already used by the ISO currency USD (`numeric_code = 840`)",
+                r#"line 11: unknown key `color` (`color = "green"`)"#,
+                "line 15: numeric code 999 does not match the ISO numeric code 978 for EUR (`numeric_code = 999`)",
+                r#"line 19: duplicate code "GEM", first defined on line 8 (`code = "GEM"`)"#,
+                r#"line 19: numeric code 9001 is also used by pts on line 3 (`code = "GEM"`)"#,
+                r#"line 21: `minor_units` must be an integer (`minor_units = "2"`)"#,
+                "line 23: missing `code` (`[[currencies]]`)",
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn toml_syntax_errors() {
+        assert_eq!(
+            issues(CurrencyConfig::parse_toml(
+                "[[currencies]]\ncode = \"PTS\"\nminor_units = PTS\n"
+            )),
+            vec!["line 3: string values must be quoted, expected literal string (`minor_units = PTS`)"]
+        );
+        assert_eq!(
+            issues(CurrencyConfig::parse_toml(
+                "[[currencies]]\ncode = \"PTS\"\nnumeric_code = 9001\nminor_units = 1.5\n"
+            )),
+            vec!["line 4: `minor_units` must be an integer (`minor_units = 1.5`)"]
+        );
+        assert_eq!(
+            issues(CurrencyConfig::parse_toml(
+                "\n[currencies]\ncode = \"PTS\"\n"
+            )),
+            vec!["line 2: `currencies` must be an array of tables (`[currencies]`)"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn json_syntax_errors() {
+        let json = "{\"currencies\": [\n  {\"code\": \"PTS\", \"minor_units\": 1.}\n]}";
+        assert_eq!(
+            issues(CurrencyConfig::parse_json(json)),
+            vec![r#"line 2: invalid number (`{"code": "PTS", "minor_units": 1.}`)"#]
+        );
+        assert_eq!(
+            issues(CurrencyConfig::parse_json("[]")),
+            vec!["line 1: invalid type: sequence, expected an object (`[]`)"]
+        );
+        assert_eq!(
+            issues(CurrencyConfig::parse_json("{\n\"currencies\": {}\n}")),
+            vec!["line 2: `currencies` must be an array (`\"currencies\": {}`)"]
+        );
+        assert_eq!(
+            issues(CurrencyConfig::parse_json(
+                "{\"currencies\": [\n\"PTS\"\n]}"
+            )),
+            vec!["line 2: each currency must be an object (`\"PTS\"`)"]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "json")]
+    fn open() {
+        assert_eq!(
+            CurrencyConfig::open("currencies.yaml").unwrap_err(),
+            ConfigError::UnsupportedFormat("yaml".to_string())
+        );
+        assert!(matches!(
+            CurrencyConfig::open("does-not-exist.json"),
+            Err(ConfigError::Io(_))
+        ));
+
+        let path = std::env::temp_dir().join("doubloon-currency-config-test.json");
+        fs::write(&path, JSON).unwrap();
+        let config = CurrencyConfig::open(&path);
+        fs::remove_file(&path).unwrap();
+        check_valid(config.unwrap());
+    }
+}
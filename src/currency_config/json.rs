@@ -0,0 +1,129 @@
+//! Reads currency entries from JSON using `serde_json`.
+use std::fmt::Formatter;
+
+use serde::{
+    de::{MapAccess, Visitor},
+    Deserialize, Deserializer,
+};
+use serde_json::value::RawValue;
+
+use super::{ConfigIssue, Entry, Field, LineIndex, Value};
+
+/// Reads the entries in the `currencies` array of the top-level object.
+/// Other top-level keys are ignored. JSON can't be read past a syntax
+/// error, so the first one is added as an issue and no entries are returned.
+pub(super) fn parse(contents: &str, issues: &mut Vec<ConfigIssue>) -> Vec<Entry> {
+    let lines = LineIndex::new(contents);
+    let line = |raw: &RawValue| lines.line(offset(contents, raw.get()));
+    let mut issue = |line: usize, message: String| {
+        issues.push(ConfigIssue::new(contents, line, message));
+    };
+
+    let document = match serde_json::from_str::<Object>(contents) {
+        Ok(document) => document,
+        Err(e) => {
+            // the position is already reported as the line of the issue
+            let message = e.to_string();
+            let position = format!(" at line {} column {}", e.line(), e.column());
+            let message = message.strip_suffix(&position).unwrap_or(&message);
+            issue(e.line(), message.to_string());
+            return Vec::new();
+        }
+    };
+    let Some((_, currencies)) = document.0.into_iter().find(|(key, _)| key == "currencies") else {
+        return Vec::new();
+    };
+    let Ok(currencies) = serde_json::from_str::<Vec<&RawValue>>(currencies.get()) else {
+        issue(
+            line(currencies),
+            "`currencies` must be an array".to_string(),
+        );
+        return Vec::new();
+    };
+
+    let mut entries = Vec::new();
+    for currency in currencies {
+        let Ok(object) = serde_json::from_str::<Object>(currency.get()) else {
+            issue(
+                line(currency),
+                "each currency must be an object".to_string(),
+            );
+            continue;
+        };
+        let fields = object
+            .0
+            .into_iter()
+            .filter_map(|(key, raw)| {
+                Some(Field {
+                    value: value(raw)?,
+                    line: line(raw),
+                    key,
+                })
+            })
+            .collect();
+        entries.push(Entry {
+            line: line(currency),
+            fields,
+        });
+    }
+    entries
+}
+
+/// Returns the byte offset of `part`, which must have been borrowed from `contents`.
+fn offset(contents: &str, part: &str) -> usize {
+    part.as_ptr() as usize - contents.as_ptr() as usize
+}
+
+/// Converts a raw JSON value into a [Value], or None if it is `null`.
+/// Numbers written with a fraction or exponent are integers if they
+/// have no fractional part, since JSON doesn't distinguish them.
+fn value(raw: &RawValue) -> Option<Value> {
+    Some(match serde_json::from_str(raw.get()) {
+        Ok(serde_json::Value::Null) => return None,
+        Ok(serde_json::Value::String(value)) => Value::String(value),
+        Ok(serde_json::Value::Number(number)) => number
+            .as_i64()
+            .or_else(|| {
+                number
+                    .as_f64()
+                    .filter(|n| n.fract() == 0.0 && n.abs() < i64::MAX as f64)
+                    .map(|n| n as i64)
+            })
+            .map_or(Value::Other, Value::Integer),
+        _ => Value::Other,
+    })
+}
+
+/// A JSON object, with the raw text of each value so that its line can
+/// be found. Unlike a map, this keeps duplicate keys so they can be reported.
+struct Object<'a>(Vec<(String, &'a RawValue)>);
+
+impl<'de: 'a, 'a> Deserialize<'de> for Object<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ObjectVisitor)
+    }
+}
+
+struct ObjectVisitor;
+
+impl<'de> Visitor<'de> for ObjectVisitor {
+    type Value = Object<'de>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("an object")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut fields = Vec::new();
+        while let Some(field) = map.next_entry()? {
+            fields.push(field);
+        }
+        Ok(Object(fields))
+    }
+}
@@ -0,0 +1,81 @@
+//! Reads currency entries from TOML using `toml_edit`.
+use std::ops::Range;
+
+use toml_edit::Document;
+
+use super::{ConfigIssue, Entry, Field, LineIndex, Value};
+
+/// Reads the entries in the `currencies` array, which may be written as
+/// `[[currencies]]` tables or as an array of inline tables. Other top-level
+/// keys are ignored. TOML can't be read past a syntax error, so the first
+/// one is added as an issue and no entries are returned.
+pub(super) fn parse(contents: &str, issues: &mut Vec<ConfigIssue>) -> Vec<Entry> {
+    let lines = LineIndex::new(contents);
+    let line = |span: Option<Range<usize>>| span.map(|span| lines.line(span.start));
+    let mut issue = |line: usize, message: String| {
+        issues.push(ConfigIssue::new(contents, line, message));
+    };
+
+    let document = match Document::parse(contents) {
+        Ok(document) => document,
+        Err(e) => {
+            issue(line(e.span()).unwrap_or(1), e.message().trim().to_string());
+            return Vec::new();
+        }
+    };
+    let Some(currencies) = document.get("currencies") else {
+        return Vec::new();
+    };
+
+    let mut entries = Vec::new();
+    if let Some(tables) = currencies.as_array_of_tables() {
+        for table in tables {
+            let entry_line = line(table.span()).unwrap_or(1);
+            entries.push(Entry {
+                line: entry_line,
+                fields: table
+                    .iter()
+                    .map(|(key, item)| Field {
+                        key: key.to_string(),
+                        value: value(item.as_value()),
+                        line: line(item.span()).unwrap_or(entry_line),
+                    })
+                    .collect(),
+            });
+        }
+    } else if let Some(array) = currencies.as_array() {
+        for currency in array {
+            let entry_line = line(currency.span()).unwrap_or(1);
+            let Some(table) = currency.as_inline_table() else {
+                issue(entry_line, "each currency must be a table".to_string());
+                continue;
+            };
+            entries.push(Entry {
+                line: entry_line,
+                fields: table
+                    .iter()
+                    .map(|(key, item)| Field {
+                        key: key.to_string(),
+                        value: value(Some(item)),
+                        line: line(item.span()).unwrap_or(entry_line),
+                    })
+                    .collect(),
+            });
+        }
+    } else {
+        issue(
+            line(currencies.span()).unwrap_or(1),
+            "`currencies` must be an array of tables".to_string(),
+        );
+    }
+    entries
+}
+
+/// Converts a TOML value into a [Value]. Tables, which have no value, are [Value::Other].
+fn value(value: Option<&toml_edit::Value>) -> Value {
+    match value {
+        Some(toml_edit::Value::String(value)) => Value::String(value.value().clone()),
+        Some(toml_edit::Value::Integer(value)) => Value::Integer(*value.value()),
+        _ => Value::Other,
+    }
+}
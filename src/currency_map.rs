@@ -1,14 +1,20 @@
 //! Provides a simplified currency code to `&dyn Currency` lookup map.
-use std::collections::HashMap;
+use std::{collections::HashMap, hash::Hash};
 
 use crate::Currency;
 
 /// Provides a map from currency code to `&dyn Currency`.
 ///
 /// Use this to lookup currencies dynamically based on a provided code.
+/// Currencies can also be found by their ISO numeric code, or by
+/// their alphabetic code ignoring case.
 #[derive(Debug, Default)]
 pub struct CurrencyMap<'c> {
     map: HashMap<&'c str, &'c dyn Currency>,
+    /// The currencies with each numeric code, in the order they were inserted.
+    numeric: HashMap<u32, Vec<&'c dyn Currency>>,
+    /// The currencies with each lower-cased code, in the order they were inserted.
+    lowercase: HashMap<String, Vec<&'c dyn Currency>>,
 }
 
 impl<'c> CurrencyMap<'c> {
@@ -16,6 +22,8 @@ impl<'c> CurrencyMap<'c> {
     pub fn new() -> Self {
         CurrencyMap {
             map: HashMap::new(),
+            numeric: HashMap::new(),
+            lowercase: HashMap::new(),
         }
     }
 
@@ -25,20 +33,225 @@ impl<'c> CurrencyMap<'c> {
     where
         I: IntoIterator<Item = &'c dyn Currency>,
     {
-        CurrencyMap {
-            map: currencies.into_iter().map(|c| (c.code(), c)).collect(),
+        let mut map = CurrencyMap::new();
+        for currency in currencies {
+            map.insert(currency);
         }
+        map
     }
 
     /// Inserts a [Currency] into the map. If there was an existing
     /// entry already in the map for the same code, it will be returned.
+    /// If another currency has the same numeric code, lookups by that
+    /// numeric code will return the newly-inserted currency.
     pub fn insert(&mut self, currency: &'c dyn Currency) -> Option<&'c dyn Currency> {
-        self.map.insert(currency.code(), currency)
+        let existing = self.map.insert(currency.code(), currency);
+        if let Some(existing) = existing {
+            self.remove_from_indexes(existing);
+        }
+        self.numeric
+            .entry(currency.numeric_code())
+            .or_default()
+            .push(currency);
+        self.lowercase
+            .entry(currency.code().to_ascii_lowercase())
+            .or_default()
+            .push(currency);
+        existing
+    }
+
+    /// Removes the [Currency] with the provided `code` from the map,
+    /// returning it if it was in the map.
+    pub fn remove(&mut self, code: &str) -> Option<&'c dyn Currency> {
+        let removed = self.map.remove(code)?;
+        self.remove_from_indexes(removed);
+        Some(removed)
+    }
+
+    /// Removes the currency from the numeric and lower-cased code indexes,
+    /// leaving any other currencies with the same numeric or lower-cased code.
+    fn remove_from_indexes(&mut self, currency: &dyn Currency) {
+        fn remove<K: Eq + Hash>(
+            index: &mut HashMap<K, Vec<&dyn Currency>>,
+            key: K,
+            currency: &dyn Currency,
+        ) {
+            if let Some(currencies) = index.get_mut(&key) {
+                currencies.retain(|c| c.code() != currency.code());
+                if currencies.is_empty() {
+                    index.remove(&key);
+                }
+            }
+        }
+        remove(&mut self.numeric, currency.numeric_code(), currency);
+        remove(
+            &mut self.lowercase,
+            currency.code().to_ascii_lowercase(),
+            currency,
+        );
     }
 
     /// Returns the [Currency] associated with the provided `code`, or
     /// None if no currency matching that code exists in the map.
-    pub fn get(&self, code: &'c str) -> Option<&'c dyn Currency> {
+    pub fn get(&self, code: &str) -> Option<&'c dyn Currency> {
         self.map.get(code).copied()
     }
+
+    /// Returns the [Currency] whose code matches the provided `code`
+    /// ignoring ASCII case, so `"usd"` will find USD. An exact match is
+    /// preferred, and otherwise if several currencies have codes that only
+    /// differ by case, this returns the one that was inserted most recently.
+    pub fn get_ignore_case(&self, code: &str) -> Option<&'c dyn Currency> {
+        self.get(code).or_else(|| {
+            self.lowercase
+                .get(&code.to_ascii_lowercase())
+                .and_then(|currencies| currencies.last())
+                .copied()
+        })
+    }
+
+    /// Returns the [Currency] associated with the provided ISO numeric code
+    /// (e.g., 840 for USD), or None if no currency in the map has that code.
+    /// If several currencies have the same numeric code, this returns the
+    /// one that was inserted most recently.
+    pub fn get_by_numeric_code(&self, numeric_code: u32) -> Option<&'c dyn Currency> {
+        self.numeric
+            .get(&numeric_code)
+            .and_then(|currencies| currencies.last())
+            .copied()
+    }
+
+    /// Returns true if the map contains a currency with the provided `code`.
+    pub fn contains(&self, code: &str) -> bool {
+        self.map.contains_key(code)
+    }
+
+    /// Returns the number of currencies in the map.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the map contains no currencies.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns an iterator over the currencies in the map, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = &'c dyn Currency> + '_ {
+        self.map.values().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency_map::*;
+    use crate::iso_currencies::{EUR, JPY, USD};
+
+    fn currencies() -> CurrencyMap<'static> {
+        CurrencyMap::from_collection([&USD as &dyn Currency, &JPY])
+    }
+
+    #[test]
+    fn get() {
+        let map = currencies();
+        let code = String::from("USD");
+        assert_eq!(map.get(&code).map(|c| c.code()), Some("USD"));
+        assert_eq!(map.get("usd"), None);
+        assert_eq!(map.get("EUR"), None);
+        assert!(map.contains("JPY"));
+        assert!(!map.contains("EUR"));
+    }
+
+    #[test]
+    fn get_ignore_case() {
+        let map = currencies();
+        assert_eq!(map.get_ignore_case("usd").map(|c| c.code()), Some("USD"));
+        assert_eq!(map.get_ignore_case("jPy").map(|c| c.code()), Some("JPY"));
+        assert_eq!(map.get_ignore_case("eur"), None);
+    }
+
+    #[test]
+    fn get_by_numeric_code() {
+        let mut map = currencies();
+        assert_eq!(map.get_by_numeric_code(840).map(|c| c.code()), Some("USD"));
+        assert_eq!(map.get_by_numeric_code(392).map(|c| c.code()), Some("JPY"));
+        assert_eq!(map.get_by_numeric_code(978), None);
+
+        map.insert(&EUR);
+        assert_eq!(map.get_by_numeric_code(978).map(|c| c.code()), Some("EUR"));
+        map.remove("EUR");
+        assert_eq!(map.get_by_numeric_code(978), None);
+    }
+
+    /// A currency that shares its numeric code with other test currencies.
+    struct Points(&'static str);
+
+    impl Currency for Points {
+        fn code(&self) -> &str {
+            self.0
+        }
+
+        fn minor_units(&self) -> u32 {
+            0
+        }
+
+        fn numeric_code(&self) -> u32 {
+            999
+        }
+    }
+
+    #[test]
+    fn shared_numeric_code() {
+        let a = Points("AAA");
+        let b = Points("BBB");
+        let mut map = CurrencyMap::from_collection([&a as &dyn Currency, &b]);
+        assert_eq!(map.get_by_numeric_code(999).map(|c| c.code()), Some("BBB"));
+
+        map.remove("BBB");
+        assert_eq!(map.get_by_numeric_code(999).map(|c| c.code()), Some("AAA"));
+        map.insert(&b);
+        map.remove("AAA");
+        assert_eq!(map.get_by_numeric_code(999).map(|c| c.code()), Some("BBB"));
+        map.remove("BBB");
+        assert_eq!(map.get_by_numeric_code(999), None);
+    }
+
+    #[test]
+    fn get_ignore_case_mixed_case_codes() {
+        let lower = Points("pts");
+        let upper = Points("PTS");
+        let mut map = CurrencyMap::from_collection([&lower as &dyn Currency]);
+        assert_eq!(map.get_ignore_case("PTS").map(|c| c.code()), Some("pts"));
+
+        map.insert(&upper);
+        assert_eq!(map.get_ignore_case("pts").map(|c| c.code()), Some("pts"));
+        assert_eq!(map.get_ignore_case("Pts").map(|c| c.code()), Some("PTS"));
+
+        map.remove("PTS");
+        assert_eq!(map.get_ignore_case("Pts").map(|c| c.code()), Some("pts"));
+        map.remove("pts");
+        assert_eq!(map.get_ignore_case("Pts"), None);
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut map = CurrencyMap::new();
+        assert!(map.is_empty());
+        assert!(map.insert(&USD).is_none());
+        assert_eq!(map.insert(&USD).map(|c| c.code()), Some("USD"));
+        assert_eq!(map.len(), 1);
+        assert_eq!(map.get_by_numeric_code(840).map(|c| c.code()), Some("USD"));
+
+        assert_eq!(map.remove("USD").map(|c| c.code()), Some("USD"));
+        assert_eq!(map.remove("USD"), None);
+        assert!(map.is_empty());
+        assert_eq!(map.get_by_numeric_code(840), None);
+    }
+
+    #[test]
+    fn iter() {
+        let mut codes: Vec<&str> = currencies().iter().map(|c| c.code()).collect();
+        codes.sort();
+        assert_eq!(codes, vec!["JPY", "USD"]);
+    }
 }
@@ -0,0 +1,179 @@
+//! Currencies defined at runtime.
+//!
+//! The [iso_currencies](crate::iso_currencies) module covers the ISO 4217
+//! currencies, but applications often need others: loyalty points, in-game
+//! currencies, or crypto-assets whose codes are read from a config file or
+//! database. A [CustomCurrency] owns its code, so it can be built from data
+//! that is only known at runtime.
+//!
+//! ```rust
+//! use rust_decimal::Decimal;
+//! use doubloon::{
+//!     Money, Currency, MoneyMathError,
+//!     currency_map::CurrencyMap,
+//!     custom_currency::CustomCurrency,
+//!     iso_currencies::USD,
+//! };
+//!
+//! let code = String::from("PTS");
+//! let points = CustomCurrency::new(code, 0, 0);
+//! let map = CurrencyMap::from_collection([&points as &dyn Currency, &USD]);
+//!
+//! let pts = map.get("PTS").unwrap();
+//! let m = Money::new(100, pts) + Money::new(50, pts);
+//! assert_eq!(m, Ok(Money::new(150, pts)));
+//! assert_eq!(
+//!     Money::new(1, pts) + Money::new(1, &USD as &dyn Currency),
+//!     Err(MoneyMathError::IncompatibleCurrencies("PTS".to_string(), "USD".to_string()))
+//! );
+//! ```
+use std::borrow::Cow;
+
+use crate::{Currency, CurrencyCategory};
+
+/// A currency whose code, minor units and numeric code
+/// are provided at runtime.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CustomCurrency {
+    code: Cow<'static, str>,
+    minor_units: u32,
+    numeric_code: u32,
+    name: Option<Cow<'static, str>>,
+    countries: &'static [&'static str],
+    is_fund: bool,
+    category: CurrencyCategory,
+}
+
+impl CustomCurrency {
+    /// Constructs a new [CustomCurrency]. The `code` can be a `&'static str`
+    /// or an owned `String`. Currencies that have no ISO numeric code can use
+    /// zero, but should then not be looked up by numeric code.
+    pub fn new(code: impl Into<Cow<'static, str>>, minor_units: u32, numeric_code: u32) -> Self {
+        Self {
+            code: code.into(),
+            minor_units,
+            numeric_code,
+            name: None,
+            countries: &[],
+            is_fund: false,
+            category: CurrencyCategory::National,
+        }
+    }
+
+    /// Returns this currency with the specified English name.
+    pub fn with_name(self, name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: Some(name.into()),
+            ..self
+        }
+    }
+
+    /// Returns this currency with the specified ISO 3166 alpha-2 country codes.
+    pub fn with_countries(self, countries: &'static [&'static str]) -> Self {
+        Self { countries, ..self }
+    }
+
+    /// Returns this currency with the specified fund flag.
+    pub fn with_fund(self, is_fund: bool) -> Self {
+        Self { is_fund, ..self }
+    }
+
+    /// Returns this currency with the specified category.
+    pub fn with_category(self, category: CurrencyCategory) -> Self {
+        Self { category, ..self }
+    }
+}
+
+impl Currency for CustomCurrency {
+    fn code(&self) -> &str {
+        &self.code
+    }
+
+    fn minor_units(&self) -> u32 {
+        self.minor_units
+    }
+
+    fn numeric_code(&self) -> u32 {
+        self.numeric_code
+    }
+
+    fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.code)
+    }
+
+    fn countries(&self) -> &[&str] {
+        self.countries
+    }
+
+    fn is_fund(&self) -> bool {
+        self.is_fund
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        self.category
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use crate::currency_map::CurrencyMap;
+    use crate::custom_currency::*;
+    use crate::iso_currencies::USD;
+    use crate::{Money, MoneyMathError, RoundingStrategy};
+
+    #[test]
+    fn owned_code() {
+        let code = format!("{}{}", "BT", "C");
+        let btc = CustomCurrency::new(code, 8, 0);
+        assert_eq!(btc.code(), "BTC");
+        assert_eq!(btc.minor_units(), 8);
+        assert_eq!(btc.numeric_code(), 0);
+        assert_eq!(btc, CustomCurrency::new("BTC", 8, 0));
+        assert_eq!(btc.name(), "BTC");
+        assert_eq!(btc.countries(), &[] as &[&str]);
+        assert_eq!(btc.category(), CurrencyCategory::National);
+
+        let btc = btc
+            .with_name("Bitcoin")
+            .with_countries(&["SV"])
+            .with_fund(false)
+            .with_category(CurrencyCategory::Supranational);
+        assert_eq!(btc.name(), "Bitcoin");
+        assert_eq!(btc.countries(), &["SV"]);
+        assert!(!btc.is_fund());
+        assert_eq!(btc.category(), CurrencyCategory::Supranational);
+    }
+
+    #[test]
+    fn money() {
+        let btc = CustomCurrency::new(String::from("BTC"), 8, 0);
+        let currency: &dyn Currency = &btc;
+        let m = Money::new(Decimal::new(123456789, 9), currency);
+        assert_eq!(
+            m.round(RoundingStrategy::MidpointNearestEven),
+            Money::new(Decimal::new(12345679, 8), currency)
+        );
+        assert_eq!(m.to_string(), "0.123456789 BTC");
+        assert_eq!(
+            m + Money::new(Decimal::ONE, &USD as &dyn Currency),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "BTC".to_string(),
+                "USD".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn currency_map() {
+        let currencies = [
+            CustomCurrency::new(String::from("PTS"), 0, 9001),
+            CustomCurrency::new(String::from("GEM"), 2, 9002),
+        ];
+        let map = CurrencyMap::from_collection(currencies.iter().map(|c| c as &dyn Currency));
+        assert_eq!(map.get("PTS").map(|c| c.minor_units()), Some(0));
+        assert_eq!(map.get_ignore_case("gem").map(|c| c.code()), Some("GEM"));
+        assert_eq!(map.get_by_numeric_code(9001).map(|c| c.code()), Some("PTS"));
+    }
+}
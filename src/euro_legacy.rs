@@ -0,0 +1,301 @@
+//! Conversions between the euro and the legacy currencies it replaced.
+//!
+//! When a country adopts the euro, the rate between the euro and its legacy
+//! currency is irrevocably fixed, and EU regulation 1103/97 mandates how
+//! amounts must be converted:
+//!
+//! - rates are given as the number of legacy units per euro, to six
+//!   significant figures, and must not be rounded or truncated;
+//! - inverse rates must not be used, so legacy amounts are converted to
+//!   euros by dividing by the rate;
+//! - amounts are converted from one legacy currency to another through the
+//!   euro, with the intermediate euro amount rounded to no fewer than three
+//!   decimal places;
+//! - the converted amount is rounded to the minor units of its currency,
+//!   with halfway values rounded up.
+//!
+//! The [EuroLegacyConverter] applies these rules to Money in the legacy
+//! currencies defined in [iso_historic_currencies](crate::iso_historic_currencies).
+//! It deliberately doesn't implement [RateProvider](crate::rate_provider::RateProvider),
+//! as that would require inverse rates.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_decimal::Decimal;
+//! use doubloon::{
+//!     Money, Currency,
+//!     euro_legacy::{self, EuroLegacyConverter},
+//!     iso_currencies::EUR,
+//!     iso_historic_currencies::{DEM, FRF},
+//! };
+//!
+//! assert_eq!(euro_legacy::fixed_rate(&DEM), Some(Decimal::new(195583, 5)));
+//!
+//! let converter = EuroLegacyConverter::new();
+//! let dem = Money::new(100, DEM);
+//! assert_eq!(
+//!     converter.convert(dem.as_dyn(), &EUR)?,
+//!     Money::new(Decimal::new(5113, 2), &EUR as &dyn Currency)
+//! );
+//!
+//! // 100 DEM -> 51.129 EUR -> 335.38 FRF
+//! assert_eq!(
+//!     converter.convert(dem.as_dyn(), &FRF)?,
+//!     Money::new(Decimal::new(33538, 2), &FRF as &dyn Currency)
+//! );
+//! # Ok(())
+//! # }
+//! ```
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::{Currency, Money, RoundingStrategy};
+
+/// The irrevocable conversion rates, as the number of legacy units per euro,
+/// given as the mantissa and scale of the Decimal.
+const FIXED_RATES: &[(&str, i64, u32)] = &[
+    ("ATS", 137603, 4),
+    ("BEF", 403399, 4),
+    ("DEM", 195583, 5),
+    ("ESP", 166386, 3),
+    ("FIM", 594573, 5),
+    ("FRF", 655957, 5),
+    ("IEP", 787564, 6),
+    ("ITL", 193627, 2),
+    ("LUF", 403399, 4),
+    ("NLG", 220371, 5),
+    ("PTE", 200482, 3),
+    ("GRD", 340750, 3),
+    ("SIT", 239640, 3),
+    ("CYP", 585274, 6),
+    ("MTL", 429300, 6),
+    ("SKK", 301260, 4),
+    ("EEK", 156466, 4),
+    ("LVL", 702804, 6),
+    ("LTL", 345280, 5),
+    ("HRK", 753450, 5),
+    ("BGN", 195583, 5),
+];
+
+/// The minimum number of decimal places for the intermediate euro amount
+/// when converting between two legacy currencies.
+const MIN_INTERMEDIATE_DECIMALS: u32 = 3;
+
+/// Converted amounts are rounded to the nearest minor unit, with halfway
+/// values rounded up.
+const ROUNDING: RoundingStrategy = RoundingStrategy::MidpointAwayFromZero;
+
+/// Errors that can occur when converting legacy euro currencies.
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum EuroLegacyError {
+    #[error("{0} is neither the euro nor a legacy euro currency")]
+    UnsupportedCurrency(String),
+    #[error("the converted amount is too large to represent")]
+    Overflow,
+}
+
+/// Returns the fixed conversion rate for a legacy euro currency,
+/// as the number of units of that currency per euro, or None if
+/// the currency is not a legacy euro currency.
+pub fn fixed_rate(currency: &dyn Currency) -> Option<Decimal> {
+    FIXED_RATES
+        .iter()
+        .find(|(code, _, _)| *code == currency.code())
+        .map(|(_, mantissa, scale)| Decimal::new(*mantissa, *scale))
+}
+
+/// Converts Money between the euro and legacy euro currencies according
+/// to the rules mandated by the EU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EuroLegacyConverter {
+    intermediate_decimals: u32,
+}
+
+impl Default for EuroLegacyConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EuroLegacyConverter {
+    /// Constructs a new [EuroLegacyConverter] that rounds intermediate
+    /// euro amounts to three decimal places.
+    pub fn new() -> Self {
+        Self {
+            intermediate_decimals: MIN_INTERMEDIATE_DECIMALS,
+        }
+    }
+
+    /// Returns this converter with the specified number of decimal places
+    /// for intermediate euro amounts. Values below three are raised to
+    /// three, as the rules require.
+    pub fn with_intermediate_decimals(self, decimals: u32) -> Self {
+        Self {
+            intermediate_decimals: decimals.max(MIN_INTERMEDIATE_DECIMALS),
+        }
+    }
+
+    /// Returns the number of decimal places for intermediate euro amounts.
+    pub fn intermediate_decimals(&self) -> u32 {
+        self.intermediate_decimals
+    }
+
+    /// Converts money from the euro or a legacy euro currency into another
+    /// of those currencies, rounding the result to the minor units of the
+    /// target currency. Money that is already in the target currency is
+    /// returned unchanged. Returns [EuroLegacyError::Overflow] if the
+    /// converted amount is too large to represent.
+    pub fn convert<'c>(
+        &self,
+        money: Money<&'c dyn Currency>,
+        to: &'c dyn Currency,
+    ) -> Result<Money<&'c dyn Currency>, EuroLegacyError> {
+        let rate = |currency: &dyn Currency| match currency.code() {
+            "EUR" => Ok(None),
+            code => fixed_rate(currency)
+                .map(Some)
+                .ok_or_else(|| EuroLegacyError::UnsupportedCurrency(code.to_string())),
+        };
+        let from_rate = rate(money.currency)?;
+        let to_rate = rate(to)?;
+        if money.currency.code() == to.code() {
+            return Ok(money);
+        }
+
+        let amount = match (from_rate, to_rate) {
+            (None, Some(to_rate)) => money.amount.checked_mul(to_rate),
+            (Some(from_rate), None) => money.amount.checked_div(from_rate),
+            (Some(from_rate), Some(to_rate)) => money
+                .amount
+                .checked_div(from_rate)
+                .map(|euros| euros.round_dp_with_strategy(self.intermediate_decimals, ROUNDING))
+                .and_then(|euros| euros.checked_mul(to_rate)),
+            (None, None) => Some(money.amount),
+        }
+        .ok_or(EuroLegacyError::Overflow)?;
+        Ok(Money::new(
+            amount.round_dp_with_strategy(to.minor_units(), ROUNDING),
+            to,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::euro_legacy::*;
+    use crate::iso_currencies::{EUR, USD};
+    use crate::iso_historic_currencies::{self, BEF, DEM, FRF, ITL, MTL, VEF};
+
+    #[test]
+    fn fixed_rates() {
+        assert_eq!(fixed_rate(&DEM), Some(Decimal::new(195583, 5)));
+        assert_eq!(fixed_rate(&ITL), Some(Decimal::new(193627, 2)));
+        assert_eq!(fixed_rate(&EUR), None);
+        assert_eq!(fixed_rate(&VEF), None);
+
+        // every rate has six significant figures, and every
+        // legacy currency is defined as a historic currency
+        for (code, mantissa, _) in FIXED_RATES {
+            assert_eq!(mantissa.to_string().len(), 6, "{code}");
+            assert!(iso_historic_currencies::CURRENCY_MAP.contains(code));
+        }
+    }
+
+    #[test]
+    fn to_and_from_euro() {
+        let converter = EuroLegacyConverter::new();
+        assert_eq!(
+            converter.convert(Money::new(Decimal::ONE, &EUR as &dyn Currency), &DEM),
+            Ok(Money::new(Decimal::new(196, 2), &DEM as &dyn Currency))
+        );
+        assert_eq!(
+            converter.convert(
+                Money::new(Decimal::new(1000, 0), &ITL as &dyn Currency),
+                &EUR
+            ),
+            Ok(Money::new(Decimal::new(52, 2), &EUR as &dyn Currency))
+        );
+        // 0.005 EUR rounds up
+        assert_eq!(
+            converter.convert(
+                Money::new(Decimal::new(1000, 2), &EUR as &dyn Currency),
+                &ITL
+            ),
+            Ok(Money::new(Decimal::new(19363, 0), &ITL as &dyn Currency))
+        );
+        assert_eq!(
+            converter.convert(
+                Money::new(Decimal::new(1000, 0), &BEF as &dyn Currency),
+                &EUR
+            ),
+            Ok(Money::new(Decimal::new(2479, 2), &EUR as &dyn Currency))
+        );
+    }
+
+    #[test]
+    fn between_legacy_currencies() {
+        let converter = EuroLegacyConverter::new();
+        // 100 DEM -> 51.129 EUR -> 335.38 FRF, while converting
+        // directly with the ratio of the rates would give 335.39 FRF
+        let dem = Money::new(Decimal::new(100, 0), &DEM as &dyn Currency);
+        assert_eq!(
+            converter.convert(dem, &FRF),
+            Ok(Money::new(Decimal::new(33538, 2), &FRF as &dyn Currency))
+        );
+        assert_eq!(
+            (dem.amount() / Decimal::new(195583, 5) * Decimal::new(655957, 5))
+                .round_dp_with_strategy(2, ROUNDING),
+            Decimal::new(33539, 2)
+        );
+
+        // more intermediate decimals are allowed, but not fewer
+        let precise = converter.with_intermediate_decimals(6);
+        assert_eq!(precise.intermediate_decimals(), 6);
+        assert_eq!(
+            precise.convert(dem, &FRF),
+            Ok(Money::new(Decimal::new(33539, 2), &FRF as &dyn Currency))
+        );
+        assert_eq!(
+            converter.with_intermediate_decimals(2),
+            EuroLegacyConverter::new()
+        );
+
+        // 1 FRF -> 0.152 EUR -> 294 ITL
+        assert_eq!(
+            converter.convert(Money::new(Decimal::ONE, &FRF as &dyn Currency), &ITL),
+            Ok(Money::new(Decimal::new(294, 0), &ITL as &dyn Currency))
+        );
+        assert_eq!(converter.convert(dem, &DEM), Ok(dem));
+    }
+
+    #[test]
+    fn unsupported_currencies() {
+        let converter = EuroLegacyConverter::new();
+        assert_eq!(
+            converter.convert(Money::new(Decimal::ONE, &USD as &dyn Currency), &DEM),
+            Err(EuroLegacyError::UnsupportedCurrency("USD".to_string()))
+        );
+        assert_eq!(
+            converter.convert(Money::new(Decimal::ONE, &DEM as &dyn Currency), &VEF),
+            Err(EuroLegacyError::UnsupportedCurrency("VEF".to_string()))
+        );
+    }
+
+    #[test]
+    fn overflow() {
+        let converter = EuroLegacyConverter::new();
+        assert_eq!(
+            converter.convert(Money::new(Decimal::MAX, &EUR as &dyn Currency), &ITL),
+            Err(EuroLegacyError::Overflow)
+        );
+        // the rate for MTL is less than one, so converting to euros overflows too
+        assert_eq!(
+            converter.convert(Money::new(Decimal::MAX, &MTL as &dyn Currency), &EUR),
+            Err(EuroLegacyError::Overflow)
+        );
+        assert_eq!(
+            converter.convert(Money::new(Decimal::MAX, &DEM as &dyn Currency), &ITL),
+            Err(EuroLegacyError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,325 @@
+//! Exchange rates for converting Money from one currency to another.
+//!
+//! An [ExchangeRate] with statically-typed currencies converts a `Money<F>`
+//! into a `Money<T>`, so passing money in the wrong currency is a compile
+//! error. An [ExchangeRate] with dynamically-typed currencies returns a
+//! [MoneyMathError] if the money is not in the rate's source currency.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_decimal::Decimal;
+//! use doubloon::{
+//!     Money, Currency, MoneyMathError, RoundingStrategy,
+//!     iso_currencies::{USD, EUR, JPY},
+//!     exchange_rate::ExchangeRate,
+//! };
+//!
+//! let usd_to_eur = ExchangeRate::new(USD, EUR, Decimal::new(92, 2));
+//! assert_eq!(usd_to_eur.convert(Money::new(10, USD)), Money::new(Decimal::new(92, 1), EUR));
+//!
+//! // This won't compile because the money is not in USD.
+//! // usd_to_eur.convert(Money::new(10, JPY));
+//!
+//! let usd: &dyn Currency = &USD;
+//! let eur: &dyn Currency = &EUR;
+//! let jpy: &dyn Currency = &JPY;
+//! let usd_to_eur = ExchangeRate::new(usd, eur, Decimal::new(92, 2));
+//! assert_eq!(
+//!     usd_to_eur.convert(Money::new(10, jpy)),
+//!     Err(MoneyMathError::IncompatibleCurrencies("JPY".to_string(), "USD".to_string()))
+//! );
+//! # Ok(())
+//! # }
+//! ```
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::{
+    iso_currencies::IsoCurrency, Currency, MinorUnits, Money, MoneyMathError, RoundingStrategy,
+};
+
+/// Errors that can occur when constructing an [ExchangeRate].
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum ExchangeRateError {
+    #[error("exchange rates must be positive, but the rate was {0}")]
+    NonPositiveRate(Decimal),
+}
+
+/// A rate for converting money from one currency to another.
+/// One unit of the `from` currency is worth `rate` units of the `to` currency.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExchangeRate<F, T> {
+    from: F,
+    to: T,
+    rate: Decimal,
+    rounding: Option<RoundingStrategy>,
+}
+
+/// Common functions for statically and dynamically-typed currencies.
+impl<F, T> ExchangeRate<F, T>
+where
+    F: Copy,
+    T: Copy,
+{
+    /// Constructs a new ExchangeRate where one unit of the `from` currency
+    /// is worth `rate` units of the `to` currency. By default, converted
+    /// amounts are not rounded, so use [ExchangeRate::with_rounding] to round
+    /// them to the minor units of the `to` currency.
+    ///
+    /// The caller must pass a positive rate: this doesn't check, so a negative
+    /// rate converts money into negative money, and a zero rate has no
+    /// [inverse](ExchangeRate::inverse). Use [ExchangeRate::try_new] for rates
+    /// that come from outside the program.
+    pub fn new<N: Into<Decimal>>(from: F, to: T, rate: N) -> Self {
+        Self {
+            from,
+            to,
+            rate: rate.into(),
+            rounding: None,
+        }
+    }
+
+    /// Same as [ExchangeRate::new], but returns
+    /// [ExchangeRateError::NonPositiveRate] if the rate is zero or negative.
+    pub fn try_new<N: Into<Decimal>>(from: F, to: T, rate: N) -> Result<Self, ExchangeRateError> {
+        let rate = rate.into();
+        if rate <= Decimal::ZERO {
+            return Err(ExchangeRateError::NonPositiveRate(rate));
+        }
+        Ok(Self::new(from, to, rate))
+    }
+
+    /// Returns a copy of this rate that rounds converted amounts to the
+    /// minor units of the `to` currency, using the specified strategy.
+    pub fn with_rounding(&self, strategy: RoundingStrategy) -> Self {
+        Self {
+            rounding: Some(strategy),
+            ..*self
+        }
+    }
+
+    /// Returns the currency this rate converts from.
+    pub fn from(&self) -> F {
+        self.from
+    }
+
+    /// Returns the currency this rate converts to.
+    pub fn to(&self) -> T {
+        self.to
+    }
+
+    /// Returns the number of `to` currency units per `from` currency unit.
+    pub fn rate(&self) -> Decimal {
+        self.rate
+    }
+
+    /// Returns the rounding strategy used for converted amounts, if any.
+    pub fn rounding(&self) -> Option<RoundingStrategy> {
+        self.rounding
+    }
+
+    /// Returns the inverse of this rate, which converts from the `to` currency
+    /// back to the `from` currency, with the same rounding strategy.
+    /// Returns None if the rate is zero and therefore has no inverse.
+    pub fn inverse(&self) -> Option<ExchangeRate<T, F>> {
+        Decimal::ONE
+            .checked_div(self.rate)
+            .map(|inverse_rate| ExchangeRate {
+                from: self.to,
+                to: self.from,
+                rate: inverse_rate,
+                rounding: self.rounding,
+            })
+    }
+
+    /// Converts an amount using this rate, rounding it to the
+    /// minor units of the `to` currency if requested. Returns
+    /// [MoneyMathError::Overflow] if the result is too large to represent.
+    fn convert_amount(&self, amount: Decimal) -> Result<Decimal, MoneyMathError>
+    where
+        T: MinorUnits,
+    {
+        let converted = amount
+            .checked_mul(self.rate)
+            .ok_or(MoneyMathError::Overflow)?;
+        Ok(match self.rounding {
+            Some(strategy) => converted.round_dp_with_strategy(self.to.minor_units(), strategy),
+            None => converted,
+        })
+    }
+}
+
+/// Functions specifically for statically-typed currencies.
+impl<F, T> ExchangeRate<F, T>
+where
+    F: Currency + Default + Copy,
+    T: Currency + Copy,
+{
+    /// Converts money in the `from` currency into the `to` currency.
+    /// Like the arithmetic operators, this panics if the converted amount
+    /// is too large to represent, so use [ExchangeRate::checked_convert]
+    /// where that matters.
+    pub fn convert(&self, money: Money<F>) -> Money<T> {
+        self.checked_convert(money)
+            .expect("converted amount is too large to represent")
+    }
+
+    /// Converts money in the `from` currency into the `to` currency.
+    /// Returns [MoneyMathError::Overflow] if the converted amount
+    /// is too large to represent.
+    pub fn checked_convert(&self, money: Money<F>) -> Result<Money<T>, MoneyMathError> {
+        Ok(Money::new(self.convert_amount(money.amount)?, self.to))
+    }
+}
+
+/// Macro for implementing `convert` for currency types that are only
+/// known at runtime, which must check the currency of the money.
+macro_rules! impl_runtime_convert {
+    ($currency:ty, $money:ty, [$($generics:tt)*]) => {
+        #[doc = concat!("Functions specifically for `", stringify!($currency), "` currencies.")]
+        impl<$($generics)*> ExchangeRate<$currency, $currency> {
+            /// Converts money in the `from` currency into the `to` currency.
+            /// Returns [MoneyMathError::IncompatibleCurrencies] if the money is in
+            /// some other currency, or [MoneyMathError::Overflow] if the converted
+            /// amount is too large to represent.
+            pub fn convert(&self, money: $money) -> Result<Money<$currency>, MoneyMathError> {
+                if money.currency.code() != self.from.code() {
+                    return Err(MoneyMathError::IncompatibleCurrencies(
+                        money.currency.code().to_string(),
+                        self.from.code().to_string(),
+                    ));
+                }
+                Ok(Money::new(self.convert_amount(money.amount)?, self.to))
+            }
+        }
+    };
+}
+
+impl_runtime_convert!(&'c dyn Currency, Money<&dyn Currency>, ['c]);
+impl_runtime_convert!(IsoCurrency, Money<IsoCurrency>, []);
+
+#[cfg(test)]
+mod tests {
+    use crate::exchange_rate::*;
+    use crate::iso_currencies::{EUR, JPY, USD};
+
+    #[test]
+    fn convert_static() {
+        let rate = ExchangeRate::new(USD, EUR, Decimal::new(92, 2));
+        assert_eq!(
+            rate.convert(Money::new(Decimal::new(1050, 2), USD)),
+            Money::new(Decimal::new(9660, 3), EUR)
+        );
+        assert_eq!(rate.from(), USD);
+        assert_eq!(rate.to(), EUR);
+        assert_eq!(rate.rate(), Decimal::new(92, 2));
+    }
+
+    #[test]
+    fn convert_dynamic() {
+        let usd: &dyn Currency = &USD;
+        let eur: &dyn Currency = &EUR;
+        let jpy: &dyn Currency = &JPY;
+        let rate = ExchangeRate::new(usd, eur, Decimal::new(92, 2));
+        assert_eq!(
+            rate.convert(Money::new(10, usd)),
+            Ok(Money::new(Decimal::new(92, 1), eur))
+        );
+        assert_eq!(
+            rate.convert(Money::new(10, jpy)),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "JPY".to_string(),
+                "USD".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn convert_iso_currency() {
+        let rate = ExchangeRate::new(IsoCurrency::USD, IsoCurrency::JPY, Decimal::new(1515, 1))
+            .with_rounding(RoundingStrategy::MidpointNearestEven);
+        assert_eq!(
+            rate.convert(Money::new(Decimal::new(101, 2), IsoCurrency::USD)),
+            Ok(Money::new(153, IsoCurrency::JPY))
+        );
+        assert_eq!(
+            rate.convert(Money::new(1, IsoCurrency::EUR)),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "EUR".to_string(),
+                "USD".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn try_new() {
+        assert_eq!(
+            ExchangeRate::try_new(USD, EUR, Decimal::new(92, 2)),
+            Ok(ExchangeRate::new(USD, EUR, Decimal::new(92, 2)))
+        );
+        assert_eq!(
+            ExchangeRate::try_new(USD, EUR, Decimal::ZERO),
+            Err(ExchangeRateError::NonPositiveRate(Decimal::ZERO))
+        );
+        assert_eq!(
+            ExchangeRate::try_new(USD, EUR, -1),
+            Err(ExchangeRateError::NonPositiveRate(Decimal::NEGATIVE_ONE))
+        );
+    }
+
+    #[test]
+    fn rounding() {
+        let rate = ExchangeRate::new(USD, JPY, Decimal::new(1515, 1))
+            .with_rounding(RoundingStrategy::MidpointNearestEven);
+        assert_eq!(
+            rate.convert(Money::new(Decimal::new(101, 2), USD)),
+            Money::new(153, JPY)
+        );
+
+        let rate = ExchangeRate::new(USD, EUR, Decimal::new(9234, 4));
+        assert_eq!(
+            rate.convert(Money::new(1, USD)),
+            Money::new(Decimal::new(9234, 4), EUR)
+        );
+        assert_eq!(
+            rate.with_rounding(RoundingStrategy::ToZero)
+                .convert(Money::new(1, USD)),
+            Money::new(Decimal::new(92, 2), EUR)
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let rate = ExchangeRate::new(EUR, USD, Decimal::new(125, 2))
+            .with_rounding(RoundingStrategy::MidpointNearestEven);
+        let inverse = rate.inverse().unwrap();
+        assert_eq!(inverse.from(), USD);
+        assert_eq!(inverse.to(), EUR);
+        assert_eq!(inverse.rate(), Decimal::new(8, 1));
+        assert_eq!(inverse.rounding(), rate.rounding());
+        assert_eq!(inverse.convert(Money::new(10, USD)), Money::new(8, EUR));
+
+        assert_eq!(ExchangeRate::new(EUR, USD, Decimal::ZERO).inverse(), None);
+    }
+
+    #[test]
+    fn overflow() {
+        let rate = ExchangeRate::new(USD, JPY, Decimal::new(150, 0));
+        assert_eq!(
+            rate.checked_convert(Money::new(Decimal::MAX, USD)),
+            Err(MoneyMathError::Overflow)
+        );
+        assert_eq!(
+            rate.checked_convert(Money::new(1, USD)),
+            Ok(Money::new(150, JPY))
+        );
+
+        let usd: &dyn Currency = &USD;
+        let jpy: &dyn Currency = &JPY;
+        let rate = ExchangeRate::new(usd, jpy, Decimal::new(150, 0));
+        assert_eq!(
+            rate.convert(Money::new(Decimal::MAX, usd)),
+            Err(MoneyMathError::Overflow)
+        );
+    }
+}
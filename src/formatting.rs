@@ -8,6 +8,9 @@ use tinystr::TinyAsciiStr;
 
 use crate::{Currency, Money};
 
+mod parsing;
+pub use parsing::{LocalizedParseError, ParsingMode};
+
 /// Republished reference to the icu crate's CurrencyFormatterOptions.
 /// If you `use doubloon::formatting::CurrencyFormatterOptions`, your
 /// code will be protected against changes to module path within the icu
@@ -34,7 +37,7 @@ impl<C> Money<C> {
     fn format_helper(
         &self,
         locale: &Locale,
-        currency_code_str: &'static str,
+        currency_code_str: &str,
         options: FormattingOptions,
     ) -> String {
         // This could only fail for app-defined Currency instances that
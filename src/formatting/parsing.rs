@@ -0,0 +1,684 @@
+//! Locale-aware parsing of user-entered monetary amounts.
+//!
+//! The icu crate doesn't (yet) support parsing, so this learns the
+//! conventions of a locale by formatting a probe amount and noting
+//! which digits, separators, currency symbol and sign placement the
+//! formatter used. Input is then parsed against those conventions.
+use std::fmt::Write;
+
+use icu::locale::Locale;
+use rust_decimal::{Decimal, RoundingStrategy};
+use thiserror::Error;
+
+use super::{CurrencyFormatterOptions, FormattingOptions};
+use crate::{AsCurrency, Money};
+
+/// Controls how forgiving `Money::parse_localized()` is about the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParsingMode {
+    /// Accepts the currency symbol or ISO code (in any case) on either
+    /// side of the amount or not at all, a minus sign anywhere outside the
+    /// amount or parentheses around it, and any number of decimal places.
+    /// Full-width and half-width forms of the symbol are treated alike,
+    /// so `"¥1,000"` is accepted in `ja-JP`, which formats it as `"￥1,000"`.
+    #[default]
+    Lenient,
+    /// Requires the input to look like the output of `Money::format()`:
+    /// the currency symbol or ISO code must be present and on the same side
+    /// as the locale puts it, the sign must be where the locale puts it,
+    /// and there may be no more decimal places than the currency's minor units.
+    Strict,
+}
+
+/// Errors that can occur when parsing a localized monetary amount.
+/// Positions are zero-based character (not byte) offsets into the input.
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum LocalizedParseError {
+    #[error("the input contains no amount")]
+    MissingAmount,
+    #[error("unexpected character {character:?} at position {position}")]
+    UnexpectedCharacter { character: char, position: usize },
+    #[error("misplaced group separator at position {position}")]
+    MisplacedGroupSeparator { position: usize },
+    #[error("extra decimal separator at position {position}")]
+    MultipleDecimalSeparators { position: usize },
+    #[error("too many decimal places: the currency allows {max} but found {found}")]
+    TooManyDecimalPlaces { max: u32, found: u32 },
+    #[error("unrecognized text {0:?}")]
+    UnrecognizedText(String),
+    #[error("expected currency {expected} but found {found}")]
+    MismatchedCurrency { expected: String, found: String },
+    #[error("the currency symbol or code is missing")]
+    MissingCurrency,
+    #[error("the currency symbol, code, or sign is not where the locale expects it")]
+    MisplacedAffix,
+    #[error("invalid amount {0:?}")]
+    InvalidAmount(String),
+}
+
+/// Integer part of the probe amount, chosen because it contains
+/// all ten digits and enough of them to show the grouping pattern.
+const PROBE_INTEGER_DIGITS: &str = "1234567890";
+
+/// Stands in for the currency symbol or code in affix patterns.
+const CURRENCY_PLACEHOLDER: char = '¤';
+
+/// Returns true for the invisible bidirectional control characters
+/// that some locales add around numbers and symbols.
+fn is_bidi_control(c: char) -> bool {
+    matches!(c, '\u{200e}' | '\u{200f}' | '\u{061c}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}')
+}
+
+/// Returns the usual form of a full-width character, such as `¥` for `￥`
+/// or `A` for `Ａ`, since users often type one in place of the other.
+fn fold_width(c: char) -> char {
+    match c {
+        '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+        '\u{ffe0}' => '¢',
+        '\u{ffe1}' => '£',
+        '\u{ffe5}' => '¥',
+        '\u{ffe6}' => '₩',
+        _ => c,
+    }
+}
+
+fn is_minus(c: char) -> bool {
+    matches!(c, '-' | '\u{2212}' | '\u{2013}' | '\u{fe63}' | '\u{ff0d}')
+}
+
+/// The conventions a locale uses when formatting a particular currency.
+#[derive(Debug)]
+struct LocaleConventions {
+    /// The locale's digits for zero through nine.
+    digits: [char; 10],
+    decimal_separator: char,
+    group_separator: Option<char>,
+    /// Size of the group nearest the decimal separator.
+    primary_group: usize,
+    /// Size of the other groups (differs for Indian-style grouping).
+    secondary_group: usize,
+    symbol: String,
+    /// Affix patterns for positive and negative amounts, with the
+    /// currency replaced by [CURRENCY_PLACEHOLDER] and the sign by `-`.
+    positive_affixes: (String, String),
+    negative_affixes: (String, String),
+}
+
+/// A formatted probe amount, split into prefix, number, and suffix.
+struct Probe {
+    prefix: String,
+    number: Vec<char>,
+    suffix: String,
+}
+
+impl Probe {
+    fn new(formatted: &str) -> Self {
+        let chars: Vec<char> = formatted.chars().filter(|c| !is_bidi_control(*c)).collect();
+        let first = chars.iter().position(|c| c.is_numeric()).unwrap_or(0);
+        let last = chars.iter().rposition(|c| c.is_numeric()).unwrap_or(0);
+        Probe {
+            prefix: chars[..first]
+                .iter()
+                .filter(|c| !c.is_whitespace())
+                .collect(),
+            number: chars[first..=last].to_vec(),
+            suffix: chars[last + 1..]
+                .iter()
+                .filter(|c| !c.is_whitespace())
+                .collect(),
+        }
+    }
+}
+
+impl LocaleConventions {
+    fn new(locale: &Locale, code: &str) -> Self {
+        let options = FormattingOptions {
+            decimal_places: 2,
+            rounding_strategy: RoundingStrategy::MidpointNearestEven,
+            currency_formatter_options: CurrencyFormatterOptions::default(),
+        };
+        let probe_amount = Decimal::from_str_exact(&format!("{PROBE_INTEGER_DIGITS}.12")).unwrap();
+        let positive =
+            Probe::new(&Money::new(probe_amount, ()).format_helper(locale, code, options.clone()));
+        let negative =
+            Probe::new(&Money::new(-probe_amount, ()).format_helper(locale, code, options));
+
+        // The numeric characters in the probe are the digits 1-9, then 0, then 1 and 2.
+        let mut digits = ['0'; 10];
+        let numerals: Vec<char> = positive
+            .number
+            .iter()
+            .copied()
+            .filter(|c| c.is_numeric())
+            .collect();
+        for (numeral, ascii) in numerals.iter().zip(PROBE_INTEGER_DIGITS.chars()) {
+            digits[ascii.to_digit(10).unwrap() as usize] = *numeral;
+        }
+
+        // The separator before the last two digits is the decimal separator,
+        // and any others are group separators.
+        let decimal_position = positive.number.len() - 3;
+        let decimal_separator = positive.number[decimal_position];
+        let integer_part = &positive.number[..decimal_position];
+        let group_separator = integer_part.iter().copied().find(|c| !c.is_numeric());
+        let group_sizes: Vec<usize> = integer_part
+            .split(|c| !c.is_numeric())
+            .map(|group| group.len())
+            .collect();
+        let (primary_group, secondary_group) = match group_sizes.as_slice() {
+            [.., secondary, _, primary] => (*primary, *secondary),
+            [_, primary] => (*primary, *primary),
+            _ => (0, 0),
+        };
+
+        let symbol = format!("{}{}", positive.prefix, positive.suffix);
+        let positive_affixes = (
+            positive
+                .prefix
+                .replace(&symbol, &CURRENCY_PLACEHOLDER.to_string()),
+            positive
+                .suffix
+                .replace(&symbol, &CURRENCY_PLACEHOLDER.to_string()),
+        );
+        let to_pattern = |affix: &str| {
+            affix
+                .replace(&symbol, &CURRENCY_PLACEHOLDER.to_string())
+                .chars()
+                .map(|c| if is_minus(c) { '-' } else { c })
+                .collect::<String>()
+        };
+        let negative_affixes = (to_pattern(&negative.prefix), to_pattern(&negative.suffix));
+
+        LocaleConventions {
+            digits,
+            decimal_separator,
+            group_separator,
+            primary_group,
+            secondary_group,
+            symbol,
+            positive_affixes,
+            negative_affixes,
+        }
+    }
+
+    fn digit_value(&self, c: char) -> Option<char> {
+        if c.is_ascii_digit() {
+            return Some(c);
+        }
+        self.digits
+            .iter()
+            .position(|d| *d == c)
+            .and_then(|value| char::from_digit(value as u32, 10))
+    }
+
+    fn is_group_separator(&self, c: char) -> bool {
+        match self.group_separator {
+            // Users rarely type the exact kind of space the locale uses.
+            Some(separator) if separator.is_whitespace() => c.is_whitespace(),
+            Some(separator) => c == separator,
+            None => false,
+        }
+    }
+}
+
+/// Converts an affix of the input into a pattern like the ones in
+/// [LocaleConventions], returning the pattern and whether the
+/// currency was present.
+fn affix_pattern(
+    affix: &[(usize, char)],
+    conventions: &LocaleConventions,
+    code: &str,
+    mode: ParsingMode,
+) -> Result<(String, bool), LocalizedParseError> {
+    let text: String = affix
+        .iter()
+        .map(|(_, c)| *c)
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    let fold = |s: &str| -> String {
+        match mode {
+            ParsingMode::Strict => s.to_string(),
+            ParsingMode::Lenient => s.chars().map(fold_width).collect(),
+        }
+    };
+    let searched = fold(&text);
+    let currency_text = [conventions.symbol.as_str(), code]
+        .into_iter()
+        .filter(|candidate| !candidate.is_empty())
+        .find_map(|candidate| {
+            let candidate = fold(candidate);
+            match mode {
+                ParsingMode::Strict => searched.find(&candidate),
+                ParsingMode::Lenient => searched
+                    .to_ascii_uppercase()
+                    .find(&candidate.to_ascii_uppercase()),
+            }
+            .map(|start| (start, candidate.len()))
+        });
+    let pattern = match currency_text {
+        Some((start, len)) => format!(
+            "{}{CURRENCY_PLACEHOLDER}{}",
+            &searched[..start],
+            &searched[start + len..]
+        ),
+        None => searched.clone(),
+    };
+    let pattern: String = pattern
+        .chars()
+        .map(|c| if is_minus(c) { '-' } else { c })
+        .collect();
+
+    let unrecognized: String = pattern
+        .chars()
+        .filter(|c| !matches!(*c, '-' | '+' | '(' | ')' | CURRENCY_PLACEHOLDER))
+        .collect();
+    if !unrecognized.is_empty() {
+        if unrecognized.len() == 3 && unrecognized.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(LocalizedParseError::MismatchedCurrency {
+                expected: code.to_string(),
+                found: unrecognized,
+            });
+        }
+        return Err(LocalizedParseError::UnrecognizedText(text));
+    }
+    Ok((pattern, currency_text.is_some()))
+}
+
+/// Parses the number part of the input into a string that [Decimal] can parse.
+fn parse_number(
+    number: &[(usize, char)],
+    conventions: &LocaleConventions,
+) -> Result<(String, u32), LocalizedParseError> {
+    let mut integer_digits = String::new();
+    let mut fraction_digits: Option<String> = None;
+    let mut group_sizes = vec![0_usize];
+    let mut separator_positions = vec![];
+
+    for (position, c) in number.iter().copied() {
+        if let Some(digit) = conventions.digit_value(c) {
+            match fraction_digits.as_mut() {
+                Some(fraction) => fraction.push(digit),
+                None => {
+                    integer_digits.push(digit);
+                    *group_sizes.last_mut().unwrap() += 1;
+                }
+            }
+        } else if c == conventions.decimal_separator {
+            if fraction_digits.is_some() {
+                return Err(LocalizedParseError::MultipleDecimalSeparators { position });
+            }
+            fraction_digits = Some(String::new());
+        } else if conventions.is_group_separator(c) {
+            if fraction_digits.is_some() || *group_sizes.last().unwrap() == 0 {
+                return Err(LocalizedParseError::MisplacedGroupSeparator { position });
+            }
+            group_sizes.push(0);
+            separator_positions.push(position);
+        } else {
+            return Err(LocalizedParseError::UnexpectedCharacter {
+                character: c,
+                position,
+            });
+        }
+    }
+
+    // When group separators are used, the group nearest the decimal separator
+    // must be the primary size, the ones before it the secondary size,
+    // and the leading group no larger than the secondary size.
+    if let Some((last, rest)) = group_sizes.split_last() {
+        if !rest.is_empty() {
+            if *last != conventions.primary_group {
+                return Err(LocalizedParseError::MisplacedGroupSeparator {
+                    position: *separator_positions.last().unwrap(),
+                });
+            }
+            for (index, size) in rest.iter().enumerate() {
+                let valid = if index == 0 {
+                    *size <= conventions.secondary_group
+                } else {
+                    *size == conventions.secondary_group
+                };
+                if !valid {
+                    return Err(LocalizedParseError::MisplacedGroupSeparator {
+                        position: separator_positions[index],
+                    });
+                }
+            }
+        }
+    }
+
+    let mut amount = if integer_digits.is_empty() {
+        "0".to_string()
+    } else {
+        integer_digits
+    };
+    let mut decimal_places = 0;
+    if let Some(fraction) = fraction_digits {
+        decimal_places = fraction.chars().count() as u32;
+        write!(amount, ".{fraction}").unwrap();
+    }
+    Ok((amount, decimal_places))
+}
+
+/// Parses a localized amount into a Decimal, for the currency with the given code.
+fn parse_localized_amount(
+    input: &str,
+    locale: &Locale,
+    code: &str,
+    minor_units: u32,
+    mode: ParsingMode,
+) -> Result<Decimal, LocalizedParseError> {
+    let conventions = LocaleConventions::new(locale, code);
+    let chars: Vec<(usize, char)> = input
+        .chars()
+        .enumerate()
+        .filter(|(_, c)| !is_bidi_control(*c))
+        .collect();
+
+    let is_digit = |c: char| conventions.digit_value(c).is_some();
+    let Some(mut first) = chars.iter().position(|(_, c)| is_digit(*c)) else {
+        return Err(LocalizedParseError::MissingAmount);
+    };
+    let last = chars.iter().rposition(|(_, c)| is_digit(*c)).unwrap();
+    // include a leading decimal separator, as in ".5"
+    if first > 0 && chars[first - 1].1 == conventions.decimal_separator {
+        first -= 1;
+    }
+
+    let (prefix, prefix_has_currency) = affix_pattern(&chars[..first], &conventions, code, mode)?;
+    let (suffix, suffix_has_currency) =
+        affix_pattern(&chars[last + 1..], &conventions, code, mode)?;
+    let (amount, decimal_places) = parse_number(&chars[first..=last], &conventions)?;
+
+    let is_negative = match mode {
+        ParsingMode::Strict => {
+            let affixes = (prefix, suffix);
+            if affixes == conventions.negative_affixes {
+                true
+            } else if affixes == conventions.positive_affixes {
+                false
+            } else if !prefix_has_currency && !suffix_has_currency {
+                return Err(LocalizedParseError::MissingCurrency);
+            } else {
+                return Err(LocalizedParseError::MisplacedAffix);
+            }
+        }
+        ParsingMode::Lenient => {
+            if prefix_has_currency && suffix_has_currency {
+                return Err(LocalizedParseError::MisplacedAffix);
+            }
+            let signs: String = format!("{prefix}{suffix}").replace(CURRENCY_PLACEHOLDER, "");
+            match signs.as_str() {
+                "" | "+" => false,
+                "-" => true,
+                "()" if prefix.contains('(') && suffix.contains(')') => true,
+                _ => return Err(LocalizedParseError::MisplacedAffix),
+            }
+        }
+    };
+
+    if mode == ParsingMode::Strict && decimal_places > minor_units {
+        return Err(LocalizedParseError::TooManyDecimalPlaces {
+            max: minor_units,
+            found: decimal_places,
+        });
+    }
+
+    let amount = Decimal::from_str_exact(&amount)
+        .map_err(|_| LocalizedParseError::InvalidAmount(input.to_string()))?;
+    Ok(if is_negative { -amount } else { amount })
+}
+
+/// Parsing functions for both statically and dynamically-typed currencies.
+impl<C> Money<C>
+where
+    C: AsCurrency + Copy,
+{
+    /// Parses a monetary amount entered by a user in the specified locale,
+    /// such as `"1 234 567,89 €"` in `fr-FR`. This is the inverse of `format()`.
+    pub fn parse_localized(
+        input: &str,
+        locale: &Locale,
+        currency: C,
+        mode: ParsingMode,
+    ) -> Result<Self, LocalizedParseError> {
+        let c = currency.as_currency();
+        let amount = parse_localized_amount(input, locale, c.code(), c.minor_units(), mode)?;
+        Ok(Money::new(amount, currency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::formatting::parsing::*;
+    use crate::iso_currencies::{EUR, INR, JPY, USD};
+    use crate::Currency;
+    use icu::locale::locale;
+
+    fn lenient<C: Currency + Copy>(
+        input: &str,
+        locale: &Locale,
+        currency: C,
+    ) -> Result<Money<C>, LocalizedParseError> {
+        Money::parse_localized(input, locale, currency, ParsingMode::Lenient)
+    }
+
+    fn strict<C: Currency + Copy>(
+        input: &str,
+        locale: &Locale,
+        currency: C,
+    ) -> Result<Money<C>, LocalizedParseError> {
+        Money::parse_localized(input, locale, currency, ParsingMode::Strict)
+    }
+
+    #[test]
+    fn round_trips_formatted_output() {
+        let locales = [
+            locale!("en-US"),
+            locale!("fr-FR"),
+            locale!("de-CH"),
+            locale!("pl-PL"),
+            locale!("ar-EG"),
+            locale!("fa-IR"),
+            locale!("he-IL"),
+            locale!("sv-SE"),
+            locale!("hi-IN"),
+        ];
+        for locale in locales.iter() {
+            for amount in [
+                Decimal::new(123456789, 2),
+                Decimal::new(-123456789, 2),
+                Decimal::new(5, 2),
+            ] {
+                let m = Money::new(amount, EUR);
+                let formatted = m.format(locale);
+                assert_eq!(
+                    strict(&formatted, locale, EUR),
+                    Ok(m),
+                    "{locale} {formatted}"
+                );
+                assert_eq!(
+                    lenient(&formatted, locale, EUR),
+                    Ok(m),
+                    "{locale} {formatted}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn parse_fr_fr() {
+        let fr = locale!("fr-FR");
+        let expected = Money::new(Decimal::new(123456789, 2), EUR);
+        assert_eq!(lenient("1 234 567,89 €", &fr, EUR), Ok(expected));
+        assert_eq!(lenient("1234567,89", &fr, EUR), Ok(expected));
+        assert_eq!(lenient("EUR 1 234 567,89", &fr, EUR), Ok(expected));
+        assert_eq!(lenient("-1 234 567,89 eur", &fr, EUR), Ok(-expected));
+        assert_eq!(strict("1 234 567,89 €", &fr, EUR), Ok(expected));
+    }
+
+    #[test]
+    fn parse_en_us() {
+        let en = locale!("en-US");
+        assert_eq!(
+            lenient("$1,234.50", &en, USD),
+            Ok(Money::new(Decimal::new(123450, 2), USD))
+        );
+        assert_eq!(
+            lenient("-$1,234.50", &en, USD),
+            Ok(Money::new(Decimal::new(-123450, 2), USD))
+        );
+        assert_eq!(
+            lenient("($1,234.50)", &en, USD),
+            Ok(Money::new(Decimal::new(-123450, 2), USD))
+        );
+        assert_eq!(
+            lenient(".5", &en, USD),
+            Ok(Money::new(Decimal::new(5, 1), USD))
+        );
+        assert_eq!(
+            lenient("$1.2345", &en, USD),
+            Ok(Money::new(Decimal::new(12345, 4), USD))
+        );
+    }
+
+    #[test]
+    fn parse_ja_jp() {
+        let ja = locale!("ja-JP");
+        let expected = Money::new(1000, JPY);
+        assert_eq!(lenient("￥1,000", &ja, JPY), Ok(expected));
+        assert_eq!(lenient("¥1,000", &ja, JPY), Ok(expected));
+        assert_eq!(lenient("-¥1,000", &ja, JPY), Ok(-expected));
+        assert_eq!(lenient("1,000 jpy", &ja, JPY), Ok(expected));
+        assert_eq!(lenient("ＪＰＹ 1,000", &ja, JPY), Ok(expected));
+        // only the locale's own form is accepted in strict mode
+        assert_eq!(strict("￥1,000", &ja, JPY), Ok(expected));
+        assert_eq!(
+            strict("¥1,000", &ja, JPY),
+            Err(LocalizedParseError::UnrecognizedText("¥".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_indian_grouping() {
+        let hi = locale!("hi-IN");
+        assert_eq!(
+            lenient("₹12,34,567.89", &hi, INR),
+            Ok(Money::new(Decimal::new(123456789, 2), INR))
+        );
+        assert_eq!(
+            lenient("₹1,234,567.89", &hi, INR),
+            Err(LocalizedParseError::MisplacedGroupSeparator { position: 6 })
+        );
+    }
+
+    #[test]
+    fn parse_native_digits() {
+        let ar = locale!("ar-EG");
+        assert_eq!(
+            lenient("١٬٢٣٤٫٥٠ US$", &ar, USD),
+            Ok(Money::new(Decimal::new(123450, 2), USD))
+        );
+        // ASCII digits are always accepted
+        assert_eq!(lenient("1234", &ar, USD), Ok(Money::new(1234, USD)));
+    }
+
+    #[test]
+    fn misplaced_group_separator() {
+        let de = locale!("de-DE");
+        // In de-DE the period is a group separator, so this is not 12.34
+        assert_eq!(
+            lenient("12.34 €", &de, EUR),
+            Err(LocalizedParseError::MisplacedGroupSeparator { position: 2 })
+        );
+        assert_eq!(
+            lenient("1.234,5.6 €", &de, EUR),
+            Err(LocalizedParseError::MisplacedGroupSeparator { position: 7 })
+        );
+    }
+
+    #[test]
+    fn multiple_decimal_separators() {
+        assert_eq!(
+            lenient("1.2.3", &locale!("en-US"), USD),
+            Err(LocalizedParseError::MultipleDecimalSeparators { position: 3 })
+        );
+    }
+
+    #[test]
+    fn unexpected_character() {
+        assert_eq!(
+            lenient("$12x34", &locale!("en-US"), USD),
+            Err(LocalizedParseError::UnexpectedCharacter {
+                character: 'x',
+                position: 3
+            })
+        );
+    }
+
+    #[test]
+    fn missing_amount() {
+        assert_eq!(
+            lenient("$", &locale!("en-US"), USD),
+            Err(LocalizedParseError::MissingAmount)
+        );
+    }
+
+    #[test]
+    fn mismatched_currency() {
+        assert_eq!(
+            lenient("12.34 JPY", &locale!("en-US"), USD),
+            Err(LocalizedParseError::MismatchedCurrency {
+                expected: "USD".to_string(),
+                found: "JPY".to_string()
+            })
+        );
+        assert_eq!(
+            lenient("€12.34", &locale!("en-US"), USD),
+            Err(LocalizedParseError::UnrecognizedText("€".to_string()))
+        );
+    }
+
+    #[test]
+    fn strict_requires_currency_in_place() {
+        let en = locale!("en-US");
+        assert_eq!(
+            strict("1,234.50", &en, USD),
+            Err(LocalizedParseError::MissingCurrency)
+        );
+        assert_eq!(
+            strict("1,234.50 $", &en, USD),
+            Err(LocalizedParseError::MisplacedAffix)
+        );
+        assert_eq!(
+            strict("-$1,234.50", &en, USD),
+            Err(LocalizedParseError::MisplacedAffix)
+        );
+        assert_eq!(
+            strict("USD 1,234.50", &en, USD),
+            Ok(Money::new(Decimal::new(123450, 2), USD))
+        );
+    }
+
+    #[test]
+    fn strict_rejects_extra_decimal_places() {
+        assert_eq!(
+            strict("$1.234", &locale!("en-US"), USD),
+            Err(LocalizedParseError::TooManyDecimalPlaces { max: 2, found: 3 })
+        );
+        assert_eq!(
+            strict("￥1.5", &locale!("ja-JP"), JPY),
+            Err(LocalizedParseError::TooManyDecimalPlaces { max: 0, found: 1 })
+        );
+    }
+
+    #[test]
+    fn parse_dyn_currency() {
+        let c: &dyn Currency = &USD;
+        assert_eq!(
+            Money::parse_localized("$1,234.50", &locale!("en-US"), c, ParsingMode::Strict),
+            Ok(Money::new(Decimal::new(123450, 2), c))
+        );
+    }
+}
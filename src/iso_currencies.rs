@@ -2,10 +2,12 @@
 //!
 //! source: <https://www.six-group.com/dam/download/financial-information/data-center/iso-currrency/lists/list-one.xml>
 
-use crate::Currency;
+use std::{str::FromStr, sync::LazyLock};
+
+use crate::{currency_map::CurrencyMap, parsing::ParseMoneyError, Currency, CurrencyCategory};
 
 /// Afghani
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct AFN;
 impl Currency for AFN {
     fn code(&self) -> &'static str {
@@ -19,10 +21,18 @@ impl Currency for AFN {
     fn numeric_code(&self) -> u32 {
         971
     }
+
+    fn name(&self) -> &'static str {
+        "Afghani"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AF"]
+    }
 }
 
 /// Euro
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct EUR;
 impl Currency for EUR {
     fn code(&self) -> &'static str {
@@ -36,10 +46,26 @@ impl Currency for EUR {
     fn numeric_code(&self) -> u32 {
         978
     }
+
+    fn name(&self) -> &'static str {
+        "Euro"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[
+            "AD", "AT", "AX", "BE", "BG", "BL", "CY", "DE", "EE", "ES", "FI", "FR", "GF", "GP",
+            "GR", "HR", "IE", "IT", "LT", "LU", "LV", "MC", "ME", "MF", "MQ", "MT", "NL", "PM",
+            "PT", "RE", "SI", "SK", "SM", "TF", "VA", "YT",
+        ]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Lek
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct ALL;
 impl Currency for ALL {
     fn code(&self) -> &'static str {
@@ -53,10 +79,18 @@ impl Currency for ALL {
     fn numeric_code(&self) -> u32 {
         8
     }
+
+    fn name(&self) -> &'static str {
+        "Lek"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AL"]
+    }
 }
 
 /// Algerian Dinar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct DZD;
 impl Currency for DZD {
     fn code(&self) -> &'static str {
@@ -70,10 +104,18 @@ impl Currency for DZD {
     fn numeric_code(&self) -> u32 {
         12
     }
+
+    fn name(&self) -> &'static str {
+        "Algerian Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["DZ"]
+    }
 }
 
 /// US Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct USD;
 impl Currency for USD {
     fn code(&self) -> &'static str {
@@ -87,10 +129,21 @@ impl Currency for USD {
     fn numeric_code(&self) -> u32 {
         840
     }
+
+    fn name(&self) -> &'static str {
+        "US Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[
+            "AS", "BQ", "EC", "FM", "GU", "HT", "IO", "MH", "MP", "PA", "PR", "PW", "SV", "TC",
+            "TL", "UM", "US", "VG", "VI",
+        ]
+    }
 }
 
 /// Kwanza
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct AOA;
 impl Currency for AOA {
     fn code(&self) -> &'static str {
@@ -104,10 +157,18 @@ impl Currency for AOA {
     fn numeric_code(&self) -> u32 {
         973
     }
+
+    fn name(&self) -> &'static str {
+        "Kwanza"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AO"]
+    }
 }
 
 /// East Caribbean Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XCD;
 impl Currency for XCD {
     fn code(&self) -> &'static str {
@@ -121,10 +182,22 @@ impl Currency for XCD {
     fn numeric_code(&self) -> u32 {
         951
     }
+
+    fn name(&self) -> &'static str {
+        "East Caribbean Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AG", "AI", "DM", "GD", "KN", "LC", "MS", "VC"]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Arab Accounting Dinar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XAD;
 impl Currency for XAD {
     fn code(&self) -> &'static str {
@@ -138,10 +211,22 @@ impl Currency for XAD {
     fn numeric_code(&self) -> u32 {
         396
     }
+
+    fn name(&self) -> &'static str {
+        "Arab Accounting Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Argentine Peso
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct ARS;
 impl Currency for ARS {
     fn code(&self) -> &'static str {
@@ -155,10 +240,18 @@ impl Currency for ARS {
     fn numeric_code(&self) -> u32 {
         32
     }
+
+    fn name(&self) -> &'static str {
+        "Argentine Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AR"]
+    }
 }
 
 /// Armenian Dram
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct AMD;
 impl Currency for AMD {
     fn code(&self) -> &'static str {
@@ -172,10 +265,18 @@ impl Currency for AMD {
     fn numeric_code(&self) -> u32 {
         51
     }
+
+    fn name(&self) -> &'static str {
+        "Armenian Dram"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AM"]
+    }
 }
 
 /// Aruban Florin
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct AWG;
 impl Currency for AWG {
     fn code(&self) -> &'static str {
@@ -189,10 +290,18 @@ impl Currency for AWG {
     fn numeric_code(&self) -> u32 {
         533
     }
+
+    fn name(&self) -> &'static str {
+        "Aruban Florin"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AW"]
+    }
 }
 
 /// Australian Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct AUD;
 impl Currency for AUD {
     fn code(&self) -> &'static str {
@@ -206,10 +315,18 @@ impl Currency for AUD {
     fn numeric_code(&self) -> u32 {
         36
     }
+
+    fn name(&self) -> &'static str {
+        "Australian Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AU", "CC", "CX", "HM", "KI", "NF", "NR", "TV"]
+    }
 }
 
 /// Azerbaijan Manat
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct AZN;
 impl Currency for AZN {
     fn code(&self) -> &'static str {
@@ -223,10 +340,18 @@ impl Currency for AZN {
     fn numeric_code(&self) -> u32 {
         944
     }
+
+    fn name(&self) -> &'static str {
+        "Azerbaijan Manat"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AZ"]
+    }
 }
 
 /// Bahamian Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BSD;
 impl Currency for BSD {
     fn code(&self) -> &'static str {
@@ -240,10 +365,18 @@ impl Currency for BSD {
     fn numeric_code(&self) -> u32 {
         44
     }
+
+    fn name(&self) -> &'static str {
+        "Bahamian Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BS"]
+    }
 }
 
 /// Bahraini Dinar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BHD;
 impl Currency for BHD {
     fn code(&self) -> &'static str {
@@ -257,10 +390,18 @@ impl Currency for BHD {
     fn numeric_code(&self) -> u32 {
         48
     }
+
+    fn name(&self) -> &'static str {
+        "Bahraini Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BH"]
+    }
 }
 
 /// Taka
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BDT;
 impl Currency for BDT {
     fn code(&self) -> &'static str {
@@ -274,10 +415,18 @@ impl Currency for BDT {
     fn numeric_code(&self) -> u32 {
         50
     }
+
+    fn name(&self) -> &'static str {
+        "Taka"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BD"]
+    }
 }
 
 /// Barbados Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BBD;
 impl Currency for BBD {
     fn code(&self) -> &'static str {
@@ -291,10 +440,18 @@ impl Currency for BBD {
     fn numeric_code(&self) -> u32 {
         52
     }
+
+    fn name(&self) -> &'static str {
+        "Barbados Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BB"]
+    }
 }
 
 /// Belarusian Ruble
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BYN;
 impl Currency for BYN {
     fn code(&self) -> &'static str {
@@ -308,10 +465,18 @@ impl Currency for BYN {
     fn numeric_code(&self) -> u32 {
         933
     }
+
+    fn name(&self) -> &'static str {
+        "Belarusian Ruble"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BY"]
+    }
 }
 
 /// Belize Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BZD;
 impl Currency for BZD {
     fn code(&self) -> &'static str {
@@ -325,10 +490,18 @@ impl Currency for BZD {
     fn numeric_code(&self) -> u32 {
         84
     }
+
+    fn name(&self) -> &'static str {
+        "Belize Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BZ"]
+    }
 }
 
 /// CFA Franc BCEAO
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XOF;
 impl Currency for XOF {
     fn code(&self) -> &'static str {
@@ -342,10 +515,22 @@ impl Currency for XOF {
     fn numeric_code(&self) -> u32 {
         952
     }
+
+    fn name(&self) -> &'static str {
+        "CFA Franc BCEAO"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BF", "BJ", "CI", "GW", "ML", "NE", "SN", "TG"]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Bermudian Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BMD;
 impl Currency for BMD {
     fn code(&self) -> &'static str {
@@ -359,10 +544,18 @@ impl Currency for BMD {
     fn numeric_code(&self) -> u32 {
         60
     }
+
+    fn name(&self) -> &'static str {
+        "Bermudian Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BM"]
+    }
 }
 
 /// Indian Rupee
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct INR;
 impl Currency for INR {
     fn code(&self) -> &'static str {
@@ -376,10 +569,18 @@ impl Currency for INR {
     fn numeric_code(&self) -> u32 {
         356
     }
+
+    fn name(&self) -> &'static str {
+        "Indian Rupee"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BT", "IN"]
+    }
 }
 
 /// Ngultrum
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BTN;
 impl Currency for BTN {
     fn code(&self) -> &'static str {
@@ -393,10 +594,18 @@ impl Currency for BTN {
     fn numeric_code(&self) -> u32 {
         64
     }
+
+    fn name(&self) -> &'static str {
+        "Ngultrum"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BT"]
+    }
 }
 
 /// Boliviano
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BOB;
 impl Currency for BOB {
     fn code(&self) -> &'static str {
@@ -410,10 +619,18 @@ impl Currency for BOB {
     fn numeric_code(&self) -> u32 {
         68
     }
+
+    fn name(&self) -> &'static str {
+        "Boliviano"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BO"]
+    }
 }
 
 /// Mvdol
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BOV;
 impl Currency for BOV {
     fn code(&self) -> &'static str {
@@ -427,10 +644,22 @@ impl Currency for BOV {
     fn numeric_code(&self) -> u32 {
         984
     }
+
+    fn name(&self) -> &'static str {
+        "Mvdol"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BO"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
 }
 
 /// Convertible Mark
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BAM;
 impl Currency for BAM {
     fn code(&self) -> &'static str {
@@ -444,10 +673,18 @@ impl Currency for BAM {
     fn numeric_code(&self) -> u32 {
         977
     }
+
+    fn name(&self) -> &'static str {
+        "Convertible Mark"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BA"]
+    }
 }
 
 /// Pula
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BWP;
 impl Currency for BWP {
     fn code(&self) -> &'static str {
@@ -461,10 +698,18 @@ impl Currency for BWP {
     fn numeric_code(&self) -> u32 {
         72
     }
+
+    fn name(&self) -> &'static str {
+        "Pula"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BW"]
+    }
 }
 
 /// Norwegian Krone
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct NOK;
 impl Currency for NOK {
     fn code(&self) -> &'static str {
@@ -478,10 +723,18 @@ impl Currency for NOK {
     fn numeric_code(&self) -> u32 {
         578
     }
+
+    fn name(&self) -> &'static str {
+        "Norwegian Krone"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BV", "NO", "SJ"]
+    }
 }
 
 /// Brazilian Real
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BRL;
 impl Currency for BRL {
     fn code(&self) -> &'static str {
@@ -495,10 +748,18 @@ impl Currency for BRL {
     fn numeric_code(&self) -> u32 {
         986
     }
+
+    fn name(&self) -> &'static str {
+        "Brazilian Real"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BR"]
+    }
 }
 
 /// Brunei Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BND;
 impl Currency for BND {
     fn code(&self) -> &'static str {
@@ -512,10 +773,18 @@ impl Currency for BND {
     fn numeric_code(&self) -> u32 {
         96
     }
+
+    fn name(&self) -> &'static str {
+        "Brunei Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BN"]
+    }
 }
 
 /// Burundi Franc
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct BIF;
 impl Currency for BIF {
     fn code(&self) -> &'static str {
@@ -529,10 +798,18 @@ impl Currency for BIF {
     fn numeric_code(&self) -> u32 {
         108
     }
+
+    fn name(&self) -> &'static str {
+        "Burundi Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BI"]
+    }
 }
 
 /// Cabo Verde Escudo
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CVE;
 impl Currency for CVE {
     fn code(&self) -> &'static str {
@@ -546,10 +823,18 @@ impl Currency for CVE {
     fn numeric_code(&self) -> u32 {
         132
     }
+
+    fn name(&self) -> &'static str {
+        "Cabo Verde Escudo"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CV"]
+    }
 }
 
 /// Riel
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct KHR;
 impl Currency for KHR {
     fn code(&self) -> &'static str {
@@ -563,10 +848,18 @@ impl Currency for KHR {
     fn numeric_code(&self) -> u32 {
         116
     }
+
+    fn name(&self) -> &'static str {
+        "Riel"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["KH"]
+    }
 }
 
 /// CFA Franc BEAC
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XAF;
 impl Currency for XAF {
     fn code(&self) -> &'static str {
@@ -580,10 +873,22 @@ impl Currency for XAF {
     fn numeric_code(&self) -> u32 {
         950
     }
+
+    fn name(&self) -> &'static str {
+        "CFA Franc BEAC"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CF", "CG", "CM", "GA", "GQ", "TD"]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Canadian Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CAD;
 impl Currency for CAD {
     fn code(&self) -> &'static str {
@@ -597,10 +902,18 @@ impl Currency for CAD {
     fn numeric_code(&self) -> u32 {
         124
     }
+
+    fn name(&self) -> &'static str {
+        "Canadian Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CA"]
+    }
 }
 
 /// Cayman Islands Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct KYD;
 impl Currency for KYD {
     fn code(&self) -> &'static str {
@@ -614,10 +927,18 @@ impl Currency for KYD {
     fn numeric_code(&self) -> u32 {
         136
     }
+
+    fn name(&self) -> &'static str {
+        "Cayman Islands Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["KY"]
+    }
 }
 
 /// Chilean Peso
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CLP;
 impl Currency for CLP {
     fn code(&self) -> &'static str {
@@ -631,10 +952,18 @@ impl Currency for CLP {
     fn numeric_code(&self) -> u32 {
         152
     }
+
+    fn name(&self) -> &'static str {
+        "Chilean Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CL"]
+    }
 }
 
 /// Unidad de Fomento
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CLF;
 impl Currency for CLF {
     fn code(&self) -> &'static str {
@@ -648,10 +977,22 @@ impl Currency for CLF {
     fn numeric_code(&self) -> u32 {
         990
     }
+
+    fn name(&self) -> &'static str {
+        "Unidad de Fomento"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CL"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
 }
 
 /// Yuan Renminbi
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CNY;
 impl Currency for CNY {
     fn code(&self) -> &'static str {
@@ -665,10 +1006,18 @@ impl Currency for CNY {
     fn numeric_code(&self) -> u32 {
         156
     }
+
+    fn name(&self) -> &'static str {
+        "Yuan Renminbi"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CN"]
+    }
 }
 
 /// Colombian Peso
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct COP;
 impl Currency for COP {
     fn code(&self) -> &'static str {
@@ -682,10 +1031,18 @@ impl Currency for COP {
     fn numeric_code(&self) -> u32 {
         170
     }
+
+    fn name(&self) -> &'static str {
+        "Colombian Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CO"]
+    }
 }
 
 /// Unidad de Valor Real
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct COU;
 impl Currency for COU {
     fn code(&self) -> &'static str {
@@ -699,10 +1056,22 @@ impl Currency for COU {
     fn numeric_code(&self) -> u32 {
         970
     }
+
+    fn name(&self) -> &'static str {
+        "Unidad de Valor Real"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CO"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
 }
 
 /// Comorian Franc
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct KMF;
 impl Currency for KMF {
     fn code(&self) -> &'static str {
@@ -716,10 +1085,18 @@ impl Currency for KMF {
     fn numeric_code(&self) -> u32 {
         174
     }
+
+    fn name(&self) -> &'static str {
+        "Comorian Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["KM"]
+    }
 }
 
 /// Congolese Franc
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CDF;
 impl Currency for CDF {
     fn code(&self) -> &'static str {
@@ -733,10 +1110,18 @@ impl Currency for CDF {
     fn numeric_code(&self) -> u32 {
         976
     }
+
+    fn name(&self) -> &'static str {
+        "Congolese Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CD"]
+    }
 }
 
 /// New Zealand Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct NZD;
 impl Currency for NZD {
     fn code(&self) -> &'static str {
@@ -750,10 +1135,18 @@ impl Currency for NZD {
     fn numeric_code(&self) -> u32 {
         554
     }
+
+    fn name(&self) -> &'static str {
+        "New Zealand Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CK", "NU", "NZ", "PN", "TK"]
+    }
 }
 
 /// Costa Rican Colon
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CRC;
 impl Currency for CRC {
     fn code(&self) -> &'static str {
@@ -767,10 +1160,18 @@ impl Currency for CRC {
     fn numeric_code(&self) -> u32 {
         188
     }
+
+    fn name(&self) -> &'static str {
+        "Costa Rican Colon"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CR"]
+    }
 }
 
 /// Cuban Peso
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CUP;
 impl Currency for CUP {
     fn code(&self) -> &'static str {
@@ -784,10 +1185,18 @@ impl Currency for CUP {
     fn numeric_code(&self) -> u32 {
         192
     }
+
+    fn name(&self) -> &'static str {
+        "Cuban Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CU"]
+    }
 }
 
 /// Caribbean Guilder
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XCG;
 impl Currency for XCG {
     fn code(&self) -> &'static str {
@@ -801,10 +1210,22 @@ impl Currency for XCG {
     fn numeric_code(&self) -> u32 {
         532
     }
+
+    fn name(&self) -> &'static str {
+        "Caribbean Guilder"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CW", "SX"]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Czech Koruna
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CZK;
 impl Currency for CZK {
     fn code(&self) -> &'static str {
@@ -818,10 +1239,18 @@ impl Currency for CZK {
     fn numeric_code(&self) -> u32 {
         203
     }
+
+    fn name(&self) -> &'static str {
+        "Czech Koruna"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CZ"]
+    }
 }
 
 /// Danish Krone
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct DKK;
 impl Currency for DKK {
     fn code(&self) -> &'static str {
@@ -835,10 +1264,18 @@ impl Currency for DKK {
     fn numeric_code(&self) -> u32 {
         208
     }
+
+    fn name(&self) -> &'static str {
+        "Danish Krone"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["DK", "FO", "GL"]
+    }
 }
 
 /// Djibouti Franc
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct DJF;
 impl Currency for DJF {
     fn code(&self) -> &'static str {
@@ -852,10 +1289,18 @@ impl Currency for DJF {
     fn numeric_code(&self) -> u32 {
         262
     }
+
+    fn name(&self) -> &'static str {
+        "Djibouti Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["DJ"]
+    }
 }
 
 /// Dominican Peso
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct DOP;
 impl Currency for DOP {
     fn code(&self) -> &'static str {
@@ -869,10 +1314,18 @@ impl Currency for DOP {
     fn numeric_code(&self) -> u32 {
         214
     }
+
+    fn name(&self) -> &'static str {
+        "Dominican Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["DO"]
+    }
 }
 
 /// Egyptian Pound
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct EGP;
 impl Currency for EGP {
     fn code(&self) -> &'static str {
@@ -886,10 +1339,18 @@ impl Currency for EGP {
     fn numeric_code(&self) -> u32 {
         818
     }
+
+    fn name(&self) -> &'static str {
+        "Egyptian Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["EG"]
+    }
 }
 
 /// El Salvador Colon
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SVC;
 impl Currency for SVC {
     fn code(&self) -> &'static str {
@@ -903,10 +1364,18 @@ impl Currency for SVC {
     fn numeric_code(&self) -> u32 {
         222
     }
+
+    fn name(&self) -> &'static str {
+        "El Salvador Colon"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SV"]
+    }
 }
 
 /// Nakfa
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct ERN;
 impl Currency for ERN {
     fn code(&self) -> &'static str {
@@ -920,10 +1389,18 @@ impl Currency for ERN {
     fn numeric_code(&self) -> u32 {
         232
     }
+
+    fn name(&self) -> &'static str {
+        "Nakfa"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ER"]
+    }
 }
 
 /// Lilangeni
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SZL;
 impl Currency for SZL {
     fn code(&self) -> &'static str {
@@ -937,10 +1414,18 @@ impl Currency for SZL {
     fn numeric_code(&self) -> u32 {
         748
     }
+
+    fn name(&self) -> &'static str {
+        "Lilangeni"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SZ"]
+    }
 }
 
 /// Ethiopian Birr
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct ETB;
 impl Currency for ETB {
     fn code(&self) -> &'static str {
@@ -954,10 +1439,18 @@ impl Currency for ETB {
     fn numeric_code(&self) -> u32 {
         230
     }
+
+    fn name(&self) -> &'static str {
+        "Ethiopian Birr"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ET"]
+    }
 }
 
 /// Falkland Islands Pound
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct FKP;
 impl Currency for FKP {
     fn code(&self) -> &'static str {
@@ -971,10 +1464,18 @@ impl Currency for FKP {
     fn numeric_code(&self) -> u32 {
         238
     }
+
+    fn name(&self) -> &'static str {
+        "Falkland Islands Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["FK"]
+    }
 }
 
 /// Fiji Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct FJD;
 impl Currency for FJD {
     fn code(&self) -> &'static str {
@@ -988,10 +1489,18 @@ impl Currency for FJD {
     fn numeric_code(&self) -> u32 {
         242
     }
+
+    fn name(&self) -> &'static str {
+        "Fiji Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["FJ"]
+    }
 }
 
 /// CFP Franc
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XPF;
 impl Currency for XPF {
     fn code(&self) -> &'static str {
@@ -1005,13 +1514,25 @@ impl Currency for XPF {
     fn numeric_code(&self) -> u32 {
         953
     }
-}
 
-/// Dalasi
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct GMD;
-impl Currency for GMD {
-    fn code(&self) -> &'static str {
+    fn name(&self) -> &'static str {
+        "CFP Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["NC", "PF", "WF"]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
+}
+
+/// Dalasi
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GMD;
+impl Currency for GMD {
+    fn code(&self) -> &'static str {
         "GMD"
     }
 
@@ -1022,10 +1543,18 @@ impl Currency for GMD {
     fn numeric_code(&self) -> u32 {
         270
     }
+
+    fn name(&self) -> &'static str {
+        "Dalasi"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GM"]
+    }
 }
 
 /// Lari
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct GEL;
 impl Currency for GEL {
     fn code(&self) -> &'static str {
@@ -1039,10 +1568,18 @@ impl Currency for GEL {
     fn numeric_code(&self) -> u32 {
         981
     }
+
+    fn name(&self) -> &'static str {
+        "Lari"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GE"]
+    }
 }
 
 /// Ghana Cedi
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct GHS;
 impl Currency for GHS {
     fn code(&self) -> &'static str {
@@ -1056,10 +1593,18 @@ impl Currency for GHS {
     fn numeric_code(&self) -> u32 {
         936
     }
+
+    fn name(&self) -> &'static str {
+        "Ghana Cedi"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GH"]
+    }
 }
 
 /// Gibraltar Pound
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct GIP;
 impl Currency for GIP {
     fn code(&self) -> &'static str {
@@ -1073,10 +1618,18 @@ impl Currency for GIP {
     fn numeric_code(&self) -> u32 {
         292
     }
+
+    fn name(&self) -> &'static str {
+        "Gibraltar Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GI"]
+    }
 }
 
 /// Quetzal
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct GTQ;
 impl Currency for GTQ {
     fn code(&self) -> &'static str {
@@ -1090,10 +1643,18 @@ impl Currency for GTQ {
     fn numeric_code(&self) -> u32 {
         320
     }
+
+    fn name(&self) -> &'static str {
+        "Quetzal"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GT"]
+    }
 }
 
 /// Pound Sterling
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct GBP;
 impl Currency for GBP {
     fn code(&self) -> &'static str {
@@ -1107,10 +1668,18 @@ impl Currency for GBP {
     fn numeric_code(&self) -> u32 {
         826
     }
+
+    fn name(&self) -> &'static str {
+        "Pound Sterling"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GB", "GG", "IM", "JE"]
+    }
 }
 
 /// Guinean Franc
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct GNF;
 impl Currency for GNF {
     fn code(&self) -> &'static str {
@@ -1124,10 +1693,18 @@ impl Currency for GNF {
     fn numeric_code(&self) -> u32 {
         324
     }
+
+    fn name(&self) -> &'static str {
+        "Guinean Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GN"]
+    }
 }
 
 /// Guyana Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct GYD;
 impl Currency for GYD {
     fn code(&self) -> &'static str {
@@ -1141,10 +1718,18 @@ impl Currency for GYD {
     fn numeric_code(&self) -> u32 {
         328
     }
+
+    fn name(&self) -> &'static str {
+        "Guyana Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GY"]
+    }
 }
 
 /// Gourde
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct HTG;
 impl Currency for HTG {
     fn code(&self) -> &'static str {
@@ -1158,10 +1743,18 @@ impl Currency for HTG {
     fn numeric_code(&self) -> u32 {
         332
     }
+
+    fn name(&self) -> &'static str {
+        "Gourde"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["HT"]
+    }
 }
 
 /// Lempira
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct HNL;
 impl Currency for HNL {
     fn code(&self) -> &'static str {
@@ -1175,10 +1768,18 @@ impl Currency for HNL {
     fn numeric_code(&self) -> u32 {
         340
     }
+
+    fn name(&self) -> &'static str {
+        "Lempira"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["HN"]
+    }
 }
 
 /// Hong Kong Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct HKD;
 impl Currency for HKD {
     fn code(&self) -> &'static str {
@@ -1192,10 +1793,18 @@ impl Currency for HKD {
     fn numeric_code(&self) -> u32 {
         344
     }
+
+    fn name(&self) -> &'static str {
+        "Hong Kong Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["HK"]
+    }
 }
 
 /// Forint
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct HUF;
 impl Currency for HUF {
     fn code(&self) -> &'static str {
@@ -1209,10 +1818,18 @@ impl Currency for HUF {
     fn numeric_code(&self) -> u32 {
         348
     }
+
+    fn name(&self) -> &'static str {
+        "Forint"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["HU"]
+    }
 }
 
 /// Iceland Krona
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct ISK;
 impl Currency for ISK {
     fn code(&self) -> &'static str {
@@ -1226,10 +1843,18 @@ impl Currency for ISK {
     fn numeric_code(&self) -> u32 {
         352
     }
+
+    fn name(&self) -> &'static str {
+        "Iceland Krona"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["IS"]
+    }
 }
 
 /// Rupiah
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct IDR;
 impl Currency for IDR {
     fn code(&self) -> &'static str {
@@ -1243,10 +1868,18 @@ impl Currency for IDR {
     fn numeric_code(&self) -> u32 {
         360
     }
+
+    fn name(&self) -> &'static str {
+        "Rupiah"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ID"]
+    }
 }
 
 /// SDR (Special Drawing Right)
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XDR;
 impl Currency for XDR {
     fn code(&self) -> &'static str {
@@ -1260,10 +1893,22 @@ impl Currency for XDR {
     fn numeric_code(&self) -> u32 {
         960
     }
+
+    fn name(&self) -> &'static str {
+        "SDR (Special Drawing Right)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Iranian Rial
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct IRR;
 impl Currency for IRR {
     fn code(&self) -> &'static str {
@@ -1277,10 +1922,18 @@ impl Currency for IRR {
     fn numeric_code(&self) -> u32 {
         364
     }
+
+    fn name(&self) -> &'static str {
+        "Iranian Rial"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["IR"]
+    }
 }
 
 /// Iraqi Dinar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct IQD;
 impl Currency for IQD {
     fn code(&self) -> &'static str {
@@ -1294,10 +1947,18 @@ impl Currency for IQD {
     fn numeric_code(&self) -> u32 {
         368
     }
+
+    fn name(&self) -> &'static str {
+        "Iraqi Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["IQ"]
+    }
 }
 
 /// New Israeli Sheqel
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct ILS;
 impl Currency for ILS {
     fn code(&self) -> &'static str {
@@ -1311,10 +1972,18 @@ impl Currency for ILS {
     fn numeric_code(&self) -> u32 {
         376
     }
+
+    fn name(&self) -> &'static str {
+        "New Israeli Sheqel"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["IL"]
+    }
 }
 
 /// Jamaican Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct JMD;
 impl Currency for JMD {
     fn code(&self) -> &'static str {
@@ -1328,10 +1997,18 @@ impl Currency for JMD {
     fn numeric_code(&self) -> u32 {
         388
     }
+
+    fn name(&self) -> &'static str {
+        "Jamaican Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["JM"]
+    }
 }
 
 /// Yen
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct JPY;
 impl Currency for JPY {
     fn code(&self) -> &'static str {
@@ -1345,10 +2022,18 @@ impl Currency for JPY {
     fn numeric_code(&self) -> u32 {
         392
     }
+
+    fn name(&self) -> &'static str {
+        "Yen"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["JP"]
+    }
 }
 
 /// Jordanian Dinar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct JOD;
 impl Currency for JOD {
     fn code(&self) -> &'static str {
@@ -1362,10 +2047,18 @@ impl Currency for JOD {
     fn numeric_code(&self) -> u32 {
         400
     }
+
+    fn name(&self) -> &'static str {
+        "Jordanian Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["JO"]
+    }
 }
 
 /// Tenge
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct KZT;
 impl Currency for KZT {
     fn code(&self) -> &'static str {
@@ -1379,10 +2072,18 @@ impl Currency for KZT {
     fn numeric_code(&self) -> u32 {
         398
     }
+
+    fn name(&self) -> &'static str {
+        "Tenge"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["KZ"]
+    }
 }
 
 /// Kenyan Shilling
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct KES;
 impl Currency for KES {
     fn code(&self) -> &'static str {
@@ -1396,10 +2097,18 @@ impl Currency for KES {
     fn numeric_code(&self) -> u32 {
         404
     }
+
+    fn name(&self) -> &'static str {
+        "Kenyan Shilling"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["KE"]
+    }
 }
 
 /// North Korean Won
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct KPW;
 impl Currency for KPW {
     fn code(&self) -> &'static str {
@@ -1413,10 +2122,18 @@ impl Currency for KPW {
     fn numeric_code(&self) -> u32 {
         408
     }
+
+    fn name(&self) -> &'static str {
+        "North Korean Won"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["KP"]
+    }
 }
 
 /// Won
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct KRW;
 impl Currency for KRW {
     fn code(&self) -> &'static str {
@@ -1430,10 +2147,18 @@ impl Currency for KRW {
     fn numeric_code(&self) -> u32 {
         410
     }
+
+    fn name(&self) -> &'static str {
+        "Won"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["KR"]
+    }
 }
 
 /// Kuwaiti Dinar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct KWD;
 impl Currency for KWD {
     fn code(&self) -> &'static str {
@@ -1447,10 +2172,18 @@ impl Currency for KWD {
     fn numeric_code(&self) -> u32 {
         414
     }
+
+    fn name(&self) -> &'static str {
+        "Kuwaiti Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["KW"]
+    }
 }
 
 /// Som
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct KGS;
 impl Currency for KGS {
     fn code(&self) -> &'static str {
@@ -1464,10 +2197,18 @@ impl Currency for KGS {
     fn numeric_code(&self) -> u32 {
         417
     }
+
+    fn name(&self) -> &'static str {
+        "Som"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["KG"]
+    }
 }
 
 /// Lao Kip
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct LAK;
 impl Currency for LAK {
     fn code(&self) -> &'static str {
@@ -1481,10 +2222,18 @@ impl Currency for LAK {
     fn numeric_code(&self) -> u32 {
         418
     }
+
+    fn name(&self) -> &'static str {
+        "Lao Kip"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LA"]
+    }
 }
 
 /// Lebanese Pound
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct LBP;
 impl Currency for LBP {
     fn code(&self) -> &'static str {
@@ -1498,10 +2247,18 @@ impl Currency for LBP {
     fn numeric_code(&self) -> u32 {
         422
     }
+
+    fn name(&self) -> &'static str {
+        "Lebanese Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LB"]
+    }
 }
 
 /// Loti
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct LSL;
 impl Currency for LSL {
     fn code(&self) -> &'static str {
@@ -1515,10 +2272,18 @@ impl Currency for LSL {
     fn numeric_code(&self) -> u32 {
         426
     }
+
+    fn name(&self) -> &'static str {
+        "Loti"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LS"]
+    }
 }
 
 /// Rand
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct ZAR;
 impl Currency for ZAR {
     fn code(&self) -> &'static str {
@@ -1532,10 +2297,18 @@ impl Currency for ZAR {
     fn numeric_code(&self) -> u32 {
         710
     }
+
+    fn name(&self) -> &'static str {
+        "Rand"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LS", "NA", "ZA"]
+    }
 }
 
 /// Liberian Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct LRD;
 impl Currency for LRD {
     fn code(&self) -> &'static str {
@@ -1549,10 +2322,18 @@ impl Currency for LRD {
     fn numeric_code(&self) -> u32 {
         430
     }
+
+    fn name(&self) -> &'static str {
+        "Liberian Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LR"]
+    }
 }
 
 /// Libyan Dinar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct LYD;
 impl Currency for LYD {
     fn code(&self) -> &'static str {
@@ -1566,10 +2347,18 @@ impl Currency for LYD {
     fn numeric_code(&self) -> u32 {
         434
     }
+
+    fn name(&self) -> &'static str {
+        "Libyan Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LY"]
+    }
 }
 
 /// Swiss Franc
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CHF;
 impl Currency for CHF {
     fn code(&self) -> &'static str {
@@ -1583,10 +2372,18 @@ impl Currency for CHF {
     fn numeric_code(&self) -> u32 {
         756
     }
+
+    fn name(&self) -> &'static str {
+        "Swiss Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CH", "LI"]
+    }
 }
 
 /// Pataca
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MOP;
 impl Currency for MOP {
     fn code(&self) -> &'static str {
@@ -1600,10 +2397,18 @@ impl Currency for MOP {
     fn numeric_code(&self) -> u32 {
         446
     }
+
+    fn name(&self) -> &'static str {
+        "Pataca"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MO"]
+    }
 }
 
 /// Denar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MKD;
 impl Currency for MKD {
     fn code(&self) -> &'static str {
@@ -1617,10 +2422,18 @@ impl Currency for MKD {
     fn numeric_code(&self) -> u32 {
         807
     }
+
+    fn name(&self) -> &'static str {
+        "Denar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MK"]
+    }
 }
 
 /// Malagasy Ariary
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MGA;
 impl Currency for MGA {
     fn code(&self) -> &'static str {
@@ -1634,10 +2447,18 @@ impl Currency for MGA {
     fn numeric_code(&self) -> u32 {
         969
     }
+
+    fn name(&self) -> &'static str {
+        "Malagasy Ariary"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MG"]
+    }
 }
 
 /// Malawi Kwacha
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MWK;
 impl Currency for MWK {
     fn code(&self) -> &'static str {
@@ -1651,10 +2472,18 @@ impl Currency for MWK {
     fn numeric_code(&self) -> u32 {
         454
     }
+
+    fn name(&self) -> &'static str {
+        "Malawi Kwacha"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MW"]
+    }
 }
 
 /// Malaysian Ringgit
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MYR;
 impl Currency for MYR {
     fn code(&self) -> &'static str {
@@ -1668,10 +2497,18 @@ impl Currency for MYR {
     fn numeric_code(&self) -> u32 {
         458
     }
+
+    fn name(&self) -> &'static str {
+        "Malaysian Ringgit"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MY"]
+    }
 }
 
 /// Rufiyaa
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MVR;
 impl Currency for MVR {
     fn code(&self) -> &'static str {
@@ -1685,10 +2522,18 @@ impl Currency for MVR {
     fn numeric_code(&self) -> u32 {
         462
     }
+
+    fn name(&self) -> &'static str {
+        "Rufiyaa"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MV"]
+    }
 }
 
 /// Ouguiya
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MRU;
 impl Currency for MRU {
     fn code(&self) -> &'static str {
@@ -1702,10 +2547,18 @@ impl Currency for MRU {
     fn numeric_code(&self) -> u32 {
         929
     }
+
+    fn name(&self) -> &'static str {
+        "Ouguiya"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MR"]
+    }
 }
 
 /// Mauritius Rupee
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MUR;
 impl Currency for MUR {
     fn code(&self) -> &'static str {
@@ -1719,10 +2572,18 @@ impl Currency for MUR {
     fn numeric_code(&self) -> u32 {
         480
     }
+
+    fn name(&self) -> &'static str {
+        "Mauritius Rupee"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MU"]
+    }
 }
 
 /// ADB Unit of Account
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XUA;
 impl Currency for XUA {
     fn code(&self) -> &'static str {
@@ -1736,10 +2597,22 @@ impl Currency for XUA {
     fn numeric_code(&self) -> u32 {
         965
     }
+
+    fn name(&self) -> &'static str {
+        "ADB Unit of Account"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Mexican Peso
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MXN;
 impl Currency for MXN {
     fn code(&self) -> &'static str {
@@ -1753,10 +2626,18 @@ impl Currency for MXN {
     fn numeric_code(&self) -> u32 {
         484
     }
+
+    fn name(&self) -> &'static str {
+        "Mexican Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MX"]
+    }
 }
 
 /// Mexican Unidad de Inversion (UDI)
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MXV;
 impl Currency for MXV {
     fn code(&self) -> &'static str {
@@ -1770,10 +2651,22 @@ impl Currency for MXV {
     fn numeric_code(&self) -> u32 {
         979
     }
+
+    fn name(&self) -> &'static str {
+        "Mexican Unidad de Inversion (UDI)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MX"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
 }
 
 /// Moldovan Leu
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MDL;
 impl Currency for MDL {
     fn code(&self) -> &'static str {
@@ -1787,10 +2680,18 @@ impl Currency for MDL {
     fn numeric_code(&self) -> u32 {
         498
     }
+
+    fn name(&self) -> &'static str {
+        "Moldovan Leu"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MD"]
+    }
 }
 
 /// Tugrik
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MNT;
 impl Currency for MNT {
     fn code(&self) -> &'static str {
@@ -1804,10 +2705,18 @@ impl Currency for MNT {
     fn numeric_code(&self) -> u32 {
         496
     }
+
+    fn name(&self) -> &'static str {
+        "Tugrik"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MN"]
+    }
 }
 
 /// Moroccan Dirham
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MAD;
 impl Currency for MAD {
     fn code(&self) -> &'static str {
@@ -1821,10 +2730,18 @@ impl Currency for MAD {
     fn numeric_code(&self) -> u32 {
         504
     }
+
+    fn name(&self) -> &'static str {
+        "Moroccan Dirham"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["EH", "MA"]
+    }
 }
 
 /// Mozambique Metical
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MZN;
 impl Currency for MZN {
     fn code(&self) -> &'static str {
@@ -1838,10 +2755,18 @@ impl Currency for MZN {
     fn numeric_code(&self) -> u32 {
         943
     }
+
+    fn name(&self) -> &'static str {
+        "Mozambique Metical"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MZ"]
+    }
 }
 
 /// Kyat
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct MMK;
 impl Currency for MMK {
     fn code(&self) -> &'static str {
@@ -1855,10 +2780,18 @@ impl Currency for MMK {
     fn numeric_code(&self) -> u32 {
         104
     }
+
+    fn name(&self) -> &'static str {
+        "Kyat"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MM"]
+    }
 }
 
 /// Namibia Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct NAD;
 impl Currency for NAD {
     fn code(&self) -> &'static str {
@@ -1872,10 +2805,18 @@ impl Currency for NAD {
     fn numeric_code(&self) -> u32 {
         516
     }
+
+    fn name(&self) -> &'static str {
+        "Namibia Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["NA"]
+    }
 }
 
 /// Nepalese Rupee
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct NPR;
 impl Currency for NPR {
     fn code(&self) -> &'static str {
@@ -1889,10 +2830,18 @@ impl Currency for NPR {
     fn numeric_code(&self) -> u32 {
         524
     }
+
+    fn name(&self) -> &'static str {
+        "Nepalese Rupee"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["NP"]
+    }
 }
 
 /// Cordoba Oro
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct NIO;
 impl Currency for NIO {
     fn code(&self) -> &'static str {
@@ -1906,10 +2855,18 @@ impl Currency for NIO {
     fn numeric_code(&self) -> u32 {
         558
     }
+
+    fn name(&self) -> &'static str {
+        "Cordoba Oro"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["NI"]
+    }
 }
 
 /// Naira
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct NGN;
 impl Currency for NGN {
     fn code(&self) -> &'static str {
@@ -1923,10 +2880,18 @@ impl Currency for NGN {
     fn numeric_code(&self) -> u32 {
         566
     }
+
+    fn name(&self) -> &'static str {
+        "Naira"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["NG"]
+    }
 }
 
 /// Rial Omani
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct OMR;
 impl Currency for OMR {
     fn code(&self) -> &'static str {
@@ -1940,10 +2905,18 @@ impl Currency for OMR {
     fn numeric_code(&self) -> u32 {
         512
     }
+
+    fn name(&self) -> &'static str {
+        "Rial Omani"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["OM"]
+    }
 }
 
 /// Pakistan Rupee
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct PKR;
 impl Currency for PKR {
     fn code(&self) -> &'static str {
@@ -1957,10 +2930,18 @@ impl Currency for PKR {
     fn numeric_code(&self) -> u32 {
         586
     }
+
+    fn name(&self) -> &'static str {
+        "Pakistan Rupee"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PK"]
+    }
 }
 
 /// Balboa
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct PAB;
 impl Currency for PAB {
     fn code(&self) -> &'static str {
@@ -1974,10 +2955,18 @@ impl Currency for PAB {
     fn numeric_code(&self) -> u32 {
         590
     }
+
+    fn name(&self) -> &'static str {
+        "Balboa"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PA"]
+    }
 }
 
 /// Kina
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct PGK;
 impl Currency for PGK {
     fn code(&self) -> &'static str {
@@ -1991,10 +2980,18 @@ impl Currency for PGK {
     fn numeric_code(&self) -> u32 {
         598
     }
+
+    fn name(&self) -> &'static str {
+        "Kina"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PG"]
+    }
 }
 
 /// Guarani
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct PYG;
 impl Currency for PYG {
     fn code(&self) -> &'static str {
@@ -2008,10 +3005,18 @@ impl Currency for PYG {
     fn numeric_code(&self) -> u32 {
         600
     }
+
+    fn name(&self) -> &'static str {
+        "Guarani"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PY"]
+    }
 }
 
 /// Sol
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct PEN;
 impl Currency for PEN {
     fn code(&self) -> &'static str {
@@ -2025,11 +3030,19 @@ impl Currency for PEN {
     fn numeric_code(&self) -> u32 {
         604
     }
-}
 
-/// Philippine Peso
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-pub struct PHP;
+    fn name(&self) -> &'static str {
+        "Sol"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PE"]
+    }
+}
+
+/// Philippine Peso
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct PHP;
 impl Currency for PHP {
     fn code(&self) -> &'static str {
         "PHP"
@@ -2042,10 +3055,18 @@ impl Currency for PHP {
     fn numeric_code(&self) -> u32 {
         608
     }
+
+    fn name(&self) -> &'static str {
+        "Philippine Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PH"]
+    }
 }
 
 /// Zloty
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct PLN;
 impl Currency for PLN {
     fn code(&self) -> &'static str {
@@ -2059,10 +3080,18 @@ impl Currency for PLN {
     fn numeric_code(&self) -> u32 {
         985
     }
+
+    fn name(&self) -> &'static str {
+        "Zloty"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PL"]
+    }
 }
 
 /// Qatari Rial
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct QAR;
 impl Currency for QAR {
     fn code(&self) -> &'static str {
@@ -2076,10 +3105,18 @@ impl Currency for QAR {
     fn numeric_code(&self) -> u32 {
         634
     }
+
+    fn name(&self) -> &'static str {
+        "Qatari Rial"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["QA"]
+    }
 }
 
 /// Romanian Leu
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct RON;
 impl Currency for RON {
     fn code(&self) -> &'static str {
@@ -2093,10 +3130,18 @@ impl Currency for RON {
     fn numeric_code(&self) -> u32 {
         946
     }
+
+    fn name(&self) -> &'static str {
+        "Romanian Leu"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["RO"]
+    }
 }
 
 /// Russian Ruble
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct RUB;
 impl Currency for RUB {
     fn code(&self) -> &'static str {
@@ -2110,10 +3155,18 @@ impl Currency for RUB {
     fn numeric_code(&self) -> u32 {
         643
     }
+
+    fn name(&self) -> &'static str {
+        "Russian Ruble"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["RU"]
+    }
 }
 
 /// Rwanda Franc
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct RWF;
 impl Currency for RWF {
     fn code(&self) -> &'static str {
@@ -2127,10 +3180,18 @@ impl Currency for RWF {
     fn numeric_code(&self) -> u32 {
         646
     }
+
+    fn name(&self) -> &'static str {
+        "Rwanda Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["RW"]
+    }
 }
 
 /// Saint Helena Pound
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SHP;
 impl Currency for SHP {
     fn code(&self) -> &'static str {
@@ -2144,10 +3205,18 @@ impl Currency for SHP {
     fn numeric_code(&self) -> u32 {
         654
     }
+
+    fn name(&self) -> &'static str {
+        "Saint Helena Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SH"]
+    }
 }
 
 /// Tala
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct WST;
 impl Currency for WST {
     fn code(&self) -> &'static str {
@@ -2161,10 +3230,18 @@ impl Currency for WST {
     fn numeric_code(&self) -> u32 {
         882
     }
+
+    fn name(&self) -> &'static str {
+        "Tala"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["WS"]
+    }
 }
 
 /// Dobra
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct STN;
 impl Currency for STN {
     fn code(&self) -> &'static str {
@@ -2178,10 +3255,18 @@ impl Currency for STN {
     fn numeric_code(&self) -> u32 {
         930
     }
+
+    fn name(&self) -> &'static str {
+        "Dobra"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ST"]
+    }
 }
 
 /// Saudi Riyal
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SAR;
 impl Currency for SAR {
     fn code(&self) -> &'static str {
@@ -2195,10 +3280,18 @@ impl Currency for SAR {
     fn numeric_code(&self) -> u32 {
         682
     }
+
+    fn name(&self) -> &'static str {
+        "Saudi Riyal"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SA"]
+    }
 }
 
 /// Serbian Dinar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct RSD;
 impl Currency for RSD {
     fn code(&self) -> &'static str {
@@ -2212,10 +3305,18 @@ impl Currency for RSD {
     fn numeric_code(&self) -> u32 {
         941
     }
+
+    fn name(&self) -> &'static str {
+        "Serbian Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["RS"]
+    }
 }
 
 /// Seychelles Rupee
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SCR;
 impl Currency for SCR {
     fn code(&self) -> &'static str {
@@ -2229,10 +3330,18 @@ impl Currency for SCR {
     fn numeric_code(&self) -> u32 {
         690
     }
+
+    fn name(&self) -> &'static str {
+        "Seychelles Rupee"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SC"]
+    }
 }
 
 /// Leone
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SLE;
 impl Currency for SLE {
     fn code(&self) -> &'static str {
@@ -2246,10 +3355,18 @@ impl Currency for SLE {
     fn numeric_code(&self) -> u32 {
         925
     }
+
+    fn name(&self) -> &'static str {
+        "Leone"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SL"]
+    }
 }
 
 /// Singapore Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SGD;
 impl Currency for SGD {
     fn code(&self) -> &'static str {
@@ -2263,10 +3380,18 @@ impl Currency for SGD {
     fn numeric_code(&self) -> u32 {
         702
     }
+
+    fn name(&self) -> &'static str {
+        "Singapore Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SG"]
+    }
 }
 
 /// Sucre
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XSU;
 impl Currency for XSU {
     fn code(&self) -> &'static str {
@@ -2280,10 +3405,22 @@ impl Currency for XSU {
     fn numeric_code(&self) -> u32 {
         994
     }
+
+    fn name(&self) -> &'static str {
+        "Sucre"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Solomon Islands Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SBD;
 impl Currency for SBD {
     fn code(&self) -> &'static str {
@@ -2297,10 +3434,18 @@ impl Currency for SBD {
     fn numeric_code(&self) -> u32 {
         90
     }
+
+    fn name(&self) -> &'static str {
+        "Solomon Islands Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SB"]
+    }
 }
 
 /// Somali Shilling
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SOS;
 impl Currency for SOS {
     fn code(&self) -> &'static str {
@@ -2314,10 +3459,18 @@ impl Currency for SOS {
     fn numeric_code(&self) -> u32 {
         706
     }
+
+    fn name(&self) -> &'static str {
+        "Somali Shilling"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SO"]
+    }
 }
 
 /// South Sudanese Pound
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SSP;
 impl Currency for SSP {
     fn code(&self) -> &'static str {
@@ -2331,10 +3484,18 @@ impl Currency for SSP {
     fn numeric_code(&self) -> u32 {
         728
     }
+
+    fn name(&self) -> &'static str {
+        "South Sudanese Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SS"]
+    }
 }
 
 /// Sri Lanka Rupee
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct LKR;
 impl Currency for LKR {
     fn code(&self) -> &'static str {
@@ -2348,10 +3509,18 @@ impl Currency for LKR {
     fn numeric_code(&self) -> u32 {
         144
     }
+
+    fn name(&self) -> &'static str {
+        "Sri Lanka Rupee"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LK"]
+    }
 }
 
 /// Sudanese Pound
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SDG;
 impl Currency for SDG {
     fn code(&self) -> &'static str {
@@ -2365,10 +3534,18 @@ impl Currency for SDG {
     fn numeric_code(&self) -> u32 {
         938
     }
+
+    fn name(&self) -> &'static str {
+        "Sudanese Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SD"]
+    }
 }
 
 /// Surinam Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SRD;
 impl Currency for SRD {
     fn code(&self) -> &'static str {
@@ -2382,10 +3559,18 @@ impl Currency for SRD {
     fn numeric_code(&self) -> u32 {
         968
     }
+
+    fn name(&self) -> &'static str {
+        "Surinam Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SR"]
+    }
 }
 
 /// Swedish Krona
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SEK;
 impl Currency for SEK {
     fn code(&self) -> &'static str {
@@ -2399,10 +3584,18 @@ impl Currency for SEK {
     fn numeric_code(&self) -> u32 {
         752
     }
+
+    fn name(&self) -> &'static str {
+        "Swedish Krona"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SE"]
+    }
 }
 
 /// WIR Euro
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CHE;
 impl Currency for CHE {
     fn code(&self) -> &'static str {
@@ -2416,10 +3609,22 @@ impl Currency for CHE {
     fn numeric_code(&self) -> u32 {
         947
     }
+
+    fn name(&self) -> &'static str {
+        "WIR Euro"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CH"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
 }
 
 /// WIR Franc
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct CHW;
 impl Currency for CHW {
     fn code(&self) -> &'static str {
@@ -2433,10 +3638,22 @@ impl Currency for CHW {
     fn numeric_code(&self) -> u32 {
         948
     }
+
+    fn name(&self) -> &'static str {
+        "WIR Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CH"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
 }
 
 /// Syrian Pound
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct SYP;
 impl Currency for SYP {
     fn code(&self) -> &'static str {
@@ -2450,10 +3667,18 @@ impl Currency for SYP {
     fn numeric_code(&self) -> u32 {
         760
     }
+
+    fn name(&self) -> &'static str {
+        "Syrian Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SY"]
+    }
 }
 
 /// New Taiwan Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct TWD;
 impl Currency for TWD {
     fn code(&self) -> &'static str {
@@ -2467,10 +3692,18 @@ impl Currency for TWD {
     fn numeric_code(&self) -> u32 {
         901
     }
+
+    fn name(&self) -> &'static str {
+        "New Taiwan Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TW"]
+    }
 }
 
 /// Somoni
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct TJS;
 impl Currency for TJS {
     fn code(&self) -> &'static str {
@@ -2484,10 +3717,18 @@ impl Currency for TJS {
     fn numeric_code(&self) -> u32 {
         972
     }
+
+    fn name(&self) -> &'static str {
+        "Somoni"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TJ"]
+    }
 }
 
 /// Tanzanian Shilling
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct TZS;
 impl Currency for TZS {
     fn code(&self) -> &'static str {
@@ -2501,10 +3742,18 @@ impl Currency for TZS {
     fn numeric_code(&self) -> u32 {
         834
     }
+
+    fn name(&self) -> &'static str {
+        "Tanzanian Shilling"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TZ"]
+    }
 }
 
 /// Baht
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct THB;
 impl Currency for THB {
     fn code(&self) -> &'static str {
@@ -2518,10 +3767,18 @@ impl Currency for THB {
     fn numeric_code(&self) -> u32 {
         764
     }
+
+    fn name(&self) -> &'static str {
+        "Baht"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TH"]
+    }
 }
 
 /// Pa’anga
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct TOP;
 impl Currency for TOP {
     fn code(&self) -> &'static str {
@@ -2535,10 +3792,18 @@ impl Currency for TOP {
     fn numeric_code(&self) -> u32 {
         776
     }
+
+    fn name(&self) -> &'static str {
+        "Pa’anga"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TO"]
+    }
 }
 
 /// Trinidad and Tobago Dollar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct TTD;
 impl Currency for TTD {
     fn code(&self) -> &'static str {
@@ -2552,10 +3817,18 @@ impl Currency for TTD {
     fn numeric_code(&self) -> u32 {
         780
     }
+
+    fn name(&self) -> &'static str {
+        "Trinidad and Tobago Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TT"]
+    }
 }
 
 /// Tunisian Dinar
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct TND;
 impl Currency for TND {
     fn code(&self) -> &'static str {
@@ -2569,10 +3842,18 @@ impl Currency for TND {
     fn numeric_code(&self) -> u32 {
         788
     }
+
+    fn name(&self) -> &'static str {
+        "Tunisian Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TN"]
+    }
 }
 
 /// Turkish Lira
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct TRY;
 impl Currency for TRY {
     fn code(&self) -> &'static str {
@@ -2586,10 +3867,18 @@ impl Currency for TRY {
     fn numeric_code(&self) -> u32 {
         949
     }
+
+    fn name(&self) -> &'static str {
+        "Turkish Lira"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TR"]
+    }
 }
 
 /// Turkmenistan New Manat
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct TMT;
 impl Currency for TMT {
     fn code(&self) -> &'static str {
@@ -2603,10 +3892,18 @@ impl Currency for TMT {
     fn numeric_code(&self) -> u32 {
         934
     }
+
+    fn name(&self) -> &'static str {
+        "Turkmenistan New Manat"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TM"]
+    }
 }
 
 /// Uganda Shilling
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct UGX;
 impl Currency for UGX {
     fn code(&self) -> &'static str {
@@ -2620,10 +3917,18 @@ impl Currency for UGX {
     fn numeric_code(&self) -> u32 {
         800
     }
+
+    fn name(&self) -> &'static str {
+        "Uganda Shilling"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UG"]
+    }
 }
 
 /// Hryvnia
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct UAH;
 impl Currency for UAH {
     fn code(&self) -> &'static str {
@@ -2637,10 +3942,18 @@ impl Currency for UAH {
     fn numeric_code(&self) -> u32 {
         980
     }
+
+    fn name(&self) -> &'static str {
+        "Hryvnia"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UA"]
+    }
 }
 
 /// UAE Dirham
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct AED;
 impl Currency for AED {
     fn code(&self) -> &'static str {
@@ -2654,10 +3967,18 @@ impl Currency for AED {
     fn numeric_code(&self) -> u32 {
         784
     }
+
+    fn name(&self) -> &'static str {
+        "UAE Dirham"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AE"]
+    }
 }
 
 /// US Dollar (Next day)
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct USN;
 impl Currency for USN {
     fn code(&self) -> &'static str {
@@ -2671,10 +3992,22 @@ impl Currency for USN {
     fn numeric_code(&self) -> u32 {
         997
     }
+
+    fn name(&self) -> &'static str {
+        "US Dollar (Next day)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["US"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
 }
 
 /// Peso Uruguayo
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct UYU;
 impl Currency for UYU {
     fn code(&self) -> &'static str {
@@ -2688,10 +4021,18 @@ impl Currency for UYU {
     fn numeric_code(&self) -> u32 {
         858
     }
+
+    fn name(&self) -> &'static str {
+        "Peso Uruguayo"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UY"]
+    }
 }
 
 /// Uruguay Peso en Unidades Indexadas (UI)
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct UYI;
 impl Currency for UYI {
     fn code(&self) -> &'static str {
@@ -2705,10 +4046,22 @@ impl Currency for UYI {
     fn numeric_code(&self) -> u32 {
         940
     }
+
+    fn name(&self) -> &'static str {
+        "Uruguay Peso en Unidades Indexadas (UI)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UY"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
 }
 
 /// Unidad Previsional
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct UYW;
 impl Currency for UYW {
     fn code(&self) -> &'static str {
@@ -2722,10 +4075,18 @@ impl Currency for UYW {
     fn numeric_code(&self) -> u32 {
         927
     }
+
+    fn name(&self) -> &'static str {
+        "Unidad Previsional"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UY"]
+    }
 }
 
 /// Uzbekistan Sum
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct UZS;
 impl Currency for UZS {
     fn code(&self) -> &'static str {
@@ -2739,10 +4100,18 @@ impl Currency for UZS {
     fn numeric_code(&self) -> u32 {
         860
     }
+
+    fn name(&self) -> &'static str {
+        "Uzbekistan Sum"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UZ"]
+    }
 }
 
 /// Vatu
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct VUV;
 impl Currency for VUV {
     fn code(&self) -> &'static str {
@@ -2756,10 +4125,18 @@ impl Currency for VUV {
     fn numeric_code(&self) -> u32 {
         548
     }
+
+    fn name(&self) -> &'static str {
+        "Vatu"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["VU"]
+    }
 }
 
 /// Bolívar Soberano
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct VES;
 impl Currency for VES {
     fn code(&self) -> &'static str {
@@ -2773,10 +4150,18 @@ impl Currency for VES {
     fn numeric_code(&self) -> u32 {
         928
     }
+
+    fn name(&self) -> &'static str {
+        "Bolívar Soberano"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["VE"]
+    }
 }
 
 /// Bolívar Soberano
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct VED;
 impl Currency for VED {
     fn code(&self) -> &'static str {
@@ -2790,10 +4175,18 @@ impl Currency for VED {
     fn numeric_code(&self) -> u32 {
         926
     }
+
+    fn name(&self) -> &'static str {
+        "Bolívar Soberano"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["VE"]
+    }
 }
 
 /// Dong
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct VND;
 impl Currency for VND {
     fn code(&self) -> &'static str {
@@ -2807,10 +4200,18 @@ impl Currency for VND {
     fn numeric_code(&self) -> u32 {
         704
     }
+
+    fn name(&self) -> &'static str {
+        "Dong"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["VN"]
+    }
 }
 
 /// Yemeni Rial
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct YER;
 impl Currency for YER {
     fn code(&self) -> &'static str {
@@ -2824,10 +4225,18 @@ impl Currency for YER {
     fn numeric_code(&self) -> u32 {
         886
     }
+
+    fn name(&self) -> &'static str {
+        "Yemeni Rial"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["YE"]
+    }
 }
 
 /// Zambian Kwacha
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct ZMW;
 impl Currency for ZMW {
     fn code(&self) -> &'static str {
@@ -2841,10 +4250,18 @@ impl Currency for ZMW {
     fn numeric_code(&self) -> u32 {
         967
     }
+
+    fn name(&self) -> &'static str {
+        "Zambian Kwacha"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZM"]
+    }
 }
 
 /// Zimbabwe Gold
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct ZWG;
 impl Currency for ZWG {
     fn code(&self) -> &'static str {
@@ -2858,10 +4275,18 @@ impl Currency for ZWG {
     fn numeric_code(&self) -> u32 {
         924
     }
+
+    fn name(&self) -> &'static str {
+        "Zimbabwe Gold"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZW"]
+    }
 }
 
 /// Bond Markets Unit European Composite Unit (EURCO)
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XBA;
 impl Currency for XBA {
     fn code(&self) -> &'static str {
@@ -2875,10 +4300,22 @@ impl Currency for XBA {
     fn numeric_code(&self) -> u32 {
         955
     }
+
+    fn name(&self) -> &'static str {
+        "Bond Markets Unit European Composite Unit (EURCO)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Bond Markets Unit European Monetary Unit (E.M.U.-6)
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XBB;
 impl Currency for XBB {
     fn code(&self) -> &'static str {
@@ -2892,10 +4329,22 @@ impl Currency for XBB {
     fn numeric_code(&self) -> u32 {
         956
     }
+
+    fn name(&self) -> &'static str {
+        "Bond Markets Unit European Monetary Unit (E.M.U.-6)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Bond Markets Unit European Unit of Account 9 (E.U.A.-9)
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XBC;
 impl Currency for XBC {
     fn code(&self) -> &'static str {
@@ -2909,10 +4358,22 @@ impl Currency for XBC {
     fn numeric_code(&self) -> u32 {
         957
     }
+
+    fn name(&self) -> &'static str {
+        "Bond Markets Unit European Unit of Account 9 (E.U.A.-9)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Bond Markets Unit European Unit of Account 17 (E.U.A.-17)
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XBD;
 impl Currency for XBD {
     fn code(&self) -> &'static str {
@@ -2926,10 +4387,22 @@ impl Currency for XBD {
     fn numeric_code(&self) -> u32 {
         958
     }
+
+    fn name(&self) -> &'static str {
+        "Bond Markets Unit European Unit of Account 17 (E.U.A.-17)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
 }
 
 /// Codes specifically reserved for testing purposes
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XTS;
 impl Currency for XTS {
     fn code(&self) -> &'static str {
@@ -2943,10 +4416,22 @@ impl Currency for XTS {
     fn numeric_code(&self) -> u32 {
         963
     }
+
+    fn name(&self) -> &'static str {
+        "Codes specifically reserved for testing purposes"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Testing
+    }
 }
 
 /// The codes assigned for transactions where no currency is involved
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XXX;
 impl Currency for XXX {
     fn code(&self) -> &'static str {
@@ -2960,10 +4445,22 @@ impl Currency for XXX {
     fn numeric_code(&self) -> u32 {
         999
     }
+
+    fn name(&self) -> &'static str {
+        "The codes assigned for transactions where no currency is involved"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::NoCurrency
+    }
 }
 
 /// Gold
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XAU;
 impl Currency for XAU {
     fn code(&self) -> &'static str {
@@ -2977,10 +4474,22 @@ impl Currency for XAU {
     fn numeric_code(&self) -> u32 {
         959
     }
+
+    fn name(&self) -> &'static str {
+        "Gold"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::PreciousMetal
+    }
 }
 
 /// Palladium
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XPD;
 impl Currency for XPD {
     fn code(&self) -> &'static str {
@@ -2994,10 +4503,22 @@ impl Currency for XPD {
     fn numeric_code(&self) -> u32 {
         964
     }
+
+    fn name(&self) -> &'static str {
+        "Palladium"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::PreciousMetal
+    }
 }
 
 /// Platinum
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XPT;
 impl Currency for XPT {
     fn code(&self) -> &'static str {
@@ -3011,10 +4532,22 @@ impl Currency for XPT {
     fn numeric_code(&self) -> u32 {
         962
     }
+
+    fn name(&self) -> &'static str {
+        "Platinum"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::PreciousMetal
+    }
 }
 
 /// Silver
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
 pub struct XAG;
 impl Currency for XAG {
     fn code(&self) -> &'static str {
@@ -3028,4 +4561,306 @@ impl Currency for XAG {
     fn numeric_code(&self) -> u32 {
         961
     }
+
+    fn name(&self) -> &'static str {
+        "Silver"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::PreciousMetal
+    }
+}
+
+/// Generates the [IsoCurrency] enum and the [ALL_CURRENCIES] slice,
+/// with one entry for each of the currency structs listed.
+macro_rules! iso_currency_enum {
+    ($($code:ident),* $(,)?) => {
+        /// All the currencies defined in this module, ordered by code.
+        /// (This can't be named `ALL` since that is the code for the Albanian Lek.)
+        pub static ALL_CURRENCIES: &[&dyn Currency] = &[$(&$code),*];
+
+        /// An owned, dynamically-typed ISO currency, with one variant for each
+        /// currency defined in this module. Unlike `&dyn Currency`, it has no
+        /// lifetime, so a `Money<IsoCurrency>` can be stored in structs, sent
+        /// across threads, and deserialized without a
+        /// [CurrencyMap](crate::currency_map::CurrencyMap). It implements
+        /// [Currency] by delegating to the struct for the variant.
+        ///
+        /// Like a `Money<&dyn Currency>`, the currency of a `Money<IsoCurrency>`
+        /// is only known at runtime, so its math operators return a Result
+        /// rather than panicking when the currencies differ.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub enum IsoCurrency {
+            $(
+                #[doc = concat!("See [", stringify!($code), "].")]
+                $code,
+            )*
+        }
+
+        impl IsoCurrency {
+            /// Returns the statically-typed currency for this variant,
+            /// which provides its minor units, name and other details.
+            pub fn currency(&self) -> &'static dyn Currency {
+                match self {
+                    $(IsoCurrency::$code => &$code,)*
+                }
+            }
+
+            /// Returns the currency with the specified alphabetic code,
+            /// or None if there is no such currency.
+            pub fn from_code(code: &str) -> Option<Self> {
+                match code {
+                    $(stringify!($code) => Some(IsoCurrency::$code),)*
+                    _ => None,
+                }
+            }
+
+            /// Returns the currency with the specified numeric code,
+            /// or None if there is no such currency.
+            pub fn from_numeric_code(numeric_code: u32) -> Option<Self> {
+                match numeric_code {
+                    $(n if n == $code.numeric_code() => Some(IsoCurrency::$code),)*
+                    _ => None,
+                }
+            }
+        }
+
+        $(
+            impl From<$code> for IsoCurrency {
+                fn from(_: $code) -> Self {
+                    IsoCurrency::$code
+                }
+            }
+        )*
+    };
+}
+
+iso_currency_enum!(
+    AED, AFN, ALL, AMD, AOA, ARS, AUD, AWG, AZN, BAM, BBD, BDT, BHD, BIF, BMD, BND, BOB, BOV, BRL,
+    BSD, BTN, BWP, BYN, BZD, CAD, CDF, CHE, CHF, CHW, CLF, CLP, CNY, COP, COU, CRC, CUP, CVE, CZK,
+    DJF, DKK, DOP, DZD, EGP, ERN, ETB, EUR, FJD, FKP, GBP, GEL, GHS, GIP, GMD, GNF, GTQ, GYD, HKD,
+    HNL, HTG, HUF, IDR, ILS, INR, IQD, IRR, ISK, JMD, JOD, JPY, KES, KGS, KHR, KMF, KPW, KRW, KWD,
+    KYD, KZT, LAK, LBP, LKR, LRD, LSL, LYD, MAD, MDL, MGA, MKD, MMK, MNT, MOP, MRU, MUR, MVR, MWK,
+    MXN, MXV, MYR, MZN, NAD, NGN, NIO, NOK, NPR, NZD, OMR, PAB, PEN, PGK, PHP, PKR, PLN, PYG, QAR,
+    RON, RSD, RUB, RWF, SAR, SBD, SCR, SDG, SEK, SGD, SHP, SLE, SOS, SRD, SSP, STN, SVC, SYP, SZL,
+    THB, TJS, TMT, TND, TOP, TRY, TTD, TWD, TZS, UAH, UGX, USD, USN, UYI, UYU, UYW, UZS, VED, VES,
+    VND, VUV, WST, XAD, XAF, XAG, XAU, XBA, XBB, XBC, XBD, XCD, XCG, XDR, XOF, XPD, XPF, XPT, XSU,
+    XTS, XUA, XXX, YER, ZAR, ZMW, ZWG,
+);
+
+/// A [CurrencyMap] containing all the currencies defined in this module,
+/// which is built the first time it is used.
+///
+/// ```rust
+/// use doubloon::{Money, iso_currencies::CURRENCY_MAP};
+///
+/// let m = Money::new(100, CURRENCY_MAP.get("JPY").unwrap());
+/// assert_eq!(m.to_string(), "100 JPY");
+/// ```
+pub static CURRENCY_MAP: LazyLock<CurrencyMap<'static>> =
+    LazyLock::new(|| CurrencyMap::from_collection(ALL_CURRENCIES.iter().copied()));
+
+/// Implementation of [Currency] for an [IsoCurrency], which delegates
+/// to the statically-typed currency for the variant.
+impl Currency for IsoCurrency {
+    fn code(&self) -> &'static str {
+        self.currency().code()
+    }
+
+    fn minor_units(&self) -> u32 {
+        self.currency().minor_units()
+    }
+
+    fn numeric_code(&self) -> u32 {
+        self.currency().numeric_code()
+    }
+
+    fn name(&self) -> &'static str {
+        self.currency().name()
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        self.currency().countries()
+    }
+
+    fn is_fund(&self) -> bool {
+        self.currency().is_fund()
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        self.currency().category()
+    }
+}
+
+/// Parses an [IsoCurrency] from its alphabetic code, such as `"USD"`.
+impl FromStr for IsoCurrency {
+    type Err = ParseMoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        IsoCurrency::from_code(s).ok_or_else(|| ParseMoneyError::UnknownCurrency(s.to_string()))
+    }
+}
+
+/// Writes the alphabetic code of the currency.
+impl std::fmt::Display for IsoCurrency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+
+    use crate::iso_currencies::*;
+    use crate::{money_bag::MoneyBag, Money, MoneyMathError, RoundingStrategy, TrySum};
+
+    #[test]
+    fn all_currencies() {
+        assert_eq!(ALL_CURRENCIES.len(), 178);
+        assert!(ALL_CURRENCIES.windows(2).all(|w| w[0].code() < w[1].code()));
+        for currency in ALL_CURRENCIES {
+            assert_eq!(
+                IsoCurrency::from_code(currency.code()).map(|c| c.currency().numeric_code()),
+                Some(currency.numeric_code())
+            );
+        }
+    }
+
+    #[test]
+    fn currency_map() {
+        for currency in ALL_CURRENCIES {
+            assert_eq!(CURRENCY_MAP.get(currency.code()), Some(*currency));
+        }
+        assert_eq!(CURRENCY_MAP.get("ALL").map(|c| c.numeric_code()), Some(8));
+        assert_eq!(CURRENCY_MAP.get("XYZ"), None);
+    }
+
+    #[test]
+    fn metadata() {
+        assert_eq!(USD.name(), "US Dollar");
+        assert!(USD.countries().contains(&"US"));
+        assert!(!USD.is_fund());
+        assert_eq!(USD.category(), CurrencyCategory::National);
+
+        assert_eq!(CHF.countries(), &["CH", "LI"]);
+        assert!(EUR.countries().contains(&"FR"));
+        assert_eq!(EUR.category(), CurrencyCategory::Supranational);
+        assert_eq!(XDR.category(), CurrencyCategory::Supranational);
+        assert_eq!(XAU.category(), CurrencyCategory::PreciousMetal);
+        assert_eq!(XTS.category(), CurrencyCategory::Testing);
+        assert_eq!(XXX.category(), CurrencyCategory::NoCurrency);
+        assert!(XXX.countries().is_empty());
+
+        let funds: Vec<&str> = ALL_CURRENCIES
+            .iter()
+            .filter(|c| c.is_fund())
+            .map(|c| c.code())
+            .collect();
+        assert_eq!(
+            funds,
+            vec!["BOV", "CHE", "CHW", "CLF", "COU", "MXV", "USN", "UYI"]
+        );
+
+        let usn = IsoCurrency::USN;
+        assert_eq!(usn.name(), "US Dollar (Next day)");
+        assert_eq!(usn.countries(), &["US"]);
+        assert!(usn.is_fund());
+        assert_eq!(usn.numeric_code(), 997);
+        assert_eq!(IsoCurrency::XAG.category(), CurrencyCategory::PreciousMetal);
+    }
+
+    #[test]
+    fn iso_currency() {
+        let usd: IsoCurrency = "USD".parse().unwrap();
+        assert_eq!(usd, IsoCurrency::USD);
+        assert_eq!(usd, IsoCurrency::from(USD));
+        assert_eq!(usd.code(), "USD");
+        assert_eq!(usd.minor_units(), 2);
+        assert_eq!(usd.currency().numeric_code(), 840);
+        assert_eq!(usd.to_string(), "USD");
+        assert_eq!(IsoCurrency::JPY.minor_units(), 0);
+
+        assert_eq!(IsoCurrency::from_numeric_code(978), Some(IsoCurrency::EUR));
+        assert_eq!(IsoCurrency::from_numeric_code(8), Some(IsoCurrency::ALL));
+        assert_eq!(IsoCurrency::from_numeric_code(1), None);
+        assert_eq!(
+            "usd".parse::<IsoCurrency>(),
+            Err(ParseMoneyError::UnknownCurrency("usd".to_string()))
+        );
+    }
+
+    #[test]
+    fn iso_currency_money() {
+        let m = (Money::new(1, IsoCurrency::USD) + Money::new(2, IsoCurrency::USD)).unwrap();
+        assert_eq!(m, Money::new(3, IsoCurrency::USD));
+        assert_eq!(m, Money::new(3, &USD as &dyn Currency));
+        assert_eq!(m.as_dyn(), Money::new(3, &USD as &dyn Currency));
+        assert_eq!(m.to_string(), "3 USD");
+        assert_eq!(-m * 2, Money::new(-6, IsoCurrency::USD));
+        assert_eq!(m / Money::new(2, IsoCurrency::USD), Ok(Decimal::new(15, 1)));
+
+        // the currency is only known at runtime, so it is compared too
+        let eur = Money::new(3, IsoCurrency::EUR);
+        assert_ne!(m, eur);
+        assert!(eur < m);
+        assert_eq!(
+            m - eur,
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "EUR".to_string()
+            ))
+        );
+        assert_eq!(
+            m.checked_add(eur),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "EUR".to_string()
+            ))
+        );
+        assert_eq!(
+            m / eur,
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "EUR".to_string()
+            ))
+        );
+
+        // no lifetime, so it can be moved to another thread
+        let handle = std::thread::spawn(move || m.round(RoundingStrategy::ToZero));
+        assert_eq!(handle.join().unwrap(), Money::new(3, IsoCurrency::USD));
+    }
+
+    #[test]
+    fn iso_currency_is_a_currency() {
+        let items = [
+            Money::new(1, IsoCurrency::USD),
+            Money::new(2, IsoCurrency::USD),
+        ];
+        assert_eq!(
+            items.into_iter().try_sum(IsoCurrency::USD),
+            Ok(Money::new(3, IsoCurrency::USD))
+        );
+        assert_eq!(
+            items.into_iter().try_sum(IsoCurrency::EUR),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "EUR".to_string(),
+                "USD".to_string()
+            ))
+        );
+
+        let mut bag = MoneyBag::new();
+        bag += Money::new(1, IsoCurrency::USD);
+        bag += Money::new(2, IsoCurrency::EUR);
+        bag += Money::new(3, &USD as &dyn Currency);
+        assert_eq!(bag.get("USD"), Some(Money::new(4, &USD as &dyn Currency)));
+        assert_eq!(bag.get("EUR"), Some(Money::new(2, &EUR as &dyn Currency)));
+
+        let currencies = CurrencyMap::from_collection([&IsoCurrency::JPY as &dyn Currency]);
+        assert_eq!(currencies.get("JPY").map(|c| c.minor_units()), Some(0));
+    }
 }
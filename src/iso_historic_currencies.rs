@@ -0,0 +1,3915 @@
+//! ISO 4217 historic currency definitions, from list three published on 2026-01-01
+//!
+//! source: <https://www.six-group.com/dam/download/financial-information/data-center/iso-currrency/lists/list-three.xml>
+//!
+//! The structs are generated from the list by `cargo xtask iso-historic-currencies`,
+//! leaving out the codes that are still in [crate::iso_currencies].
+//!
+//! List three only gives the month in which each currency was withdrawn,
+//! so [Currency::withdrawn_on] returns the first day of that month. The oldest
+//! entries only give a range of years, like "1989 to 1990", in which case it
+//! returns the first day of the last year. Where list three has several
+//! entries for the same code, the latest withdrawal is used. The list doesn't
+//! include minor units either, so those are taken from the last edition of
+//! list one that included the currency. A few units of account were never
+//! given a numeric code, so [Currency::numeric_code] returns 0 for them.
+//!
+//! These currencies can be added to a [CurrencyMap] alongside the current ones:
+//!
+//! ```rust
+//! use doubloon::{
+//!     Currency,
+//!     currency_map::CurrencyMap,
+//!     iso_currencies,
+//!     iso_historic_currencies::{self, DEM},
+//! };
+//!
+//! let map = CurrencyMap::from_collection(
+//!     iso_currencies::ALL_CURRENCIES
+//!         .iter()
+//!         .chain(iso_historic_currencies::ALL_CURRENCIES)
+//!         .copied(),
+//! );
+//! let dem = map.get("DEM").unwrap();
+//! assert_eq!(dem.name(), "Deutsche Mark");
+//! assert!(dem.is_withdrawn_by(std::time::SystemTime::now()));
+//! assert!(!map.get("EUR").unwrap().is_withdrawn_by(std::time::SystemTime::now()));
+//! ```
+
+use std::{sync::LazyLock, time::SystemTime};
+
+use crate::{currency_map::CurrencyMap, rate_provider::parse_date, Currency, CurrencyCategory};
+
+/// Afghani
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct AFA;
+impl Currency for AFA {
+    fn code(&self) -> &'static str {
+        "AFA"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        4
+    }
+
+    fn name(&self) -> &'static str {
+        "Afghani"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AF"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2003-01-01")
+    }
+}
+
+/// Markka
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct FIM;
+impl Currency for FIM {
+    fn code(&self) -> &'static str {
+        "FIM"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        246
+    }
+
+    fn name(&self) -> &'static str {
+        "Markka"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AX", "FI"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Old Lek
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ALK;
+impl Currency for ALK {
+    fn code(&self) -> &'static str {
+        "ALK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        8
+    }
+
+    fn name(&self) -> &'static str {
+        "Old Lek"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AL"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1989-12-01")
+    }
+}
+
+/// Andorran Peseta
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ADP;
+impl Currency for ADP {
+    fn code(&self) -> &'static str {
+        "ADP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        20
+    }
+
+    fn name(&self) -> &'static str {
+        "Andorran Peseta"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AD"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2003-07-01")
+    }
+}
+
+/// Spanish Peseta
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ESP;
+impl Currency for ESP {
+    fn code(&self) -> &'static str {
+        "ESP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        724
+    }
+
+    fn name(&self) -> &'static str {
+        "Spanish Peseta"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AD", "ES"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// French Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct FRF;
+impl Currency for FRF {
+    fn code(&self) -> &'static str {
+        "FRF"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        250
+    }
+
+    fn name(&self) -> &'static str {
+        "French Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AD", "FR", "GF", "GP", "MC", "MQ", "PM", "RE", "TF", "YT"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Kwanza
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct AOK;
+impl Currency for AOK {
+    fn code(&self) -> &'static str {
+        "AOK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        24
+    }
+
+    fn name(&self) -> &'static str {
+        "Kwanza"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AO"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1991-03-01")
+    }
+}
+
+/// New Kwanza
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct AON;
+impl Currency for AON {
+    fn code(&self) -> &'static str {
+        "AON"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        24
+    }
+
+    fn name(&self) -> &'static str {
+        "New Kwanza"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AO"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2000-02-01")
+    }
+}
+
+/// Kwanza Reajustado
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct AOR;
+impl Currency for AOR {
+    fn code(&self) -> &'static str {
+        "AOR"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        982
+    }
+
+    fn name(&self) -> &'static str {
+        "Kwanza Reajustado"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AO"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2000-02-01")
+    }
+}
+
+/// Austral
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ARA;
+impl Currency for ARA {
+    fn code(&self) -> &'static str {
+        "ARA"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        32
+    }
+
+    fn name(&self) -> &'static str {
+        "Austral"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1992-01-01")
+    }
+}
+
+/// Peso Argentino
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ARP;
+impl Currency for ARP {
+    fn code(&self) -> &'static str {
+        "ARP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        32
+    }
+
+    fn name(&self) -> &'static str {
+        "Peso Argentino"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1985-07-01")
+    }
+}
+
+/// Peso
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ARY;
+impl Currency for ARY {
+    fn code(&self) -> &'static str {
+        "ARY"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        32
+    }
+
+    fn name(&self) -> &'static str {
+        "Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Russian Ruble
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct RUR;
+impl Currency for RUR {
+    fn code(&self) -> &'static str {
+        "RUR"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        810
+    }
+
+    fn name(&self) -> &'static str {
+        "Russian Ruble"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[
+            "AM", "AZ", "BY", "GE", "KG", "KZ", "MD", "RU", "TJ", "TM", "UZ",
+        ]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1998-01-01")
+    }
+}
+
+/// Schilling
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ATS;
+impl Currency for ATS {
+    fn code(&self) -> &'static str {
+        "ATS"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        40
+    }
+
+    fn name(&self) -> &'static str {
+        "Schilling"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AT"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Azerbaijan Manat
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct AYM;
+impl Currency for AYM {
+    fn code(&self) -> &'static str {
+        "AYM"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        945
+    }
+
+    fn name(&self) -> &'static str {
+        "Azerbaijan Manat"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AZ"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2005-10-01")
+    }
+}
+
+/// Azerbaijanian Manat
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct AZM;
+impl Currency for AZM {
+    fn code(&self) -> &'static str {
+        "AZM"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        31
+    }
+
+    fn name(&self) -> &'static str {
+        "Azerbaijanian Manat"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AZ"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2005-12-01")
+    }
+}
+
+/// Belarusian Ruble
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BYB;
+impl Currency for BYB {
+    fn code(&self) -> &'static str {
+        "BYB"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        112
+    }
+
+    fn name(&self) -> &'static str {
+        "Belarusian Ruble"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BY"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2001-01-01")
+    }
+}
+
+/// Belarusian Ruble
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BYR;
+impl Currency for BYR {
+    fn code(&self) -> &'static str {
+        "BYR"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        974
+    }
+
+    fn name(&self) -> &'static str {
+        "Belarusian Ruble"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BY"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2017-01-01")
+    }
+}
+
+/// Convertible Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BEC;
+impl Currency for BEC {
+    fn code(&self) -> &'static str {
+        "BEC"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        993
+    }
+
+    fn name(&self) -> &'static str {
+        "Convertible Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BE"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-03-01")
+    }
+}
+
+/// Belgian Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BEF;
+impl Currency for BEF {
+    fn code(&self) -> &'static str {
+        "BEF"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        56
+    }
+
+    fn name(&self) -> &'static str {
+        "Belgian Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Financial Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BEL;
+impl Currency for BEL {
+    fn code(&self) -> &'static str {
+        "BEL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        992
+    }
+
+    fn name(&self) -> &'static str {
+        "Financial Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BE"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-03-01")
+    }
+}
+
+/// Peso boliviano
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BOP;
+impl Currency for BOP {
+    fn code(&self) -> &'static str {
+        "BOP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        68
+    }
+
+    fn name(&self) -> &'static str {
+        "Peso boliviano"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BO"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1987-02-01")
+    }
+}
+
+/// Dinar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BAD;
+impl Currency for BAD {
+    fn code(&self) -> &'static str {
+        "BAD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        70
+    }
+
+    fn name(&self) -> &'static str {
+        "Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BA"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1998-07-01")
+    }
+}
+
+/// Cruzeiro
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BRB;
+impl Currency for BRB {
+    fn code(&self) -> &'static str {
+        "BRB"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        76
+    }
+
+    fn name(&self) -> &'static str {
+        "Cruzeiro"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1986-03-01")
+    }
+}
+
+/// Cruzado
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BRC;
+impl Currency for BRC {
+    fn code(&self) -> &'static str {
+        "BRC"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        76
+    }
+
+    fn name(&self) -> &'static str {
+        "Cruzado"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1989-02-01")
+    }
+}
+
+/// Cruzeiro
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BRE;
+impl Currency for BRE {
+    fn code(&self) -> &'static str {
+        "BRE"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        76
+    }
+
+    fn name(&self) -> &'static str {
+        "Cruzeiro"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1993-03-01")
+    }
+}
+
+/// New Cruzado
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BRN;
+impl Currency for BRN {
+    fn code(&self) -> &'static str {
+        "BRN"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        76
+    }
+
+    fn name(&self) -> &'static str {
+        "New Cruzado"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-03-01")
+    }
+}
+
+/// Cruzeiro Real
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BRR;
+impl Currency for BRR {
+    fn code(&self) -> &'static str {
+        "BRR"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        987
+    }
+
+    fn name(&self) -> &'static str {
+        "Cruzeiro Real"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1994-07-01")
+    }
+}
+
+/// Lev A/52
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BGJ;
+impl Currency for BGJ {
+    fn code(&self) -> &'static str {
+        "BGJ"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        100
+    }
+
+    fn name(&self) -> &'static str {
+        "Lev A/52"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BG"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Lev A/62
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BGK;
+impl Currency for BGK {
+    fn code(&self) -> &'static str {
+        "BGK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        100
+    }
+
+    fn name(&self) -> &'static str {
+        "Lev A/62"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BG"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Lev
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BGL;
+impl Currency for BGL {
+    fn code(&self) -> &'static str {
+        "BGL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        100
+    }
+
+    fn name(&self) -> &'static str {
+        "Lev"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BG"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2003-11-01")
+    }
+}
+
+/// Bulgarian Lev
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BGN;
+impl Currency for BGN {
+    fn code(&self) -> &'static str {
+        "BGN"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        975
+    }
+
+    fn name(&self) -> &'static str {
+        "Bulgarian Lev"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BG"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2026-01-01")
+    }
+}
+
+/// Kyat
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct BUK;
+impl Currency for BUK {
+    fn code(&self) -> &'static str {
+        "BUK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        104
+    }
+
+    fn name(&self) -> &'static str {
+        "Kyat"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["BU"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-02-01")
+    }
+}
+
+/// Croatian Dinar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct HRD;
+impl Currency for HRD {
+    fn code(&self) -> &'static str {
+        "HRD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        191
+    }
+
+    fn name(&self) -> &'static str {
+        "Croatian Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["HR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1995-01-01")
+    }
+}
+
+/// Kuna
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct HRK;
+impl Currency for HRK {
+    fn code(&self) -> &'static str {
+        "HRK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        191
+    }
+
+    fn name(&self) -> &'static str {
+        "Kuna"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["HR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2023-01-01")
+    }
+}
+
+/// Peso Convertible
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct CUC;
+impl Currency for CUC {
+    fn code(&self) -> &'static str {
+        "CUC"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        931
+    }
+
+    fn name(&self) -> &'static str {
+        "Peso Convertible"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CU"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2021-06-01")
+    }
+}
+
+/// Netherlands Antillean Guilder
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ANG;
+impl Currency for ANG {
+    fn code(&self) -> &'static str {
+        "ANG"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        532
+    }
+
+    fn name(&self) -> &'static str {
+        "Netherlands Antillean Guilder"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["AN", "CW", "SX"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2025-04-01")
+    }
+}
+
+/// Cyprus Pound
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct CYP;
+impl Currency for CYP {
+    fn code(&self) -> &'static str {
+        "CYP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        196
+    }
+
+    fn name(&self) -> &'static str {
+        "Cyprus Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CY"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2008-01-01")
+    }
+}
+
+/// Krona A/53
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct CSJ;
+impl Currency for CSJ {
+    fn code(&self) -> &'static str {
+        "CSJ"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        203
+    }
+
+    fn name(&self) -> &'static str {
+        "Krona A/53"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CS"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Koruna
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct CSK;
+impl Currency for CSK {
+    fn code(&self) -> &'static str {
+        "CSK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        200
+    }
+
+    fn name(&self) -> &'static str {
+        "Koruna"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CS"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1993-03-01")
+    }
+}
+
+/// Sucre
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ECS;
+impl Currency for ECS {
+    fn code(&self) -> &'static str {
+        "ECS"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        218
+    }
+
+    fn name(&self) -> &'static str {
+        "Sucre"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["EC"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2000-09-01")
+    }
+}
+
+/// Unidad de Valor Constante (UVC)
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ECV;
+impl Currency for ECV {
+    fn code(&self) -> &'static str {
+        "ECV"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        983
+    }
+
+    fn name(&self) -> &'static str {
+        "Unidad de Valor Constante (UVC)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["EC"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2000-09-01")
+    }
+}
+
+/// Ekwele
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GQE;
+impl Currency for GQE {
+    fn code(&self) -> &'static str {
+        "GQE"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        226
+    }
+
+    fn name(&self) -> &'static str {
+        "Ekwele"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GQ"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1986-06-01")
+    }
+}
+
+/// Kroon
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct EEK;
+impl Currency for EEK {
+    fn code(&self) -> &'static str {
+        "EEK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        233
+    }
+
+    fn name(&self) -> &'static str {
+        "Kroon"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["EE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2011-01-01")
+    }
+}
+
+/// European Currency Unit (E.C.U)
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct XEU;
+impl Currency for XEU {
+    fn code(&self) -> &'static str {
+        "XEU"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        954
+    }
+
+    fn name(&self) -> &'static str {
+        "European Currency Unit (E.C.U)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1999-01-01")
+    }
+}
+
+/// Georgian Coupon
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GEK;
+impl Currency for GEK {
+    fn code(&self) -> &'static str {
+        "GEK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        268
+    }
+
+    fn name(&self) -> &'static str {
+        "Georgian Coupon"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1995-10-01")
+    }
+}
+
+/// Mark der DDR
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct DDM;
+impl Currency for DDM {
+    fn code(&self) -> &'static str {
+        "DDM"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        278
+    }
+
+    fn name(&self) -> &'static str {
+        "Mark der DDR"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["DD"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-09-01")
+    }
+}
+
+/// Deutsche Mark
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct DEM;
+impl Currency for DEM {
+    fn code(&self) -> &'static str {
+        "DEM"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        276
+    }
+
+    fn name(&self) -> &'static str {
+        "Deutsche Mark"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["DE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Cedi
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GHC;
+impl Currency for GHC {
+    fn code(&self) -> &'static str {
+        "GHC"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        288
+    }
+
+    fn name(&self) -> &'static str {
+        "Cedi"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GH"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2007-06-01")
+    }
+}
+
+/// Ghana Cedi
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GHP;
+impl Currency for GHP {
+    fn code(&self) -> &'static str {
+        "GHP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        939
+    }
+
+    fn name(&self) -> &'static str {
+        "Ghana Cedi"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GH"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2007-06-01")
+    }
+}
+
+/// Drachma
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GRD;
+impl Currency for GRD {
+    fn code(&self) -> &'static str {
+        "GRD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        300
+    }
+
+    fn name(&self) -> &'static str {
+        "Drachma"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Syli
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GNE;
+impl Currency for GNE {
+    fn code(&self) -> &'static str {
+        "GNE"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        324
+    }
+
+    fn name(&self) -> &'static str {
+        "Syli"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GN"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1989-12-01")
+    }
+}
+
+/// Syli
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GNS;
+impl Currency for GNS {
+    fn code(&self) -> &'static str {
+        "GNS"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        324
+    }
+
+    fn name(&self) -> &'static str {
+        "Syli"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GN"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1986-02-01")
+    }
+}
+
+/// Guinea Escudo
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GWE;
+impl Currency for GWE {
+    fn code(&self) -> &'static str {
+        "GWE"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        624
+    }
+
+    fn name(&self) -> &'static str {
+        "Guinea Escudo"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GW"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1981-01-01")
+    }
+}
+
+/// Guinea-Bissau Peso
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct GWP;
+impl Currency for GWP {
+    fn code(&self) -> &'static str {
+        "GWP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        624
+    }
+
+    fn name(&self) -> &'static str {
+        "Guinea-Bissau Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["GW"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1997-05-01")
+    }
+}
+
+/// Italian Lira
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ITL;
+impl Currency for ITL {
+    fn code(&self) -> &'static str {
+        "ITL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        380
+    }
+
+    fn name(&self) -> &'static str {
+        "Italian Lira"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["IT", "SM", "VA"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Old Krona
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ISJ;
+impl Currency for ISJ {
+    fn code(&self) -> &'static str {
+        "ISJ"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        352
+    }
+
+    fn name(&self) -> &'static str {
+        "Old Krona"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["IS"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Irish Pound
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct IEP;
+impl Currency for IEP {
+    fn code(&self) -> &'static str {
+        "IEP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        372
+    }
+
+    fn name(&self) -> &'static str {
+        "Irish Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["IE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Pound
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ILP;
+impl Currency for ILP {
+    fn code(&self) -> &'static str {
+        "ILP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        376
+    }
+
+    fn name(&self) -> &'static str {
+        "Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["IL"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1981-01-01")
+    }
+}
+
+/// Old Shekel
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ILR;
+impl Currency for ILR {
+    fn code(&self) -> &'static str {
+        "ILR"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        376
+    }
+
+    fn name(&self) -> &'static str {
+        "Old Shekel"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["IL"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Pathet Lao Kip
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct LAJ;
+impl Currency for LAJ {
+    fn code(&self) -> &'static str {
+        "LAJ"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        418
+    }
+
+    fn name(&self) -> &'static str {
+        "Pathet Lao Kip"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LA"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1979-12-01")
+    }
+}
+
+/// Latvian Lats
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct LVL;
+impl Currency for LVL {
+    fn code(&self) -> &'static str {
+        "LVL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        428
+    }
+
+    fn name(&self) -> &'static str {
+        "Latvian Lats"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LV"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2014-01-01")
+    }
+}
+
+/// Latvian Ruble
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct LVR;
+impl Currency for LVR {
+    fn code(&self) -> &'static str {
+        "LVR"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        428
+    }
+
+    fn name(&self) -> &'static str {
+        "Latvian Ruble"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LV"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1994-12-01")
+    }
+}
+
+/// Loti
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct LSM;
+impl Currency for LSM {
+    fn code(&self) -> &'static str {
+        "LSM"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        426
+    }
+
+    fn name(&self) -> &'static str {
+        "Loti"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LS"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1985-05-01")
+    }
+}
+
+/// Financial Rand
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ZAL;
+impl Currency for ZAL {
+    fn code(&self) -> &'static str {
+        "ZAL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        991
+    }
+
+    fn name(&self) -> &'static str {
+        "Financial Rand"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LS", "ZA"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1995-03-01")
+    }
+}
+
+/// Lithuanian Litas
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct LTL;
+impl Currency for LTL {
+    fn code(&self) -> &'static str {
+        "LTL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        440
+    }
+
+    fn name(&self) -> &'static str {
+        "Lithuanian Litas"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LT"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2014-12-01")
+    }
+}
+
+/// Talonas
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct LTT;
+impl Currency for LTT {
+    fn code(&self) -> &'static str {
+        "LTT"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        440
+    }
+
+    fn name(&self) -> &'static str {
+        "Talonas"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LT"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1993-07-01")
+    }
+}
+
+/// Luxembourg Convertible Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct LUC;
+impl Currency for LUC {
+    fn code(&self) -> &'static str {
+        "LUC"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        989
+    }
+
+    fn name(&self) -> &'static str {
+        "Luxembourg Convertible Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LU"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-03-01")
+    }
+}
+
+/// Luxembourg Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct LUF;
+impl Currency for LUF {
+    fn code(&self) -> &'static str {
+        "LUF"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        442
+    }
+
+    fn name(&self) -> &'static str {
+        "Luxembourg Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LU"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Luxembourg Financial Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct LUL;
+impl Currency for LUL {
+    fn code(&self) -> &'static str {
+        "LUL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        988
+    }
+
+    fn name(&self) -> &'static str {
+        "Luxembourg Financial Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["LU"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-03-01")
+    }
+}
+
+/// Malagasy Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct MGF;
+impl Currency for MGF {
+    fn code(&self) -> &'static str {
+        "MGF"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        450
+    }
+
+    fn name(&self) -> &'static str {
+        "Malagasy Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MG"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2004-12-01")
+    }
+}
+
+/// Maldive Rupee
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct MVQ;
+impl Currency for MVQ {
+    fn code(&self) -> &'static str {
+        "MVQ"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        462
+    }
+
+    fn name(&self) -> &'static str {
+        "Maldive Rupee"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MV"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1989-12-01")
+    }
+}
+
+/// Mali Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct MLF;
+impl Currency for MLF {
+    fn code(&self) -> &'static str {
+        "MLF"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        466
+    }
+
+    fn name(&self) -> &'static str {
+        "Mali Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ML"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1984-11-01")
+    }
+}
+
+/// Maltese Lira
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct MTL;
+impl Currency for MTL {
+    fn code(&self) -> &'static str {
+        "MTL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        470
+    }
+
+    fn name(&self) -> &'static str {
+        "Maltese Lira"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MT"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2008-01-01")
+    }
+}
+
+/// Maltese Pound
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct MTP;
+impl Currency for MTP {
+    fn code(&self) -> &'static str {
+        "MTP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        470
+    }
+
+    fn name(&self) -> &'static str {
+        "Maltese Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MT"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1983-06-01")
+    }
+}
+
+/// Ouguiya
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct MRO;
+impl Currency for MRO {
+    fn code(&self) -> &'static str {
+        "MRO"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        478
+    }
+
+    fn name(&self) -> &'static str {
+        "Ouguiya"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2017-12-01")
+    }
+}
+
+/// Mexican Peso
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct MXP;
+impl Currency for MXP {
+    fn code(&self) -> &'static str {
+        "MXP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        484
+    }
+
+    fn name(&self) -> &'static str {
+        "Mexican Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MX"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1993-01-01")
+    }
+}
+
+/// Mozambique Escudo
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct MZE;
+impl Currency for MZE {
+    fn code(&self) -> &'static str {
+        "MZE"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        508
+    }
+
+    fn name(&self) -> &'static str {
+        "Mozambique Escudo"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MZ"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1981-01-01")
+    }
+}
+
+/// Mozambique Metical
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct MZM;
+impl Currency for MZM {
+    fn code(&self) -> &'static str {
+        "MZM"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        508
+    }
+
+    fn name(&self) -> &'static str {
+        "Mozambique Metical"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["MZ"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2006-06-01")
+    }
+}
+
+/// Netherlands Guilder
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct NLG;
+impl Currency for NLG {
+    fn code(&self) -> &'static str {
+        "NLG"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        528
+    }
+
+    fn name(&self) -> &'static str {
+        "Netherlands Guilder"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["NL"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Cordoba
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct NIC;
+impl Currency for NIC {
+    fn code(&self) -> &'static str {
+        "NIC"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        558
+    }
+
+    fn name(&self) -> &'static str {
+        "Cordoba"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["NI"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-10-01")
+    }
+}
+
+/// Sol
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct PEH;
+impl Currency for PEH {
+    fn code(&self) -> &'static str {
+        "PEH"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        604
+    }
+
+    fn name(&self) -> &'static str {
+        "Sol"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Inti
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct PEI;
+impl Currency for PEI {
+    fn code(&self) -> &'static str {
+        "PEI"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        604
+    }
+
+    fn name(&self) -> &'static str {
+        "Inti"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1991-07-01")
+    }
+}
+
+/// Sol
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct PES;
+impl Currency for PES {
+    fn code(&self) -> &'static str {
+        "PES"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        604
+    }
+
+    fn name(&self) -> &'static str {
+        "Sol"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1986-02-01")
+    }
+}
+
+/// Zloty
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct PLZ;
+impl Currency for PLZ {
+    fn code(&self) -> &'static str {
+        "PLZ"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        616
+    }
+
+    fn name(&self) -> &'static str {
+        "Zloty"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PL"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1997-01-01")
+    }
+}
+
+/// Portuguese Escudo
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct PTE;
+impl Currency for PTE {
+    fn code(&self) -> &'static str {
+        "PTE"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        620
+    }
+
+    fn name(&self) -> &'static str {
+        "Portuguese Escudo"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["PT"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-03-01")
+    }
+}
+
+/// Leu A/52
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ROK;
+impl Currency for ROK {
+    fn code(&self) -> &'static str {
+        "ROK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        642
+    }
+
+    fn name(&self) -> &'static str {
+        "Leu A/52"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["RO"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Leu
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ROL;
+impl Currency for ROL {
+    fn code(&self) -> &'static str {
+        "ROL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        642
+    }
+
+    fn name(&self) -> &'static str {
+        "Leu"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["RO"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2005-06-01")
+    }
+}
+
+/// Dobra
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct STD;
+impl Currency for STD {
+    fn code(&self) -> &'static str {
+        "STD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        678
+    }
+
+    fn name(&self) -> &'static str {
+        "Dobra"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ST"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2017-12-01")
+    }
+}
+
+/// Serbian Dinar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct CSD;
+impl Currency for CSD {
+    fn code(&self) -> &'static str {
+        "CSD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        891
+    }
+
+    fn name(&self) -> &'static str {
+        "Serbian Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CS"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2006-10-01")
+    }
+}
+
+/// Leone
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct SLL;
+impl Currency for SLL {
+    fn code(&self) -> &'static str {
+        "SLL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        694
+    }
+
+    fn name(&self) -> &'static str {
+        "Leone"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SL"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2023-12-01")
+    }
+}
+
+/// Slovak Koruna
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct SKK;
+impl Currency for SKK {
+    fn code(&self) -> &'static str {
+        "SKK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        703
+    }
+
+    fn name(&self) -> &'static str {
+        "Slovak Koruna"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SK"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2009-01-01")
+    }
+}
+
+/// Tolar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct SIT;
+impl Currency for SIT {
+    fn code(&self) -> &'static str {
+        "SIT"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        705
+    }
+
+    fn name(&self) -> &'static str {
+        "Tolar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SI"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2007-01-01")
+    }
+}
+
+/// Rhodesian Dollar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct RHD;
+impl Currency for RHD {
+    fn code(&self) -> &'static str {
+        "RHD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        716
+    }
+
+    fn name(&self) -> &'static str {
+        "Rhodesian Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["RH", "ZW"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1981-01-01")
+    }
+}
+
+/// Spanish Peseta
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ESA;
+impl Currency for ESA {
+    fn code(&self) -> &'static str {
+        "ESA"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        996
+    }
+
+    fn name(&self) -> &'static str {
+        "Spanish Peseta"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ES"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1981-01-01")
+    }
+}
+
+/// "A" Account (convertible Peseta Account)
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ESB;
+impl Currency for ESB {
+    fn code(&self) -> &'static str {
+        "ESB"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        995
+    }
+
+    fn name(&self) -> &'static str {
+        "\"A\" Account (convertible Peseta Account)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ES"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1994-12-01")
+    }
+}
+
+/// Sudanese Dinar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct SDD;
+impl Currency for SDD {
+    fn code(&self) -> &'static str {
+        "SDD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        736
+    }
+
+    fn name(&self) -> &'static str {
+        "Sudanese Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SD"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2007-07-01")
+    }
+}
+
+/// Sudanese Pound
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct SDP;
+impl Currency for SDP {
+    fn code(&self) -> &'static str {
+        "SDP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        736
+    }
+
+    fn name(&self) -> &'static str {
+        "Sudanese Pound"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SD"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1998-06-01")
+    }
+}
+
+/// Surinam Guilder
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct SRG;
+impl Currency for SRG {
+    fn code(&self) -> &'static str {
+        "SRG"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        740
+    }
+
+    fn name(&self) -> &'static str {
+        "Surinam Guilder"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2004-01-01")
+    }
+}
+
+/// WIR Franc (for electronic)
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct CHC;
+impl Currency for CHC {
+    fn code(&self) -> &'static str {
+        "CHC"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        948
+    }
+
+    fn name(&self) -> &'static str {
+        "WIR Franc (for electronic)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["CH"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2004-11-01")
+    }
+}
+
+/// Tajik Ruble
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct TJR;
+impl Currency for TJR {
+    fn code(&self) -> &'static str {
+        "TJR"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        762
+    }
+
+    fn name(&self) -> &'static str {
+        "Tajik Ruble"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TJ"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2001-04-01")
+    }
+}
+
+/// Timor Escudo
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct TPE;
+impl Currency for TPE {
+    fn code(&self) -> &'static str {
+        "TPE"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        626
+    }
+
+    fn name(&self) -> &'static str {
+        "Timor Escudo"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TL"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2002-11-01")
+    }
+}
+
+/// Turkish Lira
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct TRL;
+impl Currency for TRL {
+    fn code(&self) -> &'static str {
+        "TRL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        792
+    }
+
+    fn name(&self) -> &'static str {
+        "Turkish Lira"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2005-12-01")
+    }
+}
+
+/// Turkmenistan Manat
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct TMM;
+impl Currency for TMM {
+    fn code(&self) -> &'static str {
+        "TMM"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        795
+    }
+
+    fn name(&self) -> &'static str {
+        "Turkmenistan Manat"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["TM"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2009-01-01")
+    }
+}
+
+/// Uganda Shilling
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct UGS;
+impl Currency for UGS {
+    fn code(&self) -> &'static str {
+        "UGS"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        800
+    }
+
+    fn name(&self) -> &'static str {
+        "Uganda Shilling"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UG"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1987-05-01")
+    }
+}
+
+/// Old Shilling
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct UGW;
+impl Currency for UGW {
+    fn code(&self) -> &'static str {
+        "UGW"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        800
+    }
+
+    fn name(&self) -> &'static str {
+        "Old Shilling"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UG"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Karbovanet
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct UAK;
+impl Currency for UAK {
+    fn code(&self) -> &'static str {
+        "UAK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        804
+    }
+
+    fn name(&self) -> &'static str {
+        "Karbovanet"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UA"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1996-09-01")
+    }
+}
+
+/// Rouble
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct SUR;
+impl Currency for SUR {
+    fn code(&self) -> &'static str {
+        "SUR"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        810
+    }
+
+    fn name(&self) -> &'static str {
+        "Rouble"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["SU"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-12-01")
+    }
+}
+
+/// US Dollar (Same day)
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct USS;
+impl Currency for USS {
+    fn code(&self) -> &'static str {
+        "USS"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        998
+    }
+
+    fn name(&self) -> &'static str {
+        "US Dollar (Same day)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["US"]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2014-03-01")
+    }
+}
+
+/// Old Uruguay Peso
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct UYN;
+impl Currency for UYN {
+    fn code(&self) -> &'static str {
+        "UYN"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        858
+    }
+
+    fn name(&self) -> &'static str {
+        "Old Uruguay Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UY"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1989-12-01")
+    }
+}
+
+/// Uruguayan Peso
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct UYP;
+impl Currency for UYP {
+    fn code(&self) -> &'static str {
+        "UYP"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        858
+    }
+
+    fn name(&self) -> &'static str {
+        "Uruguayan Peso"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["UY"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1993-03-01")
+    }
+}
+
+/// Bolivar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct VEB;
+impl Currency for VEB {
+    fn code(&self) -> &'static str {
+        "VEB"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        862
+    }
+
+    fn name(&self) -> &'static str {
+        "Bolivar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["VE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2008-01-01")
+    }
+}
+
+/// Bolívar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct VEF;
+impl Currency for VEF {
+    fn code(&self) -> &'static str {
+        "VEF"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        937
+    }
+
+    fn name(&self) -> &'static str {
+        "Bolívar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["VE"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2018-08-01")
+    }
+}
+
+/// Old Dong
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct VNC;
+impl Currency for VNC {
+    fn code(&self) -> &'static str {
+        "VNC"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        704
+    }
+
+    fn name(&self) -> &'static str {
+        "Old Dong"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["VN"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// Yemeni Dinar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct YDD;
+impl Currency for YDD {
+    fn code(&self) -> &'static str {
+        "YDD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        720
+    }
+
+    fn name(&self) -> &'static str {
+        "Yemeni Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["YD"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1991-09-01")
+    }
+}
+
+/// New Yugoslavian Dinar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct YUD;
+impl Currency for YUD {
+    fn code(&self) -> &'static str {
+        "YUD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        890
+    }
+
+    fn name(&self) -> &'static str {
+        "New Yugoslavian Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["YU"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1990-01-01")
+    }
+}
+
+/// New Dinar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct YUM;
+impl Currency for YUM {
+    fn code(&self) -> &'static str {
+        "YUM"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        891
+    }
+
+    fn name(&self) -> &'static str {
+        "New Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["YU"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2003-07-01")
+    }
+}
+
+/// Yugoslavian Dinar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct YUN;
+impl Currency for YUN {
+    fn code(&self) -> &'static str {
+        "YUN"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        890
+    }
+
+    fn name(&self) -> &'static str {
+        "Yugoslavian Dinar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["YU"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1995-11-01")
+    }
+}
+
+/// New Zaire
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ZRN;
+impl Currency for ZRN {
+    fn code(&self) -> &'static str {
+        "ZRN"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        180
+    }
+
+    fn name(&self) -> &'static str {
+        "New Zaire"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1999-06-01")
+    }
+}
+
+/// Zaire
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ZRZ;
+impl Currency for ZRZ {
+    fn code(&self) -> &'static str {
+        "ZRZ"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        180
+    }
+
+    fn name(&self) -> &'static str {
+        "Zaire"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZR"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1994-02-01")
+    }
+}
+
+/// Zambian Kwacha
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ZMK;
+impl Currency for ZMK {
+    fn code(&self) -> &'static str {
+        "ZMK"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        894
+    }
+
+    fn name(&self) -> &'static str {
+        "Zambian Kwacha"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZM"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2012-12-01")
+    }
+}
+
+/// Zimbabwe Dollar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ZWC;
+impl Currency for ZWC {
+    fn code(&self) -> &'static str {
+        "ZWC"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        716
+    }
+
+    fn name(&self) -> &'static str {
+        "Zimbabwe Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZW"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1989-12-01")
+    }
+}
+
+/// Zimbabwe Dollar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ZWD;
+impl Currency for ZWD {
+    fn code(&self) -> &'static str {
+        "ZWD"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        716
+    }
+
+    fn name(&self) -> &'static str {
+        "Zimbabwe Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZW"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2008-08-01")
+    }
+}
+
+/// Zimbabwe Dollar (new)
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ZWN;
+impl Currency for ZWN {
+    fn code(&self) -> &'static str {
+        "ZWN"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        942
+    }
+
+    fn name(&self) -> &'static str {
+        "Zimbabwe Dollar (new)"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZW"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2008-08-01")
+    }
+}
+
+/// Zimbabwe Dollar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ZWR;
+impl Currency for ZWR {
+    fn code(&self) -> &'static str {
+        "ZWR"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        935
+    }
+
+    fn name(&self) -> &'static str {
+        "Zimbabwe Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZW"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2009-06-01")
+    }
+}
+
+/// Zimbabwe Dollar
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct ZWL;
+impl Currency for ZWL {
+    fn code(&self) -> &'static str {
+        "ZWL"
+    }
+
+    fn minor_units(&self) -> u32 {
+        2
+    }
+
+    fn numeric_code(&self) -> u32 {
+        932
+    }
+
+    fn name(&self) -> &'static str {
+        "Zimbabwe Dollar"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &["ZW"]
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2024-09-01")
+    }
+}
+
+/// Gold-Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct XFO;
+impl Currency for XFO {
+    fn code(&self) -> &'static str {
+        "XFO"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        0
+    }
+
+    fn name(&self) -> &'static str {
+        "Gold-Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2006-10-01")
+    }
+}
+
+/// RINET Funds Code
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct XRE;
+impl Currency for XRE {
+    fn code(&self) -> &'static str {
+        "XRE"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        0
+    }
+
+    fn name(&self) -> &'static str {
+        "RINET Funds Code"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn is_fund(&self) -> bool {
+        true
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("1999-11-01")
+    }
+}
+
+/// UIC-Franc
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct XFU;
+impl Currency for XFU {
+    fn code(&self) -> &'static str {
+        "XFU"
+    }
+
+    fn minor_units(&self) -> u32 {
+        0
+    }
+
+    fn numeric_code(&self) -> u32 {
+        0
+    }
+
+    fn name(&self) -> &'static str {
+        "UIC-Franc"
+    }
+
+    fn countries(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::Supranational
+    }
+
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        parse_date("2013-11-01")
+    }
+}
+
+/// All the currencies defined in this module, ordered by code.
+pub static ALL_CURRENCIES: &[&dyn Currency] = &[
+    &ADP, &AFA, &ALK, &ANG, &AOK, &AON, &AOR, &ARA, &ARP, &ARY, &ATS, &AYM, &AZM, &BAD, &BEC, &BEF,
+    &BEL, &BGJ, &BGK, &BGL, &BGN, &BOP, &BRB, &BRC, &BRE, &BRN, &BRR, &BUK, &BYB, &BYR, &CHC, &CSD,
+    &CSJ, &CSK, &CUC, &CYP, &DDM, &DEM, &ECS, &ECV, &EEK, &ESA, &ESB, &ESP, &FIM, &FRF, &GEK, &GHC,
+    &GHP, &GNE, &GNS, &GQE, &GRD, &GWE, &GWP, &HRD, &HRK, &IEP, &ILP, &ILR, &ISJ, &ITL, &LAJ, &LSM,
+    &LTL, &LTT, &LUC, &LUF, &LUL, &LVL, &LVR, &MGF, &MLF, &MRO, &MTL, &MTP, &MVQ, &MXP, &MZE, &MZM,
+    &NIC, &NLG, &PEH, &PEI, &PES, &PLZ, &PTE, &RHD, &ROK, &ROL, &RUR, &SDD, &SDP, &SIT, &SKK, &SLL,
+    &SRG, &STD, &SUR, &TJR, &TMM, &TPE, &TRL, &UAK, &UGS, &UGW, &USS, &UYN, &UYP, &VEB, &VEF, &VNC,
+    &XEU, &XFO, &XFU, &XRE, &YDD, &YUD, &YUM, &YUN, &ZAL, &ZMK, &ZRN, &ZRZ, &ZWC, &ZWD, &ZWL, &ZWN,
+    &ZWR,
+];
+
+/// A [CurrencyMap] containing all the currencies defined in this module,
+/// which is built the first time it is used.
+pub static CURRENCY_MAP: LazyLock<CurrencyMap<'static>> =
+    LazyLock::new(|| CurrencyMap::from_collection(ALL_CURRENCIES.iter().copied()));
+
+#[cfg(test)]
+mod tests {
+    use crate::iso_currencies;
+    use crate::iso_historic_currencies::*;
+
+    fn date(s: &str) -> SystemTime {
+        parse_date(s).unwrap()
+    }
+
+    #[test]
+    fn all_currencies() {
+        assert!(ALL_CURRENCIES.windows(2).all(|w| w[0].code() < w[1].code()));
+        for currency in ALL_CURRENCIES {
+            assert!(currency.withdrawn_on().is_some());
+            assert_eq!(CURRENCY_MAP.get(currency.code()), Some(*currency));
+            assert_eq!(iso_currencies::CURRENCY_MAP.get(currency.code()), None);
+        }
+    }
+
+    #[test]
+    fn withdrawn_by() {
+        assert_eq!(DEM.withdrawn_on(), Some(date("2002-03-01")));
+        assert!(!DEM.is_withdrawn_by(date("2002-02-28")));
+        assert!(DEM.is_withdrawn_by(date("2002-03-01")));
+        assert!(!ZWL.is_withdrawn_by(date("2024-01-01")));
+        assert!(ZWL.is_withdrawn_by(date("2025-01-01")));
+
+        // current currencies are never withdrawn
+        assert_eq!(iso_currencies::EUR.withdrawn_on(), None);
+        assert!(!iso_currencies::EUR.is_withdrawn_by(date("2002-03-01")));
+    }
+
+    #[test]
+    fn metadata() {
+        assert_eq!(ALL_CURRENCIES.len(), 129);
+        assert_eq!(ITL.minor_units(), 0);
+        assert_eq!(ITL.countries(), &["IT", "SM", "VA"]);
+        assert_eq!(VEF.numeric_code(), 937);
+        assert_eq!(XEU.category(), CurrencyCategory::Supranational);
+        assert_eq!(TRL.category(), CurrencyCategory::National);
+        assert_eq!(CSK.countries(), &["CS"]);
+        assert_eq!(DDM.withdrawn_on(), Some(date("1990-09-01")));
+        assert_eq!(ARY.withdrawn_on(), Some(date("1990-01-01")));
+        assert_eq!(XFO.numeric_code(), 0);
+        assert!(BEC.is_fund());
+        for code in [
+            "MXP", "PLZ", "BRE", "BRR", "ARA", "YUM", "SUR", "CSK", "DDM",
+        ] {
+            assert!(CURRENCY_MAP.contains(code), "{code}");
+        }
+    }
+}
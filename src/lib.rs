@@ -44,7 +44,7 @@
 //! // Adding different currencies produces an Err Result.
 //! assert_eq!(
 //!     m_dyn_usd + m_dyn_jpy,
-//!     Err(MoneyMathError::IncompatibleCurrencies("USD", "JPY"))
+//!     Err(MoneyMathError::IncompatibleCurrencies("USD".to_string(), "JPY".to_string()))
 //! );
 //!
 //! // Locale-aware formatting is provided via the icu crate
@@ -98,18 +98,48 @@
 //! another service or client. The amount is serialized
 //! as a string to preserve precision.
 //!
-//! Because applications can define their own `Currency`
-//! implementations, there's no global map one can use
-//! to deserialize a currency code back into a `Currency`
-//! instance, so deserialization is a two-step process.
-//! First deserialize into a struct with a `Decimal` and
-//! `String` field. Then use the currency code string
-//! to resolve and construct the appropriate `Currency`
-//! instance, and pass that as well as the `Decimal` to
-//! `Money::new()`.
+//! Money instances with a statically-typed currency
+//! can be deserialized directly, as long as the currency
+//! type implements `Default` (all the ISO currencies do).
+//! Deserialization fails if the serialized currency code
+//! doesn't match the expected currency. Money instances
+//! with a dynamically-typed currency are deserialized
+//! using a `serde::MoneySeed`, which resolves the currency
+//! code using a `CurrencyMap`.
+//!
+//! The "toml" and "json" features enable loading currency
+//! definitions from files in those formats, using the
+//! `currency_config` module.
 //!
 //! ## Changes from Previous Versions
 //!
+//! ### Version 3.1.0 -> 4.0.0
+//! - Comparing two dynamically-typed Money instances with
+//!   different currencies via `PartialOrd` now orders them
+//!   by currency code instead of returning `None`, which
+//!   is consistent with the new `Ord` implementations.
+//! - The `Currency` and `RateProvider` traits now require
+//!   `Send + Sync`, so a `Money<&dyn Currency>` can be sent
+//!   to other threads. Currencies that only hold data already
+//!   satisfy these bounds.
+//! - `Currency::code()` now returns a `&str` borrowed from
+//!   the currency instead of a `&'static str`, so codes can
+//!   be provided at runtime. Implementations may still return
+//!   a `&'static str`.
+//! - `MoneyMathError::IncompatibleCurrencies` now holds owned
+//!   `String`s instead of `&'static str`s.
+//! - The operators and comparisons that only look at the amounts
+//!   of two statically-typed Money instances, such as `Add`,
+//!   `PartialEq` and `Ord`, now require the currency type to
+//!   implement `Default`, like `Sum` and deserialization already
+//!   did. The ISO currencies all do, so only custom currency
+//!   structs without a `Default` are affected.
+//! - `MoneyMathError` has new `Overflow` and `DivisionByZero`
+//!   variants, returned by the checked arithmetic methods.
+//!   It is now `#[non_exhaustive]`, so `match` expressions
+//!   need a wildcard arm, and future variants won't be
+//!   breaking changes.
+//!
 //! ### Version 2.0.0 -> 3.0.0
 //! - The `round()` method now rounds the amount to the
 //!   currency's number of minor units by default.
@@ -143,35 +173,102 @@
 
 use std::{
     fmt::Display,
+    hash::{Hash, Hasher},
+    iter::Sum,
     ops::{Add, Div, Mul, Neg, Rem, Sub},
+    time::SystemTime,
 };
 
+use iso_currencies::IsoCurrency;
 use rust_decimal::{prelude::ToPrimitive, Decimal};
 use thiserror::Error;
 
-#[cfg(feature = "serde")]
-use serde::{ser::SerializeStruct, Serialize};
-
 /// Strategies for use with the [Money::round] method.
 pub use rust_decimal::RoundingStrategy;
 
+pub mod allocation;
+#[cfg(any(feature = "toml", feature = "json"))]
+pub mod currency_config;
 pub mod currency_map;
+pub mod custom_currency;
+pub mod euro_legacy;
+pub mod exchange_rate;
 pub mod iso_currencies;
+pub mod iso_historic_currencies;
+pub mod money_bag;
+pub mod parsing;
+pub mod rate_provider;
+pub mod redenomination;
 
 #[cfg(feature = "formatting")]
 pub mod formatting;
 
+#[cfg(feature = "serde")]
+pub mod serde;
+
 /// Common trait for all currencies.
-pub trait Currency {
+///
+/// Currencies must be `Send` and `Sync` so that Money instances with
+/// dynamically-typed currencies (i.e., `Money<&dyn Currency>`) can be
+/// sent across threads and held across `.await` points.
+pub trait Currency: Send + Sync {
     /// Returns the unique ISO alphabetic code for this currency
     /// (e.g., "USD" or "JPY").
-    fn code(&self) -> &'static str;
+    fn code(&self) -> &str;
     /// Returns the number of minor units supported by the currency.
     /// Currencies like USD and EUR currently support 2, but others
     /// like JPY or KRW support zero.
     fn minor_units(&self) -> u32;
     /// Returns the unique ISO numeric code for this currency.
     fn numeric_code(&self) -> u32;
+    /// Returns the English name of the currency (e.g., "US Dollar").
+    /// Defaults to the code for currencies that don't provide a name.
+    fn name(&self) -> &str {
+        self.code()
+    }
+    /// Returns the ISO 3166 alpha-2 codes of the countries and territories
+    /// that use this currency. Defaults to none.
+    fn countries(&self) -> &[&str] {
+        &[]
+    }
+    /// Returns true if this is a fund code, such as USN or CLF, rather than
+    /// a currency used for payments. Defaults to false.
+    fn is_fund(&self) -> bool {
+        false
+    }
+    /// Returns the category of the currency.
+    /// Defaults to [CurrencyCategory::National].
+    fn category(&self) -> CurrencyCategory {
+        CurrencyCategory::National
+    }
+    /// Returns the date from which the currency was no longer in use,
+    /// or None if it is still in use. Defaults to None.
+    fn withdrawn_on(&self) -> Option<SystemTime> {
+        None
+    }
+    /// Returns true if the currency had been withdrawn by the specified
+    /// date. ISO doesn't record when currencies were introduced, so this
+    /// can't tell whether the currency was already in use on that date.
+    fn is_withdrawn_by(&self, date: SystemTime) -> bool {
+        self.withdrawn_on()
+            .is_some_and(|withdrawn| date >= withdrawn)
+    }
+}
+
+/// The kinds of currencies defined by ISO 4217.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CurrencyCategory {
+    /// A currency issued by a country, such as USD or JPY.
+    National,
+    /// A precious metal, such as gold (XAU) or silver (XAG).
+    PreciousMetal,
+    /// A currency or unit of account issued by a supranational
+    /// institution, such as EUR, XOF or XDR.
+    Supranational,
+    /// The code reserved for testing (XTS).
+    Testing,
+    /// The code used for transactions where no currency is involved (XXX).
+    NoCurrency,
 }
 
 /// Debug output for a dynamically-typed Currency.
@@ -215,6 +312,31 @@ impl MinorUnits for &dyn Currency {
     }
 }
 
+/// Used as a trait bound for functions that accept either an owned
+/// statically-typed Currency or a reference to a dynamically-typed one,
+/// and need more than just the `minor_units`.
+pub trait AsCurrency {
+    /// Returns the currency as a dynamically-typed Currency.
+    fn as_currency(&self) -> &dyn Currency;
+}
+
+/// Blanket implementation of [AsCurrency] for any static [Currency] instance.
+impl<C> AsCurrency for C
+where
+    C: Currency,
+{
+    fn as_currency(&self) -> &dyn Currency {
+        self
+    }
+}
+
+/// Blanket implementation of [AsCurrency] for an `&dyn Currency`.
+impl AsCurrency for &dyn Currency {
+    fn as_currency(&self) -> &dyn Currency {
+        *self
+    }
+}
+
 /// An amount of money in a particular currency.
 ///
 /// Money instances are immutable. All operations that would
@@ -327,6 +449,14 @@ where
     pub fn currency(&self) -> C {
         self.currency
     }
+
+    /// Returns a Money with the same amount and a dynamically-typed
+    /// currency that refers to this Money's Currency. This borrows
+    /// rather than consuming `self` because the returned instance
+    /// holds a reference to the currency owned by this one.
+    pub fn as_dyn(&self) -> Money<&dyn Currency> {
+        Money::new(self.amount, &self.currency)
+    }
 }
 
 /// Functions specifically for borrowed dynamically-typed currencies.
@@ -335,14 +465,58 @@ impl Money<&dyn Currency> {
     pub fn currency(&self) -> &dyn Currency {
         self.currency
     }
+
+    /// Returns a Money with the same amount and the statically-typed
+    /// Currency `C`, or [MoneyMathError::IncompatibleCurrencies] if this
+    /// Money's currency code is not the code of `C`.
+    pub fn downcast<C>(&self) -> Result<Money<C>, MoneyMathError>
+    where
+        C: Currency + Default + Copy,
+    {
+        let currency = C::default();
+        if self.currency.code() == currency.code() {
+            Ok(Money::new(self.amount, currency))
+        } else {
+            Err(MoneyMathError::IncompatibleCurrencies(
+                self.currency.code().to_string(),
+                currency.code().to_string(),
+            ))
+        }
+    }
+}
+
+/// Converts a reference to a Money with a statically-typed currency
+/// into a Money with a dynamically-typed currency. See [Money::as_dyn].
+impl<'a, C> From<&'a Money<C>> for Money<&'a dyn Currency>
+where
+    C: Currency + Copy,
+{
+    fn from(value: &'a Money<C>) -> Self {
+        value.as_dyn()
+    }
+}
+
+/// Converts a Money with a dynamically-typed currency into a Money with
+/// the statically-typed Currency `C`. See [Money::downcast].
+impl<C> TryFrom<Money<&dyn Currency>> for Money<C>
+where
+    C: Currency + Default + Copy,
+{
+    type Error = MoneyMathError;
+
+    fn try_from(value: Money<&dyn Currency>) -> Result<Self, Self::Error> {
+        value.downcast()
+    }
 }
 
 /// Allows equality comparisons between Money instances with statically-typed
 /// currencies. The compiler will already ensure that `C` is the same for
-/// both instances, so only the amounts must match.
+/// both instances, so only the amounts must match. Currency types without a
+/// `Default`, such as [IsoCurrency], can hold different currencies, so they
+/// compare the codes as well.
 impl<C> PartialEq for Money<C>
 where
-    C: Currency + PartialEq,
+    C: Currency + Default,
 {
     fn eq(&self, other: &Self) -> bool {
         self.amount == other.amount
@@ -354,21 +528,13 @@ where
 /// and the currency codes must match.
 impl<C> PartialEq<Money<&dyn Currency>> for Money<C>
 where
-    C: Currency + PartialEq,
+    C: Currency,
 {
     fn eq(&self, other: &Money<&dyn Currency>) -> bool {
         self.amount == other.amount && self.currency.code() == other.currency.code()
     }
 }
 
-/// Allows equality comparisons between Money instances with dynamically-typed
-/// currencies. Both the amounts and currency codes must match.
-impl PartialEq for Money<&dyn Currency> {
-    fn eq(&self, other: &Self) -> bool {
-        self.amount == other.amount && self.currency.code() == other.currency.code()
-    }
-}
-
 /// Allows equality comparisons between Money instances with dynamically-typed
 /// currencies and those with statically-typed currencies. Both the amounts
 /// and currency codes must match.
@@ -382,11 +548,17 @@ where
 }
 
 /// Errors that can occur when doing math with Money instances that
-/// have dynamically-typed currencies
+/// have dynamically-typed currencies, or when using the checked
+/// arithmetic methods such as [Money::checked_add].
 #[derive(Debug, Error, PartialEq, Clone)]
+#[non_exhaustive]
 pub enum MoneyMathError {
     #[error("the money instances have incompatible currencies ({0}, {1})")]
-    IncompatibleCurrencies(&'static str, &'static str),
+    IncompatibleCurrencies(String, String),
+    #[error("the result of the operation is too large to represent")]
+    Overflow,
+    #[error("attempted to divide by zero")]
+    DivisionByZero,
 }
 
 /// Macro for implementing a binary operation where the
@@ -398,7 +570,7 @@ macro_rules! impl_binary_op {
         #[doc = " for Money instances with a static currency."]
         impl<C> $trait for Money<C>
         where
-            C: Currency,
+            C: Currency + Default,
         {
             type Output = Self;
 
@@ -410,15 +582,21 @@ macro_rules! impl_binary_op {
             }
         }
 
+        impl_binary_op!(@runtime $trait, $method, &dyn Currency);
+        impl_binary_op!(@runtime $trait, $method, IsoCurrency);
+
         #[doc = "Supports "]
         #[doc = stringify!($trait)]
-        #[doc = " for two Money instances with dynamically-typed currencies."]
-        #[doc = " The Output is a Result instead of a Money since the operation"]
-        #[doc = " can fail if the currencies are incompatible."]
-        impl $trait for Money<&dyn Currency> {
+        #[doc = " for a Money instance with a dynamically-typed Currency"]
+        #[doc = " and a Money instance with a statically-typed Currency. The output"]
+        #[doc = " is a Result since the operation can fail if the currencies are incompatible."]
+        impl<C> $trait<Money<C>> for Money<&dyn Currency>
+        where
+            C: Currency,
+        {
             type Output = Result<Self, MoneyMathError>;
 
-            fn $method(self, rhs: Self) -> Self::Output {
+            fn $method(self, rhs: Money<C>) -> Self::Output {
                 if self.currency.code() == rhs.currency.code() {
                     Ok(Self {
                         amount: self.amount.$method(rhs.amount),
@@ -426,8 +604,8 @@ macro_rules! impl_binary_op {
                     })
                 } else {
                     Err(MoneyMathError::IncompatibleCurrencies(
-                        self.currency.code(),
-                        rhs.currency.code(),
+                        self.currency.code().to_string(),
+                        rhs.currency.code().to_string(),
                     ))
                 }
             }
@@ -435,16 +613,16 @@ macro_rules! impl_binary_op {
 
         #[doc = "Supports "]
         #[doc = stringify!($trait)]
-        #[doc = " for a Money instance with a dynamically-typed Currency"]
-        #[doc = " and a Money instance with a statically-typed Currency. The output"]
+        #[doc = " for a Money instance with a statically-typed Currency"]
+        #[doc = " and a Money instance with a dynamically-typed Currency. The output"]
         #[doc = " is a Result since the operation can fail if the currencies are incompatible."]
-        impl<C> $trait<Money<C>> for Money<&dyn Currency>
+        impl<C> $trait<Money<&dyn Currency>> for Money<C>
         where
             C: Currency,
         {
             type Output = Result<Self, MoneyMathError>;
 
-            fn $method(self, rhs: Money<C>) -> Self::Output {
+            fn $method(self, rhs: Money<&dyn Currency>) -> Self::Output {
                 if self.currency.code() == rhs.currency.code() {
                     Ok(Self {
                         amount: self.amount.$method(rhs.amount),
@@ -452,25 +630,23 @@ macro_rules! impl_binary_op {
                     })
                 } else {
                     Err(MoneyMathError::IncompatibleCurrencies(
-                        self.currency.code(),
-                        rhs.currency.code(),
+                        self.currency.code().to_string(),
+                        rhs.currency.code().to_string(),
                     ))
                 }
             }
         }
-
+    };
+    (@runtime $trait:ident, $method:ident, $currency:ty) => {
         #[doc = "Supports "]
         #[doc = stringify!($trait)]
-        #[doc = " for a Money instance with a statically-typed Currency"]
-        #[doc = " and a Money instance with a dynamically-typed Currency. The output"]
-        #[doc = " is a Result since the operation can fail if the currencies are incompatible."]
-        impl<C> $trait<Money<&dyn Currency>> for Money<C>
-        where
-            C: Currency,
-        {
+        #[doc = concat!(" for two Money instances with a `", stringify!($currency), "` currency.")]
+        #[doc = " The Output is a Result instead of a Money since the currencies are"]
+        #[doc = " only known at runtime, so the operation can fail if they are incompatible."]
+        impl $trait for Money<$currency> {
             type Output = Result<Self, MoneyMathError>;
 
-            fn $method(self, rhs: Money<&dyn Currency>) -> Self::Output {
+            fn $method(self, rhs: Self) -> Self::Output {
                 if self.currency.code() == rhs.currency.code() {
                     Ok(Self {
                         amount: self.amount.$method(rhs.amount),
@@ -478,8 +654,8 @@ macro_rules! impl_binary_op {
                     })
                 } else {
                     Err(MoneyMathError::IncompatibleCurrencies(
-                        self.currency.code(),
-                        rhs.currency.code(),
+                        self.currency.code().to_string(),
+                        rhs.currency.code().to_string(),
                     ))
                 }
             }
@@ -576,12 +752,191 @@ macro_rules! impl_unary_op {
 
 impl_unary_op!(Neg, neg);
 
+/// Macro for implementing a checked binary operation where the
+/// right-hand-side is another Money. These return an error instead
+/// of panicking when the result overflows.
+macro_rules! impl_checked_op {
+    ($method:ident, $decimal_method:ident, $doc:literal) => {
+        /// Checked arithmetic for statically-typed currencies.
+        impl<C> Money<C>
+        where
+            C: Currency + Default + Copy,
+        {
+            #[doc = $doc]
+            #[doc = " Returns [MoneyMathError::Overflow] if the result is too large to represent."]
+            pub fn $method(&self, rhs: Self) -> Result<Self, MoneyMathError> {
+                self.amount
+                    .$decimal_method(rhs.amount)
+                    .map(|amount| Money::new(amount, self.currency))
+                    .ok_or(MoneyMathError::Overflow)
+            }
+        }
+
+        impl_checked_op!(@runtime $method, $decimal_method, $doc, &dyn Currency);
+        impl_checked_op!(@runtime $method, $decimal_method, $doc, IsoCurrency);
+    };
+    (@runtime $method:ident, $decimal_method:ident, $doc:literal, $currency:ty) => {
+        #[doc = concat!("Checked arithmetic for Money with a `", stringify!($currency), "` currency.")]
+        impl Money<$currency> {
+            #[doc = $doc]
+            #[doc = " Returns [MoneyMathError::IncompatibleCurrencies] if the currencies differ,"]
+            #[doc = " or [MoneyMathError::Overflow] if the result is too large to represent."]
+            pub fn $method(&self, rhs: Money<$currency>) -> Result<Self, MoneyMathError> {
+                if self.currency.code() != rhs.currency.code() {
+                    return Err(MoneyMathError::IncompatibleCurrencies(
+                        self.currency.code().to_string(),
+                        rhs.currency.code().to_string(),
+                    ));
+                }
+                self.amount
+                    .$decimal_method(rhs.amount)
+                    .map(|amount| Money::new(amount, self.currency))
+                    .ok_or(MoneyMathError::Overflow)
+            }
+        }
+    };
+}
+
+impl_checked_op!(checked_add, checked_add, "Adds another Money to this one.");
+impl_checked_op!(
+    checked_sub,
+    checked_sub,
+    "Subtracts another Money from this one."
+);
+
+/// Checked arithmetic with numeric right-hand-sides, for both
+/// statically and dynamically-typed currencies.
+impl<C> Money<C>
+where
+    C: Copy,
+{
+    /// Multiplies the amount by anything that can be converted into a Decimal.
+    /// Returns [MoneyMathError::Overflow] if the result is too large to represent.
+    pub fn checked_mul<N: Into<Decimal>>(&self, rhs: N) -> Result<Self, MoneyMathError> {
+        self.amount
+            .checked_mul(rhs.into())
+            .map(|amount| Money::new(amount, self.currency))
+            .ok_or(MoneyMathError::Overflow)
+    }
+
+    /// Divides the amount by anything that can be converted into a Decimal.
+    /// Returns [MoneyMathError::DivisionByZero] if the divisor is zero,
+    /// or [MoneyMathError::Overflow] if the result is too large to represent.
+    pub fn checked_div<N: Into<Decimal>>(&self, rhs: N) -> Result<Self, MoneyMathError> {
+        let rhs = rhs.into();
+        if rhs.is_zero() {
+            return Err(MoneyMathError::DivisionByZero);
+        }
+        self.amount
+            .checked_div(rhs)
+            .map(|amount| Money::new(amount, self.currency))
+            .ok_or(MoneyMathError::Overflow)
+    }
+
+    /// Returns the remainder of dividing the amount by anything that can be
+    /// converted into a Decimal. Returns [MoneyMathError::DivisionByZero]
+    /// if the divisor is zero, or [MoneyMathError::Overflow] if the result
+    /// is too large to represent.
+    pub fn checked_rem<N: Into<Decimal>>(&self, rhs: N) -> Result<Self, MoneyMathError> {
+        let rhs = rhs.into();
+        if rhs.is_zero() {
+            return Err(MoneyMathError::DivisionByZero);
+        }
+        self.amount
+            .checked_rem(rhs)
+            .map(|amount| Money::new(amount, self.currency))
+            .ok_or(MoneyMathError::Overflow)
+    }
+}
+
+/// Supports summing an iterator of Money instances with a static currency.
+/// The sum of an empty iterator is zero in that currency.
+impl<C> Sum for Money<C>
+where
+    C: Currency + Default + Copy,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Money::new(Decimal::ZERO, C::default()), Add::add)
+    }
+}
+
+/// Supports summing an iterator of references to Money instances
+/// with a static currency. The sum of an empty iterator is zero
+/// in that currency.
+impl<'a, C> Sum<&'a Money<C>> for Money<C>
+where
+    C: Currency + Default + Copy,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Adds a [TrySum::try_sum] method to iterators of Money instances with
+/// currencies that are only known at runtime, such as `&dyn Currency` or
+/// [IsoCurrency], since [Sum] can't report an error.
+///
+/// ```rust
+/// use rust_decimal::Decimal;
+/// use doubloon::{
+///     Money, Currency, MoneyMathError, TrySum,
+///     iso_currencies::{IsoCurrency, USD, JPY},
+/// };
+///
+/// let usd: &dyn Currency = &USD;
+/// let jpy: &dyn Currency = &JPY;
+/// let items = vec![Money::new(1, usd), Money::new(2, usd)];
+/// assert_eq!(items.into_iter().try_sum(usd), Ok(Money::new(3, usd)));
+///
+/// let items = vec![Money::new(1, usd), Money::new(2, jpy)];
+/// assert_eq!(
+///     items.into_iter().try_sum(usd),
+///     Err(MoneyMathError::IncompatibleCurrencies("USD".to_string(), "JPY".to_string()))
+/// );
+///
+/// let items = vec![Money::new(1, IsoCurrency::JPY), Money::new(2, IsoCurrency::JPY)];
+/// assert_eq!(
+///     items.into_iter().try_sum(IsoCurrency::JPY),
+///     Ok(Money::new(3, IsoCurrency::JPY))
+/// );
+/// ```
+pub trait TrySum<C> {
+    /// Sums the Money instances, which must all be in the specified currency.
+    /// The currency is required so that the sum of an empty iterator can be
+    /// zero in that currency. Stops at the first Money in a different currency,
+    /// returning [MoneyMathError::IncompatibleCurrencies], or at the first
+    /// overflow, returning [MoneyMathError::Overflow].
+    fn try_sum(self, currency: C) -> Result<Money<C>, MoneyMathError>;
+}
+
+impl<I, C> TrySum<C> for I
+where
+    I: Iterator<Item = Money<C>>,
+    C: AsCurrency + Copy,
+{
+    fn try_sum(mut self, currency: C) -> Result<Money<C>, MoneyMathError> {
+        let code = currency.as_currency().code();
+        self.try_fold(Money::new(Decimal::ZERO, currency), |sum, m| {
+            if m.currency.as_currency().code() != code {
+                return Err(MoneyMathError::IncompatibleCurrencies(
+                    code.to_string(),
+                    m.currency.as_currency().code().to_string(),
+                ));
+            }
+            sum.amount
+                .checked_add(m.amount)
+                .map(|amount| Money::new(amount, currency))
+                .ok_or(MoneyMathError::Overflow)
+        })
+    }
+}
+
 /// Supports dividing one Money with a static currency by another
 /// with the same static currency. The result will be a [Decimal]
 /// representing the percentage difference between the two amounts.
 impl<C> Div for Money<C>
 where
-    C: Currency,
+    C: Currency + Default,
 {
     type Output = Decimal;
 
@@ -590,26 +945,6 @@ where
     }
 }
 
-/// Supports dividing one Money with a dynamic currency by
-/// another one. If the currencies are not the same, the
-/// Result will be an error. If the currencies are the same,
-/// the Result will be a [Decimal] representing the percentage
-/// difference between the two amounts.
-impl Div for Money<&dyn Currency> {
-    type Output = Result<Decimal, MoneyMathError>;
-
-    fn div(self, rhs: Self) -> Self::Output {
-        if self.currency.code() == rhs.currency.code() {
-            Ok(self.amount.div(rhs.amount))
-        } else {
-            Err(MoneyMathError::IncompatibleCurrencies(
-                self.currency.code(),
-                rhs.currency.code(),
-            ))
-        }
-    }
-}
-
 /// Supports dividing one Money with a dynamic currency by
 /// another Money with a static currency. If the currencies
 /// are not the same, the Result will be an error. If the
@@ -626,8 +961,8 @@ where
             Ok(self.amount.div(rhs.amount))
         } else {
             Err(MoneyMathError::IncompatibleCurrencies(
-                self.currency.code(),
-                rhs.currency.code(),
+                self.currency.code().to_string(),
+                rhs.currency.code().to_string(),
             ))
         }
     }
@@ -649,65 +984,121 @@ where
             Ok(self.amount.div(rhs.amount))
         } else {
             Err(MoneyMathError::IncompatibleCurrencies(
-                self.currency.code(),
-                rhs.currency.code(),
+                self.currency.code().to_string(),
+                rhs.currency.code().to_string(),
             ))
         }
     }
 }
 
+/// Money instances with the same statically-typed currency are equal
+/// when their amounts are equal, regardless of scale.
+impl<C> Eq for Money<C> where C: Currency + Default {}
+
 /// Allows ordering comparisons for Money instances with the same
 /// statically-typed currency.
 impl<C> PartialOrd for Money<C>
 where
-    C: Currency + PartialOrd,
+    C: Currency + Default,
 {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.amount.partial_cmp(&other.amount)
+        Some(self.cmp(other))
     }
 }
 
-/// Allows ordering comparisons for Money instances with
-/// dynamically-typed currencies.
-impl PartialOrd for Money<&dyn Currency> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        if self.currency.code() == other.currency.code() {
-            self.amount.partial_cmp(&other.amount)
-        } else {
-            None
-        }
+/// Orders Money instances with the same statically-typed currency by amount.
+impl<C> Ord for Money<C>
+where
+    C: Currency + Default,
+{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.amount.cmp(&other.amount)
     }
 }
 
-#[cfg(feature = "serde")]
-impl<C> Serialize for Money<C>
+/// Hashes only the amount, since the currency is part of the type.
+/// Amounts that are equal but have different scales, such as 1.0 and 1.00,
+/// produce the same hash.
+impl<C> Hash for Money<C>
 where
-    C: Currency,
+    C: Currency + Default,
 {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let mut struct_serializer = serializer.serialize_struct("money", 2)?;
-        struct_serializer.serialize_field("amount", &self.amount.to_string())?;
-        struct_serializer.serialize_field("currency", &self.currency.code())?;
-        struct_serializer.end()
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.amount.hash(state);
     }
 }
 
-#[cfg(feature = "serde")]
-impl Serialize for Money<&dyn Currency> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let mut struct_serializer = serializer.serialize_struct("money", 2)?;
-        struct_serializer.serialize_field("amount", &self.amount.to_string())?;
-        struct_serializer.serialize_field("currency", &self.currency.code())?;
-        struct_serializer.end()
-    }
+/// Macro for implementing the comparisons and Money division for a currency
+/// type that is only known at runtime, such as `&dyn Currency` or [IsoCurrency].
+/// Unlike the statically-typed implementations, these must compare the
+/// currency codes as well as the amounts.
+macro_rules! impl_runtime_ops {
+    ($currency:ty) => {
+        #[doc = concat!("Allows equality comparisons between Money instances with a `", stringify!($currency), "`")]
+        #[doc = " currency. Both the amounts and currency codes must match."]
+        impl PartialEq for Money<$currency> {
+            fn eq(&self, other: &Self) -> bool {
+                self.amount == other.amount && self.currency.code() == other.currency.code()
+            }
+        }
+
+        #[doc = concat!("Money instances with a `", stringify!($currency), "` currency are equal")]
+        #[doc = " when their currency codes and amounts are equal."]
+        impl Eq for Money<$currency> {}
+
+        #[doc = concat!("Allows ordering comparisons for Money instances with a `", stringify!($currency), "`")]
+        #[doc = " currency. This is consistent with the [Ord] implementation, so Money in"]
+        #[doc = " different currencies are ordered by currency code rather than being incomparable."]
+        impl PartialOrd for Money<$currency> {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        #[doc = concat!("Orders Money instances with a `", stringify!($currency), "` currency by")]
+        #[doc = " currency code, and then by amount within the same currency."]
+        impl Ord for Money<$currency> {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.currency
+                    .code()
+                    .cmp(other.currency.code())
+                    .then_with(|| self.amount.cmp(&other.amount))
+            }
+        }
+
+        /// Hashes the currency code and the amount. Amounts that are equal but
+        /// have different scales, such as 1.0 and 1.00, produce the same hash.
+        impl Hash for Money<$currency> {
+            fn hash<H: Hasher>(&self, state: &mut H) {
+                self.currency.code().hash(state);
+                self.amount.hash(state);
+            }
+        }
+
+        #[doc = concat!("Supports dividing one Money with a `", stringify!($currency), "` currency by")]
+        #[doc = " another one. If the currencies are not the same, the Result will be an"]
+        #[doc = " error. If the currencies are the same, the Result will be a [Decimal]"]
+        #[doc = " representing the percentage difference between the two amounts."]
+        impl Div for Money<$currency> {
+            type Output = Result<Decimal, MoneyMathError>;
+
+            fn div(self, rhs: Self) -> Self::Output {
+                if self.currency.code() == rhs.currency.code() {
+                    Ok(self.amount.div(rhs.amount))
+                } else {
+                    Err(MoneyMathError::IncompatibleCurrencies(
+                        self.currency.code().to_string(),
+                        rhs.currency.code().to_string(),
+                    ))
+                }
+            }
+        }
+    };
 }
 
+impl_runtime_ops!(&dyn Currency);
+impl_runtime_ops!(IsoCurrency);
+
 /// [Display::fmt] is supposed to be infallible, so this just writes the amount
 /// followed by the currency code. For more sophisticated formatting, use the
 /// the format method available with the "formatting" crate feature.
@@ -735,7 +1126,7 @@ mod tests {
     use currency_map::CurrencyMap;
     use rust_decimal::Decimal;
 
-    const CURRENCIES: LazyLock<CurrencyMap> =
+    static CURRENCIES: LazyLock<CurrencyMap> =
         LazyLock::new(|| CurrencyMap::from_collection([&USD as &dyn Currency, &JPY]));
 
     #[test]
@@ -903,15 +1294,15 @@ mod tests {
         assert_eq!(
             Money::new(Decimal::ONE, currency_usd) + Money::new(Decimal::ONE, currency_jpy),
             Err(MoneyMathError::IncompatibleCurrencies(
-                currency_usd.code(),
-                currency_jpy.code(),
+                currency_usd.code().to_string(),
+                currency_jpy.code().to_string(),
             )),
         );
         assert_eq!(
             Money::new(Decimal::ONE, currency_jpy) + Money::new(Decimal::ONE, currency_usd),
             Err(MoneyMathError::IncompatibleCurrencies(
-                currency_jpy.code(),
-                currency_usd.code(),
+                currency_jpy.code().to_string(),
+                currency_usd.code().to_string(),
             )),
         );
 
@@ -926,8 +1317,8 @@ mod tests {
             (Money::new(Decimal::ONE, currency_usd) + Money::new(Decimal::ONE, currency_jpy))
                 .and_then(|m| m + Money::new(Decimal::ONE, currency_usd)),
             Err(MoneyMathError::IncompatibleCurrencies(
-                currency_usd.code(),
-                currency_jpy.code()
+                currency_usd.code().to_string(),
+                currency_jpy.code().to_string()
             )),
         );
     }
@@ -960,15 +1351,15 @@ mod tests {
         assert_eq!(
             Money::new(Decimal::ONE, currency_usd) + Money::new(Decimal::ONE, JPY),
             Err(MoneyMathError::IncompatibleCurrencies(
-                currency_usd.code(),
-                JPY.code()
+                currency_usd.code().to_string(),
+                JPY.code().to_string()
             )),
         );
         assert_eq!(
             Money::new(Decimal::ONE, USD) + Money::new(Decimal::ONE, currency_jpy),
             Err(MoneyMathError::IncompatibleCurrencies(
-                USD.code(),
-                currency_jpy.code()
+                USD.code().to_string(),
+                currency_jpy.code().to_string()
             )),
         );
     }
@@ -1001,7 +1392,10 @@ mod tests {
         // dynamic, different currencies
         assert_eq!(
             Money::new(Decimal::TWO, currency_jpy) - Money::new(Decimal::ONE, currency_usd),
-            Err(MoneyMathError::IncompatibleCurrencies("JPY", "USD"))
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "JPY".to_string(),
+                "USD".to_string()
+            ))
         );
 
         // mixed, same currency
@@ -1017,11 +1411,17 @@ mod tests {
         // mixed, different currencies
         assert_eq!(
             Money::new(Decimal::TWO, JPY) - Money::new(Decimal::ONE, currency_usd),
-            Err(MoneyMathError::IncompatibleCurrencies("JPY", "USD"))
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "JPY".to_string(),
+                "USD".to_string()
+            ))
         );
         assert_eq!(
             Money::new(Decimal::TWO, currency_jpy) - Money::new(Decimal::ONE, USD),
-            Err(MoneyMathError::IncompatibleCurrencies("JPY", "USD"))
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "JPY".to_string(),
+                "USD".to_string()
+            ))
         );
     }
 
@@ -1093,7 +1493,10 @@ mod tests {
         let currency_jpy = CURRENCIES.get("JPY").unwrap();
         assert_eq!(
             Money::new(3, currency_usd) / Money::new(2, currency_jpy),
-            Err(MoneyMathError::IncompatibleCurrencies("USD", "JPY"))
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "JPY".to_string()
+            ))
         );
 
         // mixed, same currency
@@ -1105,7 +1508,10 @@ mod tests {
         // mixed, different currencies
         assert_eq!(
             Money::new(3, currency_usd) / Money::new(2, JPY),
-            Err(MoneyMathError::IncompatibleCurrencies("USD", "JPY"))
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "JPY".to_string()
+            ))
         );
     }
 
@@ -1200,16 +1606,87 @@ mod tests {
         assert!(Money::new(Decimal::ONE, currency_usd) < Money::new(Decimal::TWO, currency_usd));
         assert!(Money::new(Decimal::TWO, currency_usd) > Money::new(Decimal::ONE, currency_usd));
 
-        // different currencies -> incomparable
+        // different currencies -> ordered by currency code
         assert_eq!(
             Money::new(Decimal::ONE, currency_usd)
                 .partial_cmp(&Money::new(Decimal::TWO, currency_jpy)),
-            None
+            Some(std::cmp::Ordering::Greater)
         );
+        assert!(Money::new(Decimal::TWO, currency_jpy) < Money::new(Decimal::ONE, currency_usd));
+    }
+
+    #[test]
+    fn ord_static() {
+        let mut prices = vec![
+            Money::new(Decimal::new(300, 2), USD),
+            Money::new(Decimal::new(-1, 0), USD),
+            Money::new(Decimal::new(15, 1), USD),
+        ];
+        prices.sort();
+        assert_eq!(
+            prices,
+            vec![
+                Money::new(-1, USD),
+                Money::new(Decimal::new(15, 1), USD),
+                Money::new(3, USD),
+            ]
+        );
+        assert_eq!(prices.iter().max(), Some(&Money::new(3, USD)));
+    }
+
+    #[test]
+    fn ord_dynamic() {
+        let currency_usd = CURRENCIES.get("USD").unwrap();
+        let currency_jpy = CURRENCIES.get("JPY").unwrap();
+        let mut prices = vec![
+            Money::new(1, currency_usd),
+            Money::new(100, currency_jpy),
+            Money::new(-1, currency_usd),
+            Money::new(5, currency_jpy),
+        ];
+        prices.sort();
+        assert_eq!(
+            prices,
+            vec![
+                Money::new(5, currency_jpy),
+                Money::new(100, currency_jpy),
+                Money::new(-1, currency_usd),
+                Money::new(1, currency_usd),
+            ]
+        );
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::{BTreeSet, HashSet};
 
-        // different currencies -> neither greater than nor less than
-        assert!(!(Money::new(Decimal::ONE, currency_usd) < Money::new(Decimal::TWO, currency_jpy)));
-        assert!(!(Money::new(Decimal::TWO, currency_usd) > Money::new(Decimal::ONE, currency_jpy)));
+        let amounts: HashSet<Money<USD>> = [
+            Money::new(Decimal::new(10, 1), USD),
+            Money::new(Decimal::new(100, 2), USD),
+            Money::new(Decimal::ONE, USD),
+            Money::new(Decimal::TWO, USD),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(amounts.len(), 2);
+        assert!(amounts.contains(&Money::new(Decimal::new(1000, 3), USD)));
+
+        let currency_usd = CURRENCIES.get("USD").unwrap();
+        let currency_jpy = CURRENCIES.get("JPY").unwrap();
+        let amounts: HashSet<Money<&dyn Currency>> = [
+            Money::new(Decimal::new(10, 1), currency_usd),
+            Money::new(Decimal::new(100, 2), currency_usd),
+            Money::new(Decimal::ONE, currency_jpy),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(amounts.len(), 2);
+
+        let sorted: BTreeSet<Money<&dyn Currency>> = amounts.into_iter().collect();
+        assert_eq!(
+            sorted.into_iter().collect::<Vec<_>>(),
+            vec![Money::new(1, currency_jpy), Money::new(1, currency_usd)]
+        );
     }
 
     #[test]
@@ -1266,4 +1743,215 @@ mod tests {
             m.to_minor_units(RoundingStrategy::MidpointNearestEven)
         );
     }
+
+    #[test]
+    fn checked_static() {
+        let m = Money::new(Decimal::TEN, USD);
+        assert_eq!(m.checked_add(Money::new(1, USD)), Ok(Money::new(11, USD)));
+        assert_eq!(m.checked_sub(Money::new(1, USD)), Ok(Money::new(9, USD)));
+        assert_eq!(m.checked_mul(2), Ok(Money::new(20, USD)));
+        assert_eq!(m.checked_div(4), Ok(Money::new(Decimal::new(25, 1), USD)));
+        assert_eq!(m.checked_rem(3), Ok(Money::new(1, USD)));
+
+        let max = Money::new(Decimal::MAX, USD);
+        assert_eq!(max.checked_add(m), Err(MoneyMathError::Overflow));
+        assert_eq!(
+            Money::new(Decimal::MIN, USD).checked_sub(m),
+            Err(MoneyMathError::Overflow)
+        );
+        assert_eq!(max.checked_mul(2), Err(MoneyMathError::Overflow));
+        assert_eq!(
+            max.checked_div(Decimal::new(1, 1)),
+            Err(MoneyMathError::Overflow)
+        );
+        assert_eq!(m.checked_div(0), Err(MoneyMathError::DivisionByZero));
+        assert_eq!(m.checked_rem(0), Err(MoneyMathError::DivisionByZero));
+    }
+
+    #[test]
+    fn checked_dynamic() {
+        let currency_usd = CURRENCIES.get("USD").unwrap();
+        let currency_jpy = CURRENCIES.get("JPY").unwrap();
+        let m = Money::new(Decimal::TEN, currency_usd);
+        assert_eq!(
+            m.checked_add(Money::new(1, currency_usd)),
+            Ok(Money::new(11, currency_usd))
+        );
+        assert_eq!(
+            m.checked_sub(Money::new(1, currency_usd)),
+            Ok(Money::new(9, currency_usd))
+        );
+        assert_eq!(m.checked_mul(2), Ok(Money::new(20, currency_usd)));
+        assert_eq!(m.checked_div(0), Err(MoneyMathError::DivisionByZero));
+        assert_eq!(m.checked_rem(3), Ok(Money::new(1, currency_usd)));
+
+        assert_eq!(
+            m.checked_add(Money::new(1, currency_jpy)),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "JPY".to_string()
+            ))
+        );
+        assert_eq!(
+            m.checked_sub(Money::new(1, currency_jpy)),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "JPY".to_string()
+            ))
+        );
+        assert_eq!(
+            Money::new(Decimal::MAX, currency_usd).checked_add(m),
+            Err(MoneyMathError::Overflow)
+        );
+    }
+
+    #[test]
+    fn sum_static() {
+        let items = vec![Money::new(1, USD), Money::new(2, USD), Money::new(3, USD)];
+        assert_eq!(items.iter().sum::<Money<USD>>(), Money::new(6, USD));
+        assert_eq!(items.into_iter().sum::<Money<USD>>(), Money::new(6, USD));
+        assert_eq!(
+            Vec::<Money<JPY>>::new().into_iter().sum::<Money<JPY>>(),
+            Money::new(0, JPY)
+        );
+    }
+
+    #[test]
+    fn try_sum_dynamic() {
+        let currency_usd = CURRENCIES.get("USD").unwrap();
+        let currency_jpy = CURRENCIES.get("JPY").unwrap();
+        let items = vec![Money::new(1, currency_usd), Money::new(2, currency_usd)];
+        assert_eq!(
+            items.iter().copied().try_sum(currency_usd),
+            Ok(Money::new(3, currency_usd))
+        );
+        assert_eq!(
+            std::iter::empty().try_sum(currency_jpy),
+            Ok(Money::new(0, currency_jpy))
+        );
+
+        // the starting currency must match too
+        assert_eq!(
+            items.into_iter().try_sum(currency_jpy),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "JPY".to_string(),
+                "USD".to_string()
+            ))
+        );
+
+        // stops at the first mismatched currency
+        let mut items = vec![
+            Money::new(1, currency_usd),
+            Money::new(2, currency_jpy),
+            Money::new(3, currency_usd),
+        ]
+        .into_iter();
+        assert_eq!(
+            items.by_ref().try_sum(currency_usd),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "JPY".to_string()
+            ))
+        );
+        assert_eq!(items.next(), Some(Money::new(3, currency_usd)));
+
+        assert_eq!(
+            [
+                Money::new(Decimal::MAX, currency_usd),
+                Money::new(1, currency_usd)
+            ]
+            .into_iter()
+            .try_sum(currency_usd),
+            Err(MoneyMathError::Overflow)
+        );
+    }
+
+    #[test]
+    fn static_to_dynamic() {
+        let m = Money::new(Decimal::new(1234, 2), USD);
+        let m_dyn = m.as_dyn();
+        assert_eq!(m_dyn.amount(), m.amount());
+        assert_eq!(m_dyn.currency().code(), "USD");
+        assert_eq!(
+            m_dyn,
+            Money::new(Decimal::new(1234, 2), CURRENCIES.get("USD").unwrap())
+        );
+
+        let m_dyn: Money<&dyn Currency> = (&m).into();
+        assert_eq!(m_dyn, m);
+        assert_eq!(
+            (m_dyn + Money::new(1, CURRENCIES.get("USD").unwrap())),
+            Ok(Money::new(
+                Decimal::new(1334, 2),
+                CURRENCIES.get("USD").unwrap()
+            ))
+        );
+    }
+
+    #[test]
+    fn dynamic_to_static() {
+        let m = Money::new(Decimal::new(1234, 2), CURRENCIES.get("USD").unwrap());
+        assert_eq!(
+            m.downcast::<USD>(),
+            Ok(Money::new(Decimal::new(1234, 2), USD))
+        );
+        assert_eq!(
+            m.downcast::<JPY>(),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "JPY".to_string()
+            ))
+        );
+
+        let m_usd: Result<Money<USD>, _> = m.try_into();
+        assert_eq!(m_usd, Ok(Money::new(Decimal::new(1234, 2), USD)));
+        assert_eq!(
+            Money::<JPY>::try_from(m),
+            Err(MoneyMathError::IncompatibleCurrencies(
+                "USD".to_string(),
+                "JPY".to_string()
+            ))
+        );
+
+        // round trip
+        let m_usd = m_usd.unwrap();
+        assert_eq!(m_usd.as_dyn().downcast::<USD>(), Ok(m_usd));
+    }
+
+    #[test]
+    fn send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Money<&dyn Currency>>();
+        assert_send_sync::<Money<&'static dyn Currency>>();
+        assert_send_sync::<CurrencyMap>();
+        assert_send_sync::<MoneyMathError>();
+    }
+
+    #[test]
+    fn dynamic_money_across_threads() {
+        let currency_usd = CURRENCIES.get("USD").unwrap();
+        let m = Money::new(Decimal::new(1050, 2), currency_usd);
+
+        // move into another thread and back
+        let handle = std::thread::spawn(move || (m + Money::new(1, currency_usd)).unwrap());
+        assert_eq!(
+            handle.join().unwrap(),
+            Money::new(Decimal::new(1150, 2), currency_usd)
+        );
+
+        // share a borrowed map and money between scoped threads
+        let currencies = CurrencyMap::from_collection([&USD as &dyn Currency, &JPY]);
+        let prices = [
+            Money::new(1, currencies.get("USD").unwrap()),
+            Money::new(100, currencies.get("JPY").unwrap()),
+        ];
+        let doubled: Vec<String> = std::thread::scope(|scope| {
+            let handles: Vec<_> = prices
+                .iter()
+                .map(|price| scope.spawn(|| (*price * 2).to_string()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert_eq!(doubled, vec!["2 USD", "200 JPY"]);
+    }
 }
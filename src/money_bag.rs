@@ -0,0 +1,359 @@
+//! A multi-currency accumulator.
+//!
+//! Adding two `Money<&dyn Currency>` instances with different currencies
+//! returns an error, which makes totalling a list of mixed-currency amounts
+//! painful. A [MoneyBag] instead holds one amount per currency code, so any
+//! Money can be added to or subtracted from it without failing. The bag can
+//! then be collapsed into a single reporting currency using a [RateProvider].
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_decimal::Decimal;
+//! use doubloon::{
+//!     Money, Currency,
+//!     iso_currencies::{USD, EUR},
+//!     money_bag::MoneyBag,
+//!     rate_provider::InMemoryRateProvider,
+//! };
+//!
+//! let eur: &dyn Currency = &EUR;
+//! let mut bag = MoneyBag::new();
+//! bag += Money::new(10, USD);
+//! bag += Money::new(5, eur);
+//! bag -= Money::new(2, USD);
+//!
+//! assert_eq!(bag.balance::<USD>(), Money::new(8, USD));
+//! assert_eq!(bag.get("EUR"), Some(Money::new(5, eur)));
+//! assert_eq!(bag.len(), 2);
+//!
+//! let mut rates = InMemoryRateProvider::new();
+//! rates.insert(&EUR, &USD, Decimal::new(110, 2), None);
+//! let total = bag.collapse(&USD, &rates, None)?;
+//! assert_eq!(total, Money::new(Decimal::new(1350, 2), &USD as &dyn Currency));
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    collections::BTreeMap,
+    ops::{Add, AddAssign, Sub, SubAssign},
+    sync::Arc,
+    time::SystemTime,
+};
+
+use rust_decimal::Decimal;
+
+use crate::{
+    rate_provider::{RateError, RateProvider},
+    Currency, Money,
+};
+
+/// The currency of an entry in a [MoneyBag]. Statically-typed currencies
+/// are copied into the bag, while dynamically-typed currencies are borrowed.
+#[derive(Clone)]
+enum BagCurrency<'c> {
+    Owned(Arc<dyn Currency + 'c>),
+    Borrowed(&'c dyn Currency),
+}
+
+impl BagCurrency<'_> {
+    fn get(&self) -> &dyn Currency {
+        match self {
+            BagCurrency::Owned(currency) => currency.as_ref(),
+            BagCurrency::Borrowed(currency) => *currency,
+        }
+    }
+}
+
+/// An accumulator that holds one amount per currency code.
+#[derive(Clone, Default)]
+pub struct MoneyBag<'c> {
+    entries: BTreeMap<String, (BagCurrency<'c>, Decimal)>,
+}
+
+impl<'c> MoneyBag<'c> {
+    /// Constructs a new empty [MoneyBag].
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    /// Adds an amount to the entry for the currency, creating
+    /// the entry using `currency` if it doesn't yet exist.
+    fn add_amount<F>(&mut self, code: &str, amount: Decimal, currency: F)
+    where
+        F: FnOnce() -> BagCurrency<'c>,
+    {
+        match self.entries.get_mut(code) {
+            Some((_, total)) => *total += amount,
+            None => {
+                self.entries.insert(code.to_string(), (currency(), amount));
+            }
+        }
+    }
+
+    /// Returns the number of currencies in the bag.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the bag contains no currencies.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the total for each currency in the bag,
+    /// ordered by currency code. Currencies that have been added and then
+    /// subtracted back to zero are still included.
+    pub fn iter(&self) -> impl Iterator<Item = Money<&dyn Currency>> {
+        self.entries
+            .values()
+            .map(|(currency, amount)| Money::new(*amount, currency.get()))
+    }
+
+    /// Returns the total for the specified currency code,
+    /// or None if that currency has never been added to the bag.
+    pub fn get(&self, code: &str) -> Option<Money<&dyn Currency>> {
+        self.entries
+            .get(code)
+            .map(|(currency, amount)| Money::new(*amount, currency.get()))
+    }
+
+    /// Returns the total for a statically-typed currency,
+    /// which is zero if that currency has never been added to the bag.
+    pub fn balance<C>(&self) -> Money<C>
+    where
+        C: Currency + Default + Copy,
+    {
+        let currency = C::default();
+        let amount = self
+            .entries
+            .get(currency.code())
+            .map(|(_, amount)| *amount)
+            .unwrap_or(Decimal::ZERO);
+        Money::new(amount, currency)
+    }
+
+    /// Converts every total in the bag into the `target` currency using rates
+    /// from the provider, and returns their sum. Each converted amount is
+    /// rounded according to the rounding strategy of its rate, if any, so
+    /// round the result if the rates aren't rounded. Returns an error if the
+    /// provider has no rate for one of the currencies, or
+    /// [RateError::Overflow] if an amount is too large to represent.
+    pub fn collapse<'t, P>(
+        &self,
+        target: &'t dyn Currency,
+        provider: &P,
+        as_of: Option<SystemTime>,
+    ) -> Result<Money<&'t dyn Currency>, RateError>
+    where
+        P: RateProvider + ?Sized,
+    {
+        let mut total = Decimal::ZERO;
+        for (currency, amount) in self.entries.values() {
+            let currency = currency.get();
+            let converted = if currency.code() == target.code() {
+                *amount
+            } else {
+                // the rate is from the entry's currency,
+                // so the only possible error is overflow
+                provider
+                    .rate(currency, target, as_of)?
+                    .convert(Money::new(*amount, currency))
+                    .map_err(|_| RateError::Overflow)?
+                    .amount
+            };
+            total = total.checked_add(converted).ok_or(RateError::Overflow)?;
+        }
+        Ok(Money::new(total, target))
+    }
+}
+
+impl std::fmt::Debug for MoneyBag<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+macro_rules! impl_bag_ops {
+    ($money:ty, [$($generics:tt)*], $bag_currency:expr) => {
+        #[doc = "Adds the Money to the total for its currency."]
+        impl<'c, $($generics)*> AddAssign<$money> for MoneyBag<'c> {
+            fn add_assign(&mut self, rhs: $money) {
+                self.add_amount(rhs.currency.code(), rhs.amount, || $bag_currency(rhs.currency));
+            }
+        }
+
+        #[doc = "Subtracts the Money from the total for its currency."]
+        impl<'c, $($generics)*> SubAssign<$money> for MoneyBag<'c> {
+            fn sub_assign(&mut self, rhs: $money) {
+                self.add_amount(rhs.currency.code(), -rhs.amount, || $bag_currency(rhs.currency));
+            }
+        }
+
+        #[doc = "Adds the Money to the total for its currency."]
+        impl<'c, $($generics)*> Add<$money> for MoneyBag<'c> {
+            type Output = Self;
+
+            fn add(mut self, rhs: $money) -> Self::Output {
+                self += rhs;
+                self
+            }
+        }
+
+        #[doc = "Subtracts the Money from the total for its currency."]
+        impl<'c, $($generics)*> Sub<$money> for MoneyBag<'c> {
+            type Output = Self;
+
+            fn sub(mut self, rhs: $money) -> Self::Output {
+                self -= rhs;
+                self
+            }
+        }
+
+        #[doc = "Adds each Money to the total for its currency."]
+        impl<'c, $($generics)*> Extend<$money> for MoneyBag<'c> {
+            fn extend<I: IntoIterator<Item = $money>>(&mut self, iter: I) {
+                for money in iter {
+                    *self += money;
+                }
+            }
+        }
+
+        #[doc = "Collects Money into a bag holding the total for each currency."]
+        impl<'c, $($generics)*> FromIterator<$money> for MoneyBag<'c> {
+            fn from_iter<I: IntoIterator<Item = $money>>(iter: I) -> Self {
+                let mut bag = MoneyBag::new();
+                bag.extend(iter);
+                bag
+            }
+        }
+    };
+}
+
+impl_bag_ops!(Money<C>, [C: Currency + Copy + 'c], |c| BagCurrency::Owned(Arc::new(c)));
+impl_bag_ops!(Money<&'c dyn Currency>, [], BagCurrency::Borrowed);
+
+#[cfg(test)]
+mod tests {
+    use crate::iso_currencies::{EUR, GBP, JPY, USD};
+    use crate::money_bag::*;
+    use crate::rate_provider::InMemoryRateProvider;
+    use crate::{exchange_rate::ExchangeRate, RoundingStrategy};
+
+    #[test]
+    fn add_and_subtract() {
+        let usd: &dyn Currency = &USD;
+        let mut bag = MoneyBag::new();
+        assert!(bag.is_empty());
+
+        bag += Money::new(10, USD);
+        bag += Money::new(Decimal::new(250, 2), usd);
+        bag += Money::new(100, JPY);
+        bag -= Money::new(1, USD);
+        bag -= Money::new(1, &EUR as &dyn Currency);
+
+        assert_eq!(bag.len(), 3);
+        assert_eq!(bag.balance::<USD>(), Money::new(Decimal::new(1150, 2), USD));
+        assert_eq!(bag.balance::<JPY>(), Money::new(100, JPY));
+        assert_eq!(bag.balance::<EUR>(), Money::new(-1, EUR));
+        assert_eq!(bag.balance::<GBP>(), Money::new(0, GBP));
+        assert_eq!(bag.get("USD"), Some(Money::new(Decimal::new(1150, 2), usd)));
+        assert_eq!(bag.get("GBP"), None);
+
+        let bag = bag + Money::new(1, GBP) - Money::new(100, JPY);
+        assert_eq!(bag.balance::<GBP>(), Money::new(1, GBP));
+        assert_eq!(bag.get("JPY"), Some(Money::new(0, &JPY as &dyn Currency)));
+    }
+
+    #[test]
+    fn iterate() {
+        let bag: MoneyBag = [
+            Money::new(1, &USD as &dyn Currency),
+            Money::new(2, &EUR),
+            Money::new(3, &USD),
+        ]
+        .into_iter()
+        .collect();
+
+        let totals: Vec<String> = bag.iter().map(|m| m.to_string()).collect();
+        assert_eq!(totals, vec!["2 EUR", "4 USD"]);
+    }
+
+    #[test]
+    fn collect_static() {
+        let mut bag: MoneyBag = vec![Money::new(1, USD), Money::new(2, USD)]
+            .into_iter()
+            .collect();
+        bag.extend([Money::new(3, JPY)]);
+        assert_eq!(bag.balance::<USD>(), Money::new(3, USD));
+        assert_eq!(bag.balance::<JPY>(), Money::new(3, JPY));
+    }
+
+    #[test]
+    fn collapse() {
+        let mut rates = InMemoryRateProvider::new();
+        rates.insert(&EUR, &USD, Decimal::new(110, 2), None);
+        rates.insert(&USD, &JPY, 150, None);
+
+        let bag = MoneyBag::new() + Money::new(10, USD) + Money::new(5, EUR);
+        assert_eq!(
+            bag.collapse(&USD, &rates, None),
+            Ok(Money::new(Decimal::new(1550, 2), &USD as &dyn Currency))
+        );
+        assert_eq!(
+            bag.collapse(&JPY, &rates, None),
+            Err(RateError::NotFound {
+                from: "EUR".to_string(),
+                to: "JPY".to_string()
+            })
+        );
+        assert_eq!(
+            MoneyBag::new().collapse(&GBP, &rates, None),
+            Ok(Money::new(0, &GBP as &dyn Currency))
+        );
+    }
+
+    /// Returns the rates from a table, rounded toward zero.
+    struct RoundingProvider(InMemoryRateProvider);
+
+    impl RateProvider for RoundingProvider {
+        fn rate<'c>(
+            &self,
+            from: &'c dyn Currency,
+            to: &'c dyn Currency,
+            as_of: Option<SystemTime>,
+        ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+            self.0
+                .rate(from, to, as_of)
+                .map(|rate| rate.with_rounding(RoundingStrategy::ToZero))
+        }
+    }
+
+    #[test]
+    fn collapse_rounding() {
+        let mut rates = InMemoryRateProvider::new();
+        rates.insert(&EUR, &USD, Decimal::new(1105, 3), None);
+        let bag = MoneyBag::new() + Money::new(Decimal::new(1, 2), USD) + Money::new(3, EUR);
+        // 3 EUR is 3.315 USD
+        assert_eq!(
+            bag.collapse(&USD, &rates, None),
+            Ok(Money::new(Decimal::new(3325, 3), &USD as &dyn Currency))
+        );
+        assert_eq!(
+            bag.collapse(&USD, &RoundingProvider(rates), None),
+            Ok(Money::new(Decimal::new(332, 2), &USD as &dyn Currency))
+        );
+    }
+
+    #[test]
+    fn collapse_overflow() {
+        let mut rates = InMemoryRateProvider::new();
+        rates.insert(&EUR, &USD, Decimal::TWO, None);
+        let bag = MoneyBag::new() + Money::new(Decimal::MAX, EUR);
+        assert_eq!(bag.collapse(&USD, &rates, None), Err(RateError::Overflow));
+
+        let bag = MoneyBag::new() + Money::new(Decimal::MAX, USD) + Money::new(1, EUR);
+        assert_eq!(bag.collapse(&USD, &rates, None), Err(RateError::Overflow));
+    }
+}
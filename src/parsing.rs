@@ -0,0 +1,234 @@
+//! Parsing of Money instances from strings like `"12.34 USD"` or `"USD 12.34"`.
+//!
+//! The first form is what the [Display](std::fmt::Display) implementation
+//! for [Money] writes, so parsing round-trips with `to_string()`.
+//! Money with a statically-typed currency can be parsed using [str::parse],
+//! while money with a dynamically-typed currency is parsed using
+//! [Money::parse], which resolves the currency code using a [CurrencyMap].
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_decimal::Decimal;
+//! use doubloon::{
+//!     {Money, Currency},
+//!     iso_currencies::{USD, JPY},
+//!     currency_map::CurrencyMap,
+//!     parsing::ParseMoneyError,
+//! };
+//!
+//! let m: Money<USD> = "12.34 USD".parse()?;
+//! assert_eq!(m, Money::new(Decimal::new(1234, 2), USD));
+//! assert_eq!("USD 12.34".parse::<Money<USD>>()?, m);
+//! assert_eq!(
+//!     "100 JPY".parse::<Money<USD>>(),
+//!     Err(ParseMoneyError::MismatchedCurrency {
+//!         expected: "USD".to_string(),
+//!         found: "JPY".to_string()
+//!     })
+//! );
+//!
+//! let currencies = CurrencyMap::from_collection(vec![&USD as &dyn Currency, &JPY]);
+//! let m = Money::parse("100 JPY", &currencies)?;
+//! assert_eq!(m, Money::new(100, JPY));
+//! # Ok(())
+//! # }
+//! ```
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::{currency_map::CurrencyMap, iso_currencies::IsoCurrency, Currency, Money};
+
+/// Errors that can occur when parsing a Money from a string.
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum ParseMoneyError {
+    #[error("invalid money string {0:?}: expected an amount and currency code")]
+    InvalidFormat(String),
+    #[error("invalid money amount {0:?}")]
+    InvalidAmount(String),
+    #[error("unknown currency code {0:?}")]
+    UnknownCurrency(String),
+    #[error("expected currency {expected} but found {found}")]
+    MismatchedCurrency { expected: String, found: String },
+}
+
+/// Splits a string like `"12.34 USD"` or `"USD 12.34"` into the parsed
+/// amount and the (unresolved) currency code.
+pub(crate) fn parse_parts(s: &str) -> Result<(Decimal, &str), ParseMoneyError> {
+    let mut parts = s.split_whitespace();
+    let (first, second) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(first), Some(second), None) => (first, second),
+        _ => return Err(ParseMoneyError::InvalidFormat(s.to_string())),
+    };
+
+    // Amounts start with a digit, sign, or decimal point,
+    // while currency codes never do.
+    let (amount, code) = if first.starts_with(|c: char| c.is_ascii_digit() || "+-.".contains(c)) {
+        (first, second)
+    } else {
+        (second, first)
+    };
+
+    let amount = Decimal::from_str(amount)
+        .map_err(|_| ParseMoneyError::InvalidAmount(amount.to_string()))?;
+    Ok((amount, code))
+}
+
+/// Used as a trait bound when parsing or deserializing Money, to find
+/// the currency for the code in the string. A statically-typed Currency
+/// only accepts its own code, while an [IsoCurrency] accepts the code of
+/// any of the currencies defined in [iso_currencies](crate::iso_currencies).
+pub trait FromCurrencyCode: Sized {
+    /// Returns the currency with the specified alphabetic code, or a
+    /// [ParseMoneyError] if there is no such currency.
+    fn from_currency_code(code: &str) -> Result<Self, ParseMoneyError>;
+}
+
+/// Blanket implementation of [FromCurrencyCode] for any static [Currency],
+/// which returns [ParseMoneyError::MismatchedCurrency] for any other code.
+impl<C> FromCurrencyCode for C
+where
+    C: Currency + Default,
+{
+    fn from_currency_code(code: &str) -> Result<Self, ParseMoneyError> {
+        let currency = C::default();
+        if code != currency.code() {
+            return Err(ParseMoneyError::MismatchedCurrency {
+                expected: currency.code().to_string(),
+                found: code.to_string(),
+            });
+        }
+        Ok(currency)
+    }
+}
+
+/// Implementation of [FromCurrencyCode] for an [IsoCurrency], which
+/// returns [ParseMoneyError::UnknownCurrency] for codes it doesn't define.
+impl FromCurrencyCode for IsoCurrency {
+    fn from_currency_code(code: &str) -> Result<Self, ParseMoneyError> {
+        code.parse()
+    }
+}
+
+/// Parses a Money with a statically-typed currency or an [IsoCurrency].
+/// The currency code in the string must match the code of `C`, or for an
+/// [IsoCurrency], be the code of any ISO currency.
+impl<C> FromStr for Money<C>
+where
+    C: FromCurrencyCode + Copy,
+{
+    type Err = ParseMoneyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (amount, code) = parse_parts(s)?;
+        Ok(Money::new(amount, C::from_currency_code(code)?))
+    }
+}
+
+/// Parsing functions specifically for dynamically-typed currencies.
+impl<'c> Money<&'c dyn Currency> {
+    /// Parses a Money with a dynamically-typed currency, resolving the
+    /// currency code using the provided [CurrencyMap].
+    pub fn parse(s: &str, currencies: &CurrencyMap<'c>) -> Result<Self, ParseMoneyError> {
+        let (amount, code) = parse_parts(s)?;
+        let currency = currencies
+            .get(code)
+            .ok_or_else(|| ParseMoneyError::UnknownCurrency(code.to_string()))?;
+        Ok(Money::new(amount, currency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iso_currencies::{JPY, USD};
+    use crate::parsing::*;
+
+    #[test]
+    fn parse_static() {
+        assert_eq!(
+            "12.34 USD".parse::<Money<USD>>(),
+            Ok(Money::new(Decimal::new(1234, 2), USD))
+        );
+        assert_eq!(
+            "USD 12.34".parse::<Money<USD>>(),
+            Ok(Money::new(Decimal::new(1234, 2), USD))
+        );
+        assert_eq!("-100 JPY".parse::<Money<JPY>>(), Ok(Money::new(-100, JPY)));
+        assert_eq!(
+            "  .5   USD ".parse::<Money<USD>>(),
+            Ok(Money::new(Decimal::new(5, 1), USD))
+        );
+    }
+
+    #[test]
+    fn parse_round_trips_display() {
+        let m = Money::new(Decimal::new(-123456789, 4), USD);
+        assert_eq!(m.to_string().parse::<Money<USD>>(), Ok(m));
+    }
+
+    #[test]
+    fn parse_static_mismatched_currency() {
+        assert_eq!(
+            "12.34 JPY".parse::<Money<USD>>(),
+            Err(ParseMoneyError::MismatchedCurrency {
+                expected: "USD".to_string(),
+                found: "JPY".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_invalid_amount() {
+        assert_eq!(
+            "12.3.4 USD".parse::<Money<USD>>(),
+            Err(ParseMoneyError::InvalidAmount("12.3.4".to_string()))
+        );
+        assert_eq!(
+            "USD abc".parse::<Money<USD>>(),
+            Err(ParseMoneyError::InvalidAmount("abc".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_format() {
+        for s in ["", "12.34", "12.34 USD extra"] {
+            assert_eq!(
+                s.parse::<Money<USD>>(),
+                Err(ParseMoneyError::InvalidFormat(s.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_dynamic() {
+        let currencies = CurrencyMap::from_collection([&USD as &dyn Currency, &JPY]);
+        assert_eq!(
+            Money::parse("12.34 USD", &currencies),
+            Ok(Money::new(
+                Decimal::new(1234, 2),
+                currencies.get("USD").unwrap()
+            ))
+        );
+        assert_eq!(
+            Money::parse("JPY 100", &currencies),
+            Ok(Money::new(100, currencies.get("JPY").unwrap()))
+        );
+        assert_eq!(
+            Money::parse("12.34 EUR", &currencies),
+            Err(ParseMoneyError::UnknownCurrency("EUR".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_iso_currency() {
+        assert_eq!(
+            "12.34 JPY".parse::<Money<IsoCurrency>>(),
+            Ok(Money::new(Decimal::new(1234, 2), IsoCurrency::JPY))
+        );
+        assert_eq!(
+            "12.34 XYZ".parse::<Money<IsoCurrency>>(),
+            Err(ParseMoneyError::UnknownCurrency("XYZ".to_string()))
+        );
+    }
+}
@@ -0,0 +1,518 @@
+//! Pluggable sources of [ExchangeRate]s.
+//!
+//! A [RateProvider] answers the question "what is the rate from currency A
+//! to currency B, optionally as of some point in time?" This module includes
+//! providers backed by an in-memory table, a CSV file, and the European
+//! Central Bank's daily reference-rate XML format, as well as a caching
+//! wrapper, a chain that falls back from one provider to the next, and a
+//! provider that computes cross rates through a pivot currency.
+//!
+//! All providers return rates in terms of the `&dyn Currency` instances
+//! passed to [RateProvider::rate], so they work with any [Currency],
+//! including those resolved through a [CurrencyMap](crate::currency_map::CurrencyMap).
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_decimal::Decimal;
+//! use doubloon::{
+//!     Money, Currency,
+//!     iso_currencies::{USD, EUR, GBP},
+//!     rate_provider::{RateProvider, InMemoryRateProvider, ChainedRateProvider},
+//! };
+//!
+//! let mut table = InMemoryRateProvider::new();
+//! table.insert(&EUR, &USD, Decimal::new(108, 2), None);
+//!
+//! let rate = table.rate(&EUR, &USD, None)?;
+//! assert_eq!(rate.convert(Money::new(10, &EUR as &dyn Currency))?, Money::new(Decimal::new(108, 1), &USD as &dyn Currency));
+//!
+//! // rates in the opposite direction are inverted automatically
+//! assert_eq!(table.rate(&USD, &EUR, None)?.rate(), Decimal::ONE / Decimal::new(108, 2));
+//!
+//! // try the table first, then another provider
+//! let mut fallback = InMemoryRateProvider::new();
+//! fallback.insert(&GBP, &USD, Decimal::new(127, 2), None);
+//! let chain = ChainedRateProvider::new(vec![Box::new(table), Box::new(fallback)]);
+//! assert_eq!(chain.rate(&GBP, &USD, None)?.rate(), Decimal::new(127, 2));
+//! # Ok(())
+//! # }
+//! ```
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, SystemTime},
+};
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::{exchange_rate::ExchangeRate, Currency, RoundingStrategy};
+
+mod csv;
+mod ecb;
+mod triangulation;
+
+pub use csv::CsvRateProvider;
+pub use ecb::EcbRateProvider;
+pub use triangulation::{
+    Conversion, ConversionPath, IntermediateRounding, TriangulatingRateProvider,
+};
+
+/// Errors that can occur when getting or loading exchange rates.
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum RateError {
+    #[error("no exchange rate is available from {from} to {to}")]
+    NotFound { from: String, to: String },
+    #[error("unknown currency code {0:?}")]
+    UnknownCurrency(String),
+    #[error("invalid rate data on line {line}: {message}")]
+    InvalidData { line: usize, message: String },
+    #[error("unable to read rate data: {0}")]
+    Io(String),
+    #[error("the converted amount is too large to represent")]
+    Overflow,
+}
+
+/// A source of exchange rates. Providers must be `Send` and `Sync` so they
+/// can be shared between threads, such as the handlers of an async service.
+pub trait RateProvider: Send + Sync {
+    /// Returns the rate for converting from one currency to another. If `as_of`
+    /// is provided, the rate in effect at that time is returned; otherwise the
+    /// most recent rate is returned.
+    fn rate<'c>(
+        &self,
+        from: &'c dyn Currency,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError>;
+}
+
+impl<P> RateProvider for &P
+where
+    P: RateProvider + ?Sized,
+{
+    fn rate<'c>(
+        &self,
+        from: &'c dyn Currency,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+        (**self).rate(from, to, as_of)
+    }
+}
+
+impl<P> RateProvider for Box<P>
+where
+    P: RateProvider + ?Sized,
+{
+    fn rate<'c>(
+        &self,
+        from: &'c dyn Currency,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+        (**self).rate(from, to, as_of)
+    }
+}
+
+/// The rates for one pair of currencies, sorted by effective time.
+type RateHistory = Vec<(Option<SystemTime>, Decimal)>;
+
+/// The from-currency code, to-currency code, and time of a cached rate.
+type CacheKey = (String, String, SystemTime);
+
+/// A cached rate and its rounding strategy.
+type CachedRate = (Decimal, Option<RoundingStrategy>);
+
+fn not_found(from: &dyn Currency, to: &dyn Currency) -> RateError {
+    RateError::NotFound {
+        from: from.code().to_string(),
+        to: to.code().to_string(),
+    }
+}
+
+/// A rate provider backed by an in-memory table.
+///
+/// Each rate may have an effective time, after which it applies until
+/// superseded by a later rate for the same pair of currencies. Rates without
+/// an effective time apply at all times, unless superseded. If there is no
+/// rate for a pair, but there is one for the opposite direction, it is
+/// inverted. Converting a currency to itself always uses a rate of one.
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryRateProvider {
+    rates: HashMap<(String, String), RateHistory>,
+}
+
+impl InMemoryRateProvider {
+    /// Constructs a new empty [InMemoryRateProvider].
+    pub fn new() -> Self {
+        Self {
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Adds a rate to the table, effective from the specified time
+    /// or at all times if `effective` is None. If there was already a rate
+    /// for the same pair and effective time, it is replaced.
+    pub fn insert<N: Into<Decimal>>(
+        &mut self,
+        from: &dyn Currency,
+        to: &dyn Currency,
+        rate: N,
+        effective: Option<SystemTime>,
+    ) {
+        let entries = self
+            .rates
+            .entry((from.code().to_string(), to.code().to_string()))
+            .or_default();
+        let rate = rate.into();
+        // keep entries sorted by effective time, with None first
+        match entries.binary_search_by(|(e, _)| e.cmp(&effective)) {
+            Ok(index) => entries[index].1 = rate,
+            Err(index) => entries.insert(index, (effective, rate)),
+        }
+    }
+
+    /// Returns the number of currency pairs in the table.
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    /// Returns true if the table contains no rates.
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// Returns the rate in effect for the pair of codes at the specified time.
+    fn lookup(&self, from: &str, to: &str, as_of: Option<SystemTime>) -> Option<Decimal> {
+        let entries = self.rates.get(&(from.to_string(), to.to_string()))?;
+        entries
+            .iter()
+            .rev()
+            .find(|(effective, _)| match (effective, as_of) {
+                (Some(effective), Some(as_of)) => *effective <= as_of,
+                _ => true,
+            })
+            .map(|(_, rate)| *rate)
+    }
+}
+
+impl RateProvider for InMemoryRateProvider {
+    fn rate<'c>(
+        &self,
+        from: &'c dyn Currency,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+        if from.code() == to.code() {
+            return Ok(ExchangeRate::new(from, to, Decimal::ONE));
+        }
+        if let Some(rate) = self.lookup(from.code(), to.code(), as_of) {
+            return Ok(ExchangeRate::new(from, to, rate));
+        }
+        self.lookup(to.code(), from.code(), as_of)
+            .and_then(|rate| Decimal::ONE.checked_div(rate))
+            .map(|rate| ExchangeRate::new(from, to, rate))
+            .ok_or_else(|| not_found(from, to))
+    }
+}
+
+/// A rate provider that remembers the rates returned by another provider,
+/// so that slow providers are only asked once for each pair and time.
+/// Errors are not cached, and neither are the latest rates (those requested
+/// with an `as_of` of None), since they change over time.
+#[derive(Debug, Default)]
+pub struct CachingRateProvider<P> {
+    inner: P,
+    cache: Mutex<HashMap<CacheKey, CachedRate>>,
+}
+
+impl<P> CachingRateProvider<P>
+where
+    P: RateProvider,
+{
+    /// Constructs a new [CachingRateProvider] wrapping another provider.
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Removes all cached rates.
+    pub fn clear(&self) {
+        self.cache().clear();
+    }
+
+    /// Returns the wrapped provider.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    fn cache(&self) -> MutexGuard<'_, HashMap<CacheKey, CachedRate>> {
+        self.cache
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl<P> RateProvider for CachingRateProvider<P>
+where
+    P: RateProvider,
+{
+    fn rate<'c>(
+        &self,
+        from: &'c dyn Currency,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+        let Some(time) = as_of else {
+            return self.inner.rate(from, to, as_of);
+        };
+        let key = (from.code().to_string(), to.code().to_string(), time);
+        let cached = self.cache().get(&key).copied();
+        if let Some((rate, rounding)) = cached {
+            let rate = ExchangeRate::new(from, to, rate);
+            return Ok(match rounding {
+                Some(strategy) => rate.with_rounding(strategy),
+                None => rate,
+            });
+        }
+        // the cache isn't locked while the inner provider looks up
+        // the rate, so a slow lookup doesn't block other threads
+        let rate = self.inner.rate(from, to, as_of)?;
+        self.cache().insert(key, (rate.rate(), rate.rounding()));
+        Ok(rate)
+    }
+}
+
+/// A rate provider that asks each of several providers in order,
+/// returning the first rate found. If none of them have a rate,
+/// the error from the last provider is returned.
+#[derive(Default)]
+pub struct ChainedRateProvider {
+    providers: Vec<Box<dyn RateProvider>>,
+}
+
+impl ChainedRateProvider {
+    /// Constructs a new [ChainedRateProvider] that tries the providers in order.
+    pub fn new(providers: Vec<Box<dyn RateProvider>>) -> Self {
+        Self { providers }
+    }
+
+    /// Adds another provider to the end of the chain.
+    pub fn push(&mut self, provider: Box<dyn RateProvider>) {
+        self.providers.push(provider);
+    }
+}
+
+impl RateProvider for ChainedRateProvider {
+    fn rate<'c>(
+        &self,
+        from: &'c dyn Currency,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+        let mut error = not_found(from, to);
+        for provider in self.providers.iter() {
+            match provider.rate(from, to, as_of) {
+                Ok(rate) => return Ok(rate),
+                Err(e) => error = e,
+            }
+        }
+        Err(error)
+    }
+}
+
+/// Parses a `YYYY-MM-DD` date into the SystemTime at midnight UTC on that day.
+pub(crate) fn parse_date(s: &str) -> Option<SystemTime> {
+    let mut parts = s.trim().splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+    if day == 0 || day > days_in_month {
+        return None;
+    }
+
+    // days since the Unix epoch, from Howard Hinnant's days_from_civil
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let year_of_era = y.rem_euclid(400);
+    let shifted_month = (month as i64 + 9) % 12;
+    let day_of_year = (153 * shifted_month + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let offset = Duration::from_secs(days.unsigned_abs() * 86400);
+    if days >= 0 {
+        SystemTime::UNIX_EPOCH.checked_add(offset)
+    } else {
+        SystemTime::UNIX_EPOCH.checked_sub(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iso_currencies::{EUR, GBP, JPY, USD};
+    use crate::rate_provider::*;
+
+    fn date(s: &str) -> SystemTime {
+        parse_date(s).unwrap()
+    }
+
+    #[test]
+    fn parse_dates() {
+        assert_eq!(date("1970-01-01"), SystemTime::UNIX_EPOCH);
+        assert_eq!(
+            date("2024-03-01"),
+            SystemTime::UNIX_EPOCH + Duration::from_secs(1709251200)
+        );
+        assert_eq!(
+            date("1969-12-31"),
+            SystemTime::UNIX_EPOCH - Duration::from_secs(86400)
+        );
+        assert_eq!(parse_date("2023-02-29"), None);
+        assert_eq!(parse_date("2024-13-01"), None);
+        assert_eq!(parse_date("2024-01"), None);
+        assert_eq!(parse_date("yesterday"), None);
+    }
+
+    #[test]
+    fn in_memory_lookup() {
+        let mut table = InMemoryRateProvider::new();
+        table.insert(&EUR, &USD, Decimal::new(108, 2), None);
+
+        let rate = table.rate(&EUR, &USD, None).unwrap();
+        assert_eq!(rate.from().code(), "EUR");
+        assert_eq!(rate.to().code(), "USD");
+        assert_eq!(rate.rate(), Decimal::new(108, 2));
+
+        assert_eq!(
+            table.rate(&USD, &EUR, None).unwrap().rate(),
+            Decimal::ONE / Decimal::new(108, 2)
+        );
+        assert_eq!(table.rate(&JPY, &JPY, None).unwrap().rate(), Decimal::ONE);
+        assert_eq!(
+            table.rate(&EUR, &JPY, None),
+            Err(RateError::NotFound {
+                from: "EUR".to_string(),
+                to: "JPY".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn in_memory_as_of() {
+        let mut table = InMemoryRateProvider::new();
+        table.insert(&EUR, &USD, Decimal::new(110, 2), Some(date("2024-02-01")));
+        table.insert(&EUR, &USD, Decimal::new(100, 2), None);
+        table.insert(&EUR, &USD, Decimal::new(108, 2), Some(date("2024-01-01")));
+
+        let rate_on = |d: &str| table.rate(&EUR, &USD, Some(date(d))).unwrap().rate();
+        assert_eq!(rate_on("2023-06-01"), Decimal::new(100, 2));
+        assert_eq!(rate_on("2024-01-01"), Decimal::new(108, 2));
+        assert_eq!(rate_on("2024-01-31"), Decimal::new(108, 2));
+        assert_eq!(rate_on("2024-06-01"), Decimal::new(110, 2));
+        assert_eq!(
+            table.rate(&EUR, &USD, None).unwrap().rate(),
+            Decimal::new(110, 2)
+        );
+    }
+
+    #[test]
+    fn in_memory_without_undated_rate() {
+        let mut table = InMemoryRateProvider::new();
+        table.insert(&EUR, &USD, Decimal::new(108, 2), Some(date("2024-01-01")));
+        assert!(table.rate(&EUR, &USD, Some(date("2023-12-31"))).is_err());
+    }
+
+    /// Counts how many times it has been asked for a rate.
+    struct CountingProvider {
+        table: InMemoryRateProvider,
+        calls: Mutex<usize>,
+    }
+
+    impl RateProvider for CountingProvider {
+        fn rate<'c>(
+            &self,
+            from: &'c dyn Currency,
+            to: &'c dyn Currency,
+            as_of: Option<SystemTime>,
+        ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+            *self.calls.lock().unwrap() += 1;
+            self.table
+                .rate(from, to, as_of)
+                .map(|rate| rate.with_rounding(RoundingStrategy::ToZero))
+        }
+    }
+
+    #[test]
+    fn caching() {
+        let mut table = InMemoryRateProvider::new();
+        table.insert(&EUR, &USD, Decimal::new(108, 2), None);
+        let caching = CachingRateProvider::new(CountingProvider {
+            table,
+            calls: Mutex::new(0),
+        });
+
+        let as_of = Some(date("2024-01-31"));
+        for _ in 0..3 {
+            let rate = caching.rate(&EUR, &USD, as_of).unwrap();
+            assert_eq!(rate.rate(), Decimal::new(108, 2));
+            assert_eq!(rate.rounding(), Some(RoundingStrategy::ToZero));
+            assert!(caching.rate(&EUR, &GBP, as_of).is_err());
+        }
+        // one call for the cached rate, and three for the uncached error
+        assert_eq!(*caching.inner.calls.lock().unwrap(), 4);
+
+        // the latest rates aren't cached
+        for _ in 0..3 {
+            caching.rate(&EUR, &USD, None).unwrap();
+        }
+        assert_eq!(*caching.inner.calls.lock().unwrap(), 7);
+
+        caching.clear();
+        caching.rate(&EUR, &USD, as_of).unwrap();
+        assert_eq!(*caching.into_inner().calls.lock().unwrap(), 8);
+    }
+
+    #[test]
+    fn chained() {
+        let mut first = InMemoryRateProvider::new();
+        first.insert(&EUR, &USD, Decimal::new(108, 2), None);
+        let mut second = InMemoryRateProvider::new();
+        second.insert(&EUR, &USD, Decimal::new(200, 2), None);
+        second.insert(&GBP, &USD, Decimal::new(127, 2), None);
+
+        let mut chain = ChainedRateProvider::new(vec![Box::new(first)]);
+        chain.push(Box::new(second));
+
+        assert_eq!(
+            chain.rate(&EUR, &USD, None).unwrap().rate(),
+            Decimal::new(108, 2)
+        );
+        assert_eq!(
+            chain.rate(&GBP, &USD, None).unwrap().rate(),
+            Decimal::new(127, 2)
+        );
+        assert_eq!(
+            chain.rate(&JPY, &USD, None),
+            Err(RateError::NotFound {
+                from: "JPY".to_string(),
+                to: "USD".to_string()
+            })
+        );
+        assert!(ChainedRateProvider::default()
+            .rate(&EUR, &USD, None)
+            .is_err());
+    }
+}
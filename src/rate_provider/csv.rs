@@ -0,0 +1,182 @@
+//! A rate provider that loads rates from a CSV file.
+use std::{fs, path::Path, str::FromStr, time::SystemTime};
+
+use rust_decimal::Decimal;
+
+use super::{parse_date, InMemoryRateProvider, RateError, RateProvider};
+use crate::{currency_map::CurrencyMap, exchange_rate::ExchangeRate, Currency};
+
+/// A rate provider that loads rates from CSV data.
+///
+/// Each line contains a from-currency code, a to-currency code, the rate,
+/// and optionally the `YYYY-MM-DD` date from which the rate is effective:
+///
+/// ```text
+/// from,to,rate,date
+/// EUR,USD,1.0921,2024-01-05
+/// GBP,USD,1.27
+/// ```
+///
+/// The header line is optional. Blank lines and lines starting with `#` are
+/// ignored. All currency codes must be found in the provided [CurrencyMap],
+/// or parsing fails with a [RateError::InvalidData] for the line, and rates
+/// are looked up as described in [InMemoryRateProvider].
+#[derive(Debug, Default, Clone)]
+pub struct CsvRateProvider {
+    table: InMemoryRateProvider,
+}
+
+impl CsvRateProvider {
+    /// Loads rates from the CSV file at the specified path.
+    pub fn open<P: AsRef<Path>>(path: P, currencies: &CurrencyMap) -> Result<Self, RateError> {
+        let contents = fs::read_to_string(path).map_err(|e| RateError::Io(e.to_string()))?;
+        Self::parse(&contents, currencies)
+    }
+
+    /// Loads rates from CSV data that has already been read into a string.
+    pub fn parse(contents: &str, currencies: &CurrencyMap) -> Result<Self, RateError> {
+        let mut table = InMemoryRateProvider::new();
+        for (index, line) in contents.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            if line.is_empty()
+                || line.starts_with('#')
+                || (line_number == 1 && line.to_ascii_lowercase().starts_with("from,"))
+            {
+                continue;
+            }
+
+            let invalid = |message: String| RateError::InvalidData {
+                line: line_number,
+                message,
+            };
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() < 3 || fields.len() > 4 {
+                return Err(invalid(format!(
+                    "expected 3 or 4 fields but found {}",
+                    fields.len()
+                )));
+            }
+
+            let resolve = |code: &str| {
+                currencies
+                    .get(code)
+                    .ok_or_else(|| invalid(format!("unknown currency code {code:?}")))
+            };
+            let from = resolve(fields[0])?;
+            let to = resolve(fields[1])?;
+            let rate = Decimal::from_str(fields[2])
+                .map_err(|_| invalid(format!("invalid rate {:?}", fields[2])))?;
+            let effective = match fields.get(3) {
+                Some(date) if !date.is_empty() => Some(
+                    parse_date(date).ok_or_else(|| invalid(format!("invalid date {date:?}")))?,
+                ),
+                _ => None,
+            };
+            table.insert(from, to, rate, effective);
+        }
+        Ok(Self { table })
+    }
+}
+
+impl RateProvider for CsvRateProvider {
+    fn rate<'c>(
+        &self,
+        from: &'c dyn Currency,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+        self.table.rate(from, to, as_of)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iso_currencies::{EUR, GBP, USD};
+    use crate::rate_provider::csv::*;
+
+    fn currencies() -> CurrencyMap<'static> {
+        CurrencyMap::from_collection([&EUR as &dyn Currency, &GBP, &USD])
+    }
+
+    #[test]
+    fn parse() {
+        let provider = CsvRateProvider::parse(
+            "from,to,rate,date\n\
+             # comment\n\
+             EUR,USD,1.08,2024-01-01\n\
+             \n\
+             EUR, USD, 1.10, 2024-02-01\n\
+             GBP,USD,1.27\n",
+            &currencies(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            provider.rate(&EUR, &USD, None).unwrap().rate(),
+            Decimal::new(110, 2)
+        );
+        assert_eq!(
+            provider
+                .rate(&EUR, &USD, parse_date("2024-01-15"))
+                .unwrap()
+                .rate(),
+            Decimal::new(108, 2)
+        );
+        assert_eq!(
+            provider.rate(&GBP, &USD, None).unwrap().rate(),
+            Decimal::new(127, 2)
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let currencies = currencies();
+        assert_eq!(
+            CsvRateProvider::parse("EUR,USD\n", &currencies).unwrap_err(),
+            RateError::InvalidData {
+                line: 1,
+                message: "expected 3 or 4 fields but found 2".to_string()
+            }
+        );
+        assert_eq!(
+            CsvRateProvider::parse("EUR,USD,1.08\nEUR,XYZ,1.0\n", &currencies).unwrap_err(),
+            RateError::InvalidData {
+                line: 2,
+                message: "unknown currency code \"XYZ\"".to_string()
+            }
+        );
+        assert_eq!(
+            CsvRateProvider::parse("EUR,USD,abc\n", &currencies).unwrap_err(),
+            RateError::InvalidData {
+                line: 1,
+                message: "invalid rate \"abc\"".to_string()
+            }
+        );
+        assert_eq!(
+            CsvRateProvider::parse("EUR,USD,1.08\nEUR,USD,1.08,2024-02-30", &currencies)
+                .unwrap_err(),
+            RateError::InvalidData {
+                line: 2,
+                message: "invalid date \"2024-02-30\"".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn open() {
+        let path = std::env::temp_dir().join("doubloon-csv-rate-provider-test.csv");
+        fs::write(&path, "EUR,USD,1.08\n").unwrap();
+        let provider = CsvRateProvider::open(&path, &currencies()).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            provider.rate(&EUR, &USD, None).unwrap().rate(),
+            Decimal::new(108, 2)
+        );
+
+        assert!(matches!(
+            CsvRateProvider::open(&path, &currencies()),
+            Err(RateError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,219 @@
+//! A rate provider that parses the European Central Bank's
+//! euro foreign exchange reference rates XML format.
+use std::{fs, path::Path, str::FromStr, time::SystemTime};
+
+use rust_decimal::Decimal;
+
+use super::{parse_date, InMemoryRateProvider, RateError, RateProvider};
+use crate::{currency_map::CurrencyMap, exchange_rate::ExchangeRate, Currency};
+
+/// A rate provider that loads rates from the XML files published by the
+/// European Central Bank, such as `eurofxref-daily.xml` or `eurofxref-hist.xml`:
+///
+/// ```xml
+/// <gesmes:Envelope>
+///   <Cube>
+///     <Cube time="2024-01-05">
+///       <Cube currency="USD" rate="1.0921"/>
+///       <Cube currency="JPY" rate="158.08"/>
+///     </Cube>
+///   </Cube>
+/// </gesmes:Envelope>
+/// ```
+///
+/// The rates are all from EUR, so EUR must be in the provided [CurrencyMap].
+/// Each rate is effective from the date of its enclosing `time` element.
+/// Rates for currencies that are not in the map are skipped. Rates from other
+/// currencies into EUR are found by inversion, as described in [InMemoryRateProvider],
+/// but this provider does not compute cross rates between two non-EUR currencies.
+#[derive(Debug, Default, Clone)]
+pub struct EcbRateProvider {
+    table: InMemoryRateProvider,
+}
+
+impl EcbRateProvider {
+    /// Loads rates from the ECB XML file at the specified path.
+    pub fn open<P: AsRef<Path>>(path: P, currencies: &CurrencyMap) -> Result<Self, RateError> {
+        let contents = fs::read_to_string(path).map_err(|e| RateError::Io(e.to_string()))?;
+        Self::parse(&contents, currencies)
+    }
+
+    /// Loads rates from ECB XML that has already been read into a string.
+    pub fn parse(contents: &str, currencies: &CurrencyMap) -> Result<Self, RateError> {
+        let eur = currencies
+            .get("EUR")
+            .ok_or_else(|| RateError::UnknownCurrency("EUR".to_string()))?;
+
+        let mut table = InMemoryRateProvider::new();
+        let mut effective = None;
+        let mut offset = 0;
+        // the line of the current element, counted from the previous one
+        // so that large history files aren't rescanned for every element
+        let mut line = 1;
+        let mut counted = 0;
+        while let Some(start) = contents[offset..].find("<Cube") {
+            let start = offset + start;
+            line += contents[counted..start].matches('\n').count();
+            counted = start;
+            let invalid = |message: String| RateError::InvalidData { line, message };
+            let end = contents[start..]
+                .find('>')
+                .map(|end| start + end)
+                .ok_or_else(|| invalid("unterminated Cube element".to_string()))?;
+            let tag = &contents[start + "<Cube".len()..end];
+            offset = end;
+
+            if let Some(time) = attribute(tag, "time") {
+                effective = Some(
+                    parse_date(time).ok_or_else(|| invalid(format!("invalid time {time:?}")))?,
+                );
+            }
+            if let Some(code) = attribute(tag, "currency") {
+                let rate = attribute(tag, "rate")
+                    .ok_or_else(|| invalid(format!("missing rate for {code}")))?;
+                let rate = Decimal::from_str(rate)
+                    .map_err(|_| invalid(format!("invalid rate {rate:?}")))?;
+                if let Some(currency) = currencies.get(code) {
+                    table.insert(eur, currency, rate, effective);
+                }
+            }
+        }
+        Ok(Self { table })
+    }
+}
+
+/// Returns the value of the named attribute within the text of an XML tag.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let mut rest = tag;
+    while let Some(index) = rest.find(name) {
+        let preceded_by_space = rest[..index]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_whitespace);
+        let after = rest[index + name.len()..].trim_start();
+        rest = &rest[index + name.len()..];
+        if !preceded_by_space {
+            continue;
+        }
+        let Some(after) = after.strip_prefix('=') else {
+            continue;
+        };
+        let after = after.trim_start();
+        let quote = after.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &after[1..];
+        return value.find(quote).map(|end| &value[..end]);
+    }
+    None
+}
+
+impl RateProvider for EcbRateProvider {
+    fn rate<'c>(
+        &self,
+        from: &'c dyn Currency,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+        self.table.rate(from, to, as_of)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iso_currencies::{EUR, GBP, JPY, USD};
+    use crate::rate_provider::ecb::*;
+
+    const HISTORY: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<gesmes:Envelope xmlns:gesmes="http://www.gesmes.org/xml/2002-08-01" xmlns="http://www.ecb.int/vocabulary/2002-08-01/eurofxref">
+	<gesmes:subject>Reference rates</gesmes:subject>
+	<gesmes:Sender>
+		<gesmes:name>European Central Bank</gesmes:name>
+	</gesmes:Sender>
+	<Cube>
+		<Cube time="2024-01-05">
+			<Cube currency="USD" rate="1.0921"/>
+			<Cube currency="JPY" rate="158.08"/>
+			<Cube currency="CHF" rate="0.9283"/>
+		</Cube>
+		<Cube time='2024-01-04'>
+			<Cube currency="USD" rate="1.0953"/>
+			<Cube currency="JPY" rate="158.42"/>
+		</Cube>
+	</Cube>
+</gesmes:Envelope>"#;
+
+    fn currencies() -> CurrencyMap<'static> {
+        CurrencyMap::from_collection([&EUR as &dyn Currency, &JPY, &USD])
+    }
+
+    #[test]
+    fn parse() {
+        let provider = EcbRateProvider::parse(HISTORY, &currencies()).unwrap();
+        assert_eq!(
+            provider.rate(&EUR, &USD, None).unwrap().rate(),
+            Decimal::new(10921, 4)
+        );
+        assert_eq!(
+            provider
+                .rate(&EUR, &JPY, parse_date("2024-01-04"))
+                .unwrap()
+                .rate(),
+            Decimal::new(15842, 2)
+        );
+        assert_eq!(
+            provider.rate(&USD, &EUR, None).unwrap().rate(),
+            Decimal::ONE / Decimal::new(10921, 4)
+        );
+        // no rates before the first date
+        assert!(provider.rate(&EUR, &USD, parse_date("2024-01-03")).is_err());
+        // CHF is not in the currency map, and GBP is not in the file
+        assert!(provider.rate(&EUR, &GBP, None).is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            EcbRateProvider::parse(
+                HISTORY,
+                &CurrencyMap::from_collection([&USD as &dyn Currency])
+            )
+            .unwrap_err(),
+            RateError::UnknownCurrency("EUR".to_string())
+        );
+        assert_eq!(
+            EcbRateProvider::parse(
+                "<Cube>\n<Cube time=\"2024-01-05\">\n<Cube currency=\"USD\" rate=\"x\"/>",
+                &currencies()
+            )
+            .unwrap_err(),
+            RateError::InvalidData {
+                line: 3,
+                message: "invalid rate \"x\"".to_string()
+            }
+        );
+        assert_eq!(
+            EcbRateProvider::parse(&HISTORY.replace("158.42", "x"), &currencies()).unwrap_err(),
+            RateError::InvalidData {
+                line: 15,
+                message: "invalid rate \"x\"".to_string()
+            }
+        );
+        assert_eq!(
+            EcbRateProvider::parse("<Cube time=\"5 Jan\">", &currencies()).unwrap_err(),
+            RateError::InvalidData {
+                line: 1,
+                message: "invalid time \"5 Jan\"".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn attributes() {
+        assert_eq!(
+            attribute(r#" currency="USD" rate="1.09"/"#, "rate"),
+            Some("1.09")
+        );
+        assert_eq!(attribute(r#" currency = 'USD'"#, "currency"), Some("USD"));
+        assert_eq!(attribute(r#" datetime="x""#, "time"), None);
+        assert_eq!(attribute(r#" currency="USD"#, "currency"), None);
+    }
+}
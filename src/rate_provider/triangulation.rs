@@ -0,0 +1,378 @@
+//! Cross rates computed through a pivot currency.
+use std::time::SystemTime;
+
+use rust_decimal::Decimal;
+
+use super::{RateError, RateProvider};
+use crate::{exchange_rate::ExchangeRate, Currency, Money, RoundingStrategy};
+
+/// Controls rounding of the amounts produced by each leg of a conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IntermediateRounding {
+    /// Amounts are not rounded, so a triangulated conversion is the same
+    /// as converting with the product of the two rates.
+    #[default]
+    None,
+    /// The amount produced by each leg is rounded to the minor units
+    /// of that leg's target currency, as some regulations require.
+    PerLeg(RoundingStrategy),
+}
+
+/// The path used to convert between two currencies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversionPath {
+    /// The provider had a rate for the pair of currencies.
+    Direct,
+    /// The conversion went through the pivot currency with this code.
+    Triangulated { pivot: String },
+}
+
+/// The result of converting Money using a [TriangulatingRateProvider].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion<'c> {
+    money: Money<&'c dyn Currency>,
+    path: ConversionPath,
+}
+
+impl<'c> Conversion<'c> {
+    /// Returns the converted money.
+    pub fn money(&self) -> Money<&'c dyn Currency> {
+        self.money
+    }
+
+    /// Returns the path used for the conversion.
+    pub fn path(&self) -> &ConversionPath {
+        &self.path
+    }
+}
+
+/// A rate provider that falls back to computing a cross rate through a
+/// pivot currency when the wrapped provider has no direct rate.
+///
+/// Rate tables usually quote everything against one base currency,
+/// such as EUR for the ECB, so a rate from JPY to GBP can be computed from
+/// the rates from JPY to EUR and EUR to GBP.
+///
+/// ```rust
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use rust_decimal::Decimal;
+/// use doubloon::{
+///     Money, Currency, RoundingStrategy,
+///     iso_currencies::{EUR, GBP, JPY},
+///     rate_provider::{
+///         InMemoryRateProvider, TriangulatingRateProvider,
+///         IntermediateRounding, ConversionPath,
+///     },
+/// };
+///
+/// let mut table = InMemoryRateProvider::new();
+/// table.insert(&EUR, &JPY, 160, None);
+/// table.insert(&EUR, &GBP, Decimal::new(85, 2), None);
+///
+/// let provider = TriangulatingRateProvider::new(table, &EUR)
+///     .with_intermediate_rounding(IntermediateRounding::PerLeg(RoundingStrategy::MidpointNearestEven));
+/// let conversion = provider.convert(Money::new(1000, &JPY as &dyn Currency), &GBP, None)?;
+/// assert_eq!(conversion.money(), Money::new(Decimal::new(531, 2), &GBP as &dyn Currency));
+/// assert_eq!(conversion.path(), &ConversionPath::Triangulated { pivot: "EUR".to_string() });
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TriangulatingRateProvider<'p, P> {
+    inner: P,
+    pivot: &'p dyn Currency,
+    rounding: IntermediateRounding,
+}
+
+impl<'p, P> TriangulatingRateProvider<'p, P>
+where
+    P: RateProvider,
+{
+    /// Constructs a new [TriangulatingRateProvider] that wraps another
+    /// provider and triangulates through the specified pivot currency,
+    /// without any intermediate rounding.
+    pub fn new(inner: P, pivot: &'p dyn Currency) -> Self {
+        Self {
+            inner,
+            pivot,
+            rounding: IntermediateRounding::None,
+        }
+    }
+
+    /// Returns this provider with the specified intermediate rounding,
+    /// which is used by [TriangulatingRateProvider::convert].
+    pub fn with_intermediate_rounding(self, rounding: IntermediateRounding) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Returns the pivot currency.
+    pub fn pivot(&self) -> &'p dyn Currency {
+        self.pivot
+    }
+
+    /// Returns the intermediate rounding used for conversions.
+    pub fn intermediate_rounding(&self) -> IntermediateRounding {
+        self.rounding
+    }
+
+    /// Returns the rates for each leg of the conversion and the path used.
+    fn legs(
+        &self,
+        from: &dyn Currency,
+        to: &dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<(Vec<(Decimal, u32)>, ConversionPath), RateError> {
+        let not_found = match self.inner.rate(from, to, as_of) {
+            Ok(rate) => {
+                return Ok((
+                    vec![(rate.rate(), to.minor_units())],
+                    ConversionPath::Direct,
+                ))
+            }
+            Err(e @ RateError::NotFound { .. }) => e,
+            Err(e) => return Err(e),
+        };
+        let pivot = self.pivot;
+        if from.code() == pivot.code() || to.code() == pivot.code() {
+            return Err(not_found);
+        }
+        // a missing leg means there is no rate for the pair,
+        // but any other error is reported as it is
+        let leg = |from: &dyn Currency, to: &dyn Currency| match self.inner.rate(from, to, as_of) {
+            Ok(rate) => Ok(Some(rate.rate())),
+            Err(RateError::NotFound { .. }) => Ok(None),
+            Err(e) => Err(e),
+        };
+        let (Some(first), Some(second)) = (leg(from, pivot)?, leg(pivot, to)?) else {
+            return Err(not_found);
+        };
+        Ok((
+            vec![(first, pivot.minor_units()), (second, to.minor_units())],
+            ConversionPath::Triangulated {
+                pivot: pivot.code().to_string(),
+            },
+        ))
+    }
+
+    /// Converts money into another currency, triangulating through the pivot
+    /// currency if there is no direct rate, and rounding the amount produced
+    /// by each leg according to the intermediate rounding. Returns
+    /// [RateError::Overflow] if an amount is too large to represent.
+    pub fn convert<'c>(
+        &self,
+        money: Money<&'c dyn Currency>,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<Conversion<'c>, RateError> {
+        let (legs, path) = self.legs(money.currency, to, as_of)?;
+        let amount = legs
+            .into_iter()
+            .try_fold(money.amount, |amount, (rate, minor_units)| {
+                let converted = amount.checked_mul(rate).ok_or(RateError::Overflow)?;
+                Ok(match self.rounding {
+                    IntermediateRounding::None => converted,
+                    IntermediateRounding::PerLeg(strategy) => {
+                        converted.round_dp_with_strategy(minor_units, strategy)
+                    }
+                })
+            })?;
+        Ok(Conversion {
+            money: Money::new(amount, to),
+            path,
+        })
+    }
+}
+
+impl<P> RateProvider for TriangulatingRateProvider<'_, P>
+where
+    P: RateProvider,
+{
+    fn rate<'c>(
+        &self,
+        from: &'c dyn Currency,
+        to: &'c dyn Currency,
+        as_of: Option<SystemTime>,
+    ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+        // triangulated rates are the product of the rates for each leg
+        let (legs, _) = self.legs(from, to, as_of)?;
+        let rate = legs
+            .iter()
+            .try_fold(Decimal::ONE, |product, (rate, _)| {
+                product.checked_mul(*rate)
+            })
+            .ok_or(RateError::Overflow)?;
+        Ok(ExchangeRate::new(from, to, rate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iso_currencies::{CHF, EUR, GBP, JPY, USD};
+    use crate::rate_provider::{triangulation::*, InMemoryRateProvider};
+
+    fn provider() -> TriangulatingRateProvider<'static, InMemoryRateProvider> {
+        let mut table = InMemoryRateProvider::new();
+        table.insert(&EUR, &JPY, 160, None);
+        table.insert(&EUR, &GBP, Decimal::new(85, 2), None);
+        table.insert(&EUR, &USD, Decimal::new(108, 2), None);
+        table.insert(&USD, &GBP, Decimal::new(79, 2), None);
+        TriangulatingRateProvider::new(table, &EUR)
+    }
+
+    #[test]
+    fn direct() {
+        let provider = provider();
+        let conversion = provider
+            .convert(
+                Money::new(Decimal::new(100, 0), &USD as &dyn Currency),
+                &GBP,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            conversion.money(),
+            Money::new(Decimal::new(7900, 2), &GBP as &dyn Currency)
+        );
+        assert_eq!(conversion.path(), &ConversionPath::Direct);
+
+        // inverted by the wrapped provider, but still direct
+        let conversion = provider
+            .convert(
+                Money::new(Decimal::new(160, 0), &JPY as &dyn Currency),
+                &EUR,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            conversion.money(),
+            Money::new(Decimal::ONE, &EUR as &dyn Currency)
+        );
+        assert_eq!(conversion.path(), &ConversionPath::Direct);
+    }
+
+    #[test]
+    fn triangulated() {
+        let provider = provider();
+        let conversion = provider
+            .convert(
+                Money::new(Decimal::new(1000, 0), &JPY as &dyn Currency),
+                &GBP,
+                None,
+            )
+            .unwrap();
+        assert_eq!(conversion.money().currency().code(), "GBP");
+        assert_eq!(
+            conversion.money().amount().round_dp(10),
+            Decimal::new(53125, 4)
+        );
+        assert_eq!(
+            conversion.path(),
+            &ConversionPath::Triangulated {
+                pivot: "EUR".to_string()
+            }
+        );
+        assert_eq!(
+            provider.rate(&JPY, &GBP, None).unwrap().rate().round_dp(10),
+            Decimal::new(53125, 7)
+        );
+    }
+
+    #[test]
+    fn per_leg_rounding() {
+        let provider = provider().with_intermediate_rounding(IntermediateRounding::PerLeg(
+            RoundingStrategy::MidpointNearestEven,
+        ));
+        // 1234 JPY -> 7.7125 EUR, rounded to 7.71 EUR -> 6.5535 GBP, rounded to 6.55 GBP,
+        // while converting without intermediate rounding gives 6.555625 GBP
+        let conversion = provider
+            .convert(
+                Money::new(Decimal::new(1234, 0), &JPY as &dyn Currency),
+                &GBP,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            conversion.money(),
+            Money::new(Decimal::new(655, 2), &GBP as &dyn Currency)
+        );
+        assert_eq!(
+            provider.rate(&JPY, &GBP, None).unwrap().rate().round_dp(10) * Decimal::from(1234),
+            Decimal::new(6555625, 6)
+        );
+
+        // a direct conversion is a single leg, and is rounded too
+        let conversion = provider
+            .convert(
+                Money::new(Decimal::new(333, 2), &USD as &dyn Currency),
+                &GBP,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            conversion.money(),
+            Money::new(Decimal::new(263, 2), &GBP as &dyn Currency)
+        );
+    }
+
+    #[test]
+    fn not_found() {
+        let provider = provider();
+        let not_found = RateError::NotFound {
+            from: "CHF".to_string(),
+            to: "GBP".to_string(),
+        };
+        assert_eq!(
+            provider.convert(Money::new(Decimal::ONE, &CHF as &dyn Currency), &GBP, None),
+            Err(not_found.clone())
+        );
+        assert_eq!(provider.rate(&CHF, &GBP, None), Err(not_found));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut table = InMemoryRateProvider::new();
+        table.insert(&JPY, &EUR, Decimal::MAX, None);
+        table.insert(&EUR, &GBP, Decimal::MAX, None);
+        let provider = TriangulatingRateProvider::new(table, &EUR);
+        assert_eq!(
+            provider.convert(Money::new(Decimal::TWO, &JPY as &dyn Currency), &EUR, None),
+            Err(RateError::Overflow)
+        );
+        assert_eq!(
+            provider.convert(Money::new(Decimal::ONE, &JPY as &dyn Currency), &GBP, None),
+            Err(RateError::Overflow)
+        );
+        assert_eq!(provider.rate(&JPY, &GBP, None), Err(RateError::Overflow));
+    }
+
+    /// Fails to look up any rate from the pivot currency (EUR),
+    /// and looks up all the others in the wrapped provider.
+    struct FailingPivot(InMemoryRateProvider);
+
+    impl RateProvider for FailingPivot {
+        fn rate<'c>(
+            &self,
+            from: &'c dyn Currency,
+            to: &'c dyn Currency,
+            as_of: Option<SystemTime>,
+        ) -> Result<ExchangeRate<&'c dyn Currency, &'c dyn Currency>, RateError> {
+            if from.code() == "EUR" {
+                return Err(RateError::Io("connection reset".to_string()));
+            }
+            self.0.rate(from, to, as_of)
+        }
+    }
+
+    #[test]
+    fn pivot_leg_error() {
+        let mut table = InMemoryRateProvider::new();
+        table.insert(&JPY, &EUR, Decimal::new(625, 5), None);
+        let provider = TriangulatingRateProvider::new(FailingPivot(table), &EUR);
+        let error = RateError::Io("connection reset".to_string());
+        assert_eq!(
+            provider.convert(Money::new(Decimal::ONE, &JPY as &dyn Currency), &GBP, None),
+            Err(error.clone())
+        );
+        assert_eq!(provider.rate(&JPY, &GBP, None), Err(error));
+    }
+}
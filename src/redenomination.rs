@@ -0,0 +1,313 @@
+//! Official redenominations of currencies.
+//!
+//! When a currency is redenominated, a new currency replaces it at a fixed
+//! ratio, such as 100,000 VEF to 1 VES. This module holds a table of those
+//! redenominations, so historical amounts can be migrated without
+//! hardcoding the ratios.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_decimal::Decimal;
+//! use doubloon::{
+//!     Money, Currency,
+//!     iso_currencies::{TRY, VES},
+//!     iso_historic_currencies::{TRL, VEB},
+//!     redenomination,
+//! };
+//!
+//! let old = Money::new(Decimal::new(12_345_678, 0), TRL);
+//! assert_eq!(
+//!     redenomination::redenominate(old.as_dyn())?,
+//!     Money::new(Decimal::new(1235, 2), &TRY as &dyn Currency)
+//! );
+//!
+//! // VEB was redenominated to VEF, which was redenominated to VES
+//! let old = Money::new(Decimal::new(1_000_000_000, 0), VEB);
+//! assert_eq!(
+//!     redenomination::redenominate_to(old.as_dyn(), &VES)?,
+//!     Money::new(Decimal::new(1000, 2), &VES as &dyn Currency)
+//! );
+//! # Ok(())
+//! # }
+//! ```
+use std::time::SystemTime;
+
+use rust_decimal::Decimal;
+use thiserror::Error;
+
+use crate::{
+    iso_currencies, iso_historic_currencies, rate_provider::parse_date, Currency, Money,
+    RoundingStrategy,
+};
+
+/// The redenominations, as the old code, the new code, the number of old
+/// units per new unit given as the mantissa and scale of the Decimal, and
+/// the date from which the new currency was used.
+const REDENOMINATIONS: &[(&str, &str, i64, u32, &str)] = &[
+    ("RUR", "RUB", 1_000, 0, "1998-01-01"),
+    ("AFA", "AFN", 1_000, 0, "2002-10-07"),
+    ("SRG", "SRD", 1_000, 0, "2004-01-01"),
+    ("MGF", "MGA", 5, 0, "2005-01-01"),
+    ("TRL", "TRY", 1_000_000, 0, "2005-01-01"),
+    ("ROL", "RON", 10_000, 0, "2005-07-01"),
+    ("AZM", "AZN", 5_000, 0, "2006-01-01"),
+    ("MZM", "MZN", 1_000, 0, "2006-07-01"),
+    ("ZWD", "ZWN", 1_000, 0, "2006-08-01"),
+    ("SDD", "SDG", 100, 0, "2007-01-10"),
+    ("GHC", "GHS", 10_000, 0, "2007-07-01"),
+    ("VEB", "VEF", 1_000, 0, "2008-01-01"),
+    ("ZWN", "ZWR", 10_000_000_000, 0, "2008-08-01"),
+    ("TMM", "TMT", 5_000, 0, "2009-01-01"),
+    ("ZWR", "ZWL", 1_000_000_000_000, 0, "2009-02-02"),
+    ("BYR", "BYN", 10_000, 0, "2016-07-01"),
+    ("MRO", "MRU", 10, 0, "2018-01-01"),
+    ("STD", "STN", 1_000, 0, "2018-01-01"),
+    ("VEF", "VES", 100_000, 0, "2018-08-20"),
+    ("VES", "VED", 1_000_000, 0, "2021-10-01"),
+    ("SLL", "SLE", 1_000, 0, "2022-07-01"),
+    ("ZWL", "ZWG", 24_987_242, 4, "2024-04-05"),
+];
+
+/// Redenominated amounts are rounded to the minor units of the new
+/// currency, with halfway values rounded away from zero.
+const ROUNDING: RoundingStrategy = RoundingStrategy::MidpointAwayFromZero;
+
+/// Errors that can occur when redenominating Money.
+#[derive(Debug, Error, PartialEq, Clone)]
+pub enum RedenominationError {
+    #[error("{0} has not been redenominated")]
+    NotRedenominated(String),
+    #[error("{from} was not redenominated into {to}")]
+    Unrelated { from: String, to: String },
+    #[error("expected currency {expected} but found {found}")]
+    WrongCurrency { expected: String, found: String },
+}
+
+/// The replacement of one currency by another at a fixed ratio.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Redenomination {
+    from: &'static dyn Currency,
+    to: &'static dyn Currency,
+    ratio: Decimal,
+    effective_on: SystemTime,
+}
+
+impl Redenomination {
+    fn from_row(row: &(&str, &str, i64, u32, &str)) -> Self {
+        let (from, to, mantissa, scale, effective_on) = *row;
+        Self {
+            from: currency(from),
+            to: currency(to),
+            ratio: Decimal::new(mantissa, scale),
+            effective_on: parse_date(effective_on).expect("valid redenomination date"),
+        }
+    }
+
+    /// Returns the currency that was replaced.
+    pub fn from(&self) -> &'static dyn Currency {
+        self.from
+    }
+
+    /// Returns the currency that replaced it.
+    pub fn to(&self) -> &'static dyn Currency {
+        self.to
+    }
+
+    /// Returns the number of units of the old currency per unit of the new one.
+    pub fn ratio(&self) -> Decimal {
+        self.ratio
+    }
+
+    /// Returns the date from which the new currency was used.
+    pub fn effective_on(&self) -> SystemTime {
+        self.effective_on
+    }
+
+    /// Converts money in the old currency to the new one, rounding to the
+    /// minor units of the new currency. Returns
+    /// [RedenominationError::WrongCurrency] if the money is not
+    /// in the old currency.
+    pub fn convert(
+        &self,
+        money: Money<&dyn Currency>,
+    ) -> Result<Money<&'static dyn Currency>, RedenominationError> {
+        if money.currency.code() != self.from.code() {
+            return Err(RedenominationError::WrongCurrency {
+                expected: self.from.code().to_string(),
+                found: money.currency.code().to_string(),
+            });
+        }
+        Ok(Money::new(
+            (money.amount / self.ratio).round_dp_with_strategy(self.to.minor_units(), ROUNDING),
+            self.to,
+        ))
+    }
+}
+
+/// Returns the currency with the specified code, whether current or historic.
+fn currency(code: &str) -> &'static dyn Currency {
+    iso_currencies::CURRENCY_MAP
+        .get(code)
+        .or_else(|| iso_historic_currencies::CURRENCY_MAP.get(code))
+        .expect("redenominated currencies are defined")
+}
+
+/// Returns an iterator over all the redenominations, ordered by the date
+/// from which they were effective.
+pub fn redenominations() -> impl Iterator<Item = Redenomination> {
+    REDENOMINATIONS.iter().map(Redenomination::from_row)
+}
+
+/// Returns the redenomination that replaced the specified currency,
+/// or None if it has not been redenominated.
+pub fn find(from: &dyn Currency) -> Option<Redenomination> {
+    REDENOMINATIONS
+        .iter()
+        .find(|row| row.0 == from.code())
+        .map(Redenomination::from_row)
+}
+
+/// Converts money into the currency that replaced its currency.
+pub fn redenominate(
+    money: Money<&dyn Currency>,
+) -> Result<Money<&'static dyn Currency>, RedenominationError> {
+    let redenomination = find(money.currency)
+        .ok_or_else(|| RedenominationError::NotRedenominated(money.currency.code().to_string()))?;
+    Ok(redenomination
+        .convert(money)
+        .expect("redenomination is from the money's currency"))
+}
+
+/// Converts money into the specified currency by following successive
+/// redenominations, such as VEB to VEF to VES. The ratios are combined and
+/// the amount is only rounded once, to the minor units of the target currency.
+pub fn redenominate_to<'c>(
+    money: Money<&dyn Currency>,
+    to: &'c dyn Currency,
+) -> Result<Money<&'c dyn Currency>, RedenominationError> {
+    let unrelated = || RedenominationError::Unrelated {
+        from: money.currency.code().to_string(),
+        to: to.code().to_string(),
+    };
+    let mut code = money.currency.code();
+    let mut ratio = Decimal::ONE;
+    while code != to.code() {
+        let row = REDENOMINATIONS
+            .iter()
+            .find(|row| row.0 == code)
+            .ok_or_else(unrelated)?;
+        ratio *= Decimal::new(row.2, row.3);
+        code = row.1;
+    }
+    Ok(Money::new(
+        (money.amount / ratio).round_dp_with_strategy(to.minor_units(), ROUNDING),
+        to,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iso_currencies::{RON, USD, VED, VES, ZWG};
+    use crate::iso_historic_currencies::{ROL, TRL, VEB, VEF, ZWD, ZWL};
+    use crate::redenomination::*;
+
+    #[test]
+    fn table() {
+        let all: Vec<Redenomination> = redenominations().collect();
+        assert_eq!(all.len(), REDENOMINATIONS.len());
+        assert!(all
+            .windows(2)
+            .all(|w| w[0].effective_on() <= w[1].effective_on()));
+        for redenomination in &all {
+            assert!(!redenomination
+                .to()
+                .is_withdrawn_by(redenomination.effective_on()));
+        }
+
+        let vef = find(&VEF).unwrap();
+        assert_eq!(vef.from().code(), "VEF");
+        assert_eq!(vef.to().code(), "VES");
+        assert_eq!(vef.ratio(), Decimal::new(100_000, 0));
+        assert_eq!(vef.effective_on(), parse_date("2018-08-20").unwrap());
+        assert_eq!(find(&USD), None);
+    }
+
+    #[test]
+    fn convert() {
+        let vef = find(&VEF).unwrap();
+        assert_eq!(
+            vef.convert(Money::new(
+                Decimal::new(123_456_789, 2),
+                &VEF as &dyn Currency
+            )),
+            Ok(Money::new(Decimal::new(1235, 2), &VES as &dyn Currency))
+        );
+        assert_eq!(
+            vef.convert(Money::new(Decimal::ONE, &VES as &dyn Currency)),
+            Err(RedenominationError::WrongCurrency {
+                expected: "VEF".to_string(),
+                found: "VES".to_string()
+            })
+        );
+
+        assert_eq!(
+            redenominate(Money::new(Decimal::new(15_000, 0), &ROL as &dyn Currency)),
+            Ok(Money::new(Decimal::new(150, 2), &RON as &dyn Currency))
+        );
+        assert_eq!(
+            redenominate(Money::new(Decimal::new(5, 0), &TRL as &dyn Currency)),
+            Ok(Money::new(
+                Decimal::ZERO,
+                &crate::iso_currencies::TRY as &dyn Currency
+            ))
+        );
+        assert_eq!(
+            redenominate(Money::new(Decimal::new(10_000, 0), &ZWL as &dyn Currency)),
+            Ok(Money::new(Decimal::new(400, 2), &ZWG as &dyn Currency))
+        );
+        assert_eq!(
+            redenominate(Money::new(Decimal::ONE, &USD as &dyn Currency)),
+            Err(RedenominationError::NotRedenominated("USD".to_string()))
+        );
+    }
+
+    #[test]
+    fn successive() {
+        assert_eq!(
+            redenominate_to(
+                Money::new(Decimal::new(1_000_000_000, 0), &VEB as &dyn Currency),
+                &VES
+            ),
+            Ok(Money::new(Decimal::new(1000, 2), &VES as &dyn Currency))
+        );
+        assert_eq!(
+            redenominate_to(
+                Money::new(Decimal::new(1_000_000_000, 0), &VEB as &dyn Currency),
+                &VED
+            ),
+            Ok(Money::new(Decimal::ZERO, &VED as &dyn Currency))
+        );
+        assert_eq!(
+            redenominate_to(Money::new(Decimal::new(1, 0), &VEF as &dyn Currency), &VEF),
+            Ok(Money::new(Decimal::ONE, &VEF as &dyn Currency))
+        );
+        // ZWD -> ZWN -> ZWR -> ZWL is a factor of 10^25
+        assert_eq!(
+            redenominate_to(
+                Money::new(
+                    Decimal::from_i128_with_scale(10i128.pow(25), 0),
+                    &ZWD as &dyn Currency
+                ),
+                &ZWL
+            ),
+            Ok(Money::new(Decimal::ONE, &ZWL as &dyn Currency))
+        );
+        assert_eq!(
+            redenominate_to(Money::new(Decimal::ONE, &VES as &dyn Currency), &VEF),
+            Err(RedenominationError::Unrelated {
+                from: "VES".to_string(),
+                to: "VEF".to_string()
+            })
+        );
+    }
+}
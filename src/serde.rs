@@ -0,0 +1,788 @@
+//! Serde support for [Money], enabled via the "serde" crate feature.
+//!
+//! Money instances are serialized as a struct with two fields: the
+//! amount as a string (to preserve precision), and the currency code.
+//! For example, `Money::new(Decimal::ONE, USD)` serializes to JSON as
+//! `{"amount":"1","currency":"USD"}`.
+//!
+//! Money instances with statically-typed currencies can be deserialized
+//! directly, provided the currency type implements `Default` (all the
+//! structs in [iso_currencies](crate::iso_currencies) do). The currency
+//! code in the serialized data must match the expected currency, or
+//! deserialization will fail with a descriptive error.
+//!
+//! Money instances with dynamically-typed currencies need some way to
+//! resolve the serialized currency code back into a `&dyn Currency`,
+//! so they are deserialized using a [MoneySeed], which looks up the
+//! code in a [CurrencyMap]:
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use serde::de::DeserializeSeed;
+//! use doubloon::{
+//!     {Money, Currency},
+//!     iso_currencies::{USD, JPY},
+//!     currency_map::CurrencyMap,
+//!     serde::MoneySeed,
+//! };
+//!
+//! let currencies = CurrencyMap::from_collection(vec![&USD as &dyn Currency, &JPY]);
+//! let mut deserializer = serde_json::Deserializer::from_str(
+//!     r#"{"amount":"100","currency":"JPY"}"#
+//! );
+//! let m = MoneySeed::new(&currencies).deserialize(&mut deserializer)?;
+//! assert_eq!(m, Money::new(100, JPY));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Other Representations
+//!
+//! APIs often use other wire shapes for monetary amounts, so this module
+//! also provides submodules for use with serde's `with` field attribute:
+//!
+//! - [minor_units] writes `{"amount":1234,"currency":"USD"}`, where the
+//!   amount is an integer number of currency minor units (like Stripe).
+//! - [number] writes `{"amount":12.34,"currency":"USD"}`, where the amount
+//!   is a number rather than a string. Beware that many JSON parsers read
+//!   numbers as binary floating point values, which may lose precision.
+//! - [string] writes `"12.34 USD"`, the same as the
+//!   [Display](std::fmt::Display) output.
+//! - [tuple] writes `["12.34","USD"]`.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use rust_decimal::Decimal;
+//! use serde::{Deserialize, Serialize};
+//! use doubloon::{Money, iso_currencies::USD};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Charge {
+//!     #[serde(with = "doubloon::serde::minor_units")]
+//!     total: Money<USD>,
+//! }
+//!
+//! let charge = Charge { total: Money::new(Decimal::new(1234, 2), USD) };
+//! let json = serde_json::to_string(&charge)?;
+//! assert_eq!(json, r#"{"total":{"amount":1234,"currency":"USD"}}"#);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Each submodule's `serialize` function works with both statically and
+//! dynamically-typed currencies, but its `deserialize` function only works
+//! with statically-typed currencies. For dynamically-typed currencies,
+//! use `serialize_with` and deserialize using the submodule's `seed`
+//! function, which returns a [MoneySeed] for that representation.
+use std::{fmt, str::FromStr};
+
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{
+    de::{self, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeStruct, SerializeTuple},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    currency_map::CurrencyMap,
+    iso_currencies::IsoCurrency,
+    parsing::{parse_parts, FromCurrencyCode},
+    AsCurrency, Currency, MinorUnits, Money,
+};
+
+const FIELDS: &[&str] = &["amount", "currency"];
+
+impl<C> Serialize for Money<C>
+where
+    C: Currency,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Representation::Struct.serialize(self, serializer)
+    }
+}
+
+impl Serialize for Money<&dyn Currency> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Representation::Struct.serialize(self, serializer)
+    }
+}
+
+/// Supports deserializing Money instances with a statically-typed currency
+/// or an [IsoCurrency]. The serialized currency code must match the code
+/// of `C`, or for an [IsoCurrency], be the code of any ISO currency.
+impl<'de, C> Deserialize<'de> for Money<C>
+where
+    C: FromCurrencyCode + MinorUnits + Copy,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Representation::Struct.deserialize_static(deserializer)
+    }
+}
+
+/// Serializes an [IsoCurrency] as its alphabetic code.
+impl Serialize for IsoCurrency {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.code())
+    }
+}
+
+/// Deserializes an [IsoCurrency] from its alphabetic code.
+impl<'de> Deserialize<'de> for IsoCurrency {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+        IsoCurrency::from_code(&code)
+            .ok_or_else(|| de::Error::custom(format!("unknown currency code {code:?}")))
+    }
+}
+
+/// Deserializes a `Money<&dyn Currency>`, resolving the serialized
+/// currency code using a [CurrencyMap]. Deserialization fails if the
+/// code is not in the map.
+#[derive(Debug, Clone, Copy)]
+pub struct MoneySeed<'a, 'c> {
+    currencies: &'a CurrencyMap<'c>,
+    representation: Representation,
+}
+
+impl<'a, 'c> MoneySeed<'a, 'c> {
+    /// Constructs a new [MoneySeed] that will resolve currency codes
+    /// using the provided [CurrencyMap].
+    pub fn new(currencies: &'a CurrencyMap<'c>) -> Self {
+        Self {
+            currencies,
+            representation: Representation::Struct,
+        }
+    }
+}
+
+impl<'de, 'c> DeserializeSeed<'de> for MoneySeed<'_, 'c> {
+    type Value = Money<&'c dyn Currency>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = self.representation.deserialize(deserializer)?;
+        let currency = resolve_currency(self.currencies, &raw.currency)?;
+        Ok(raw.amount.into_money(currency))
+    }
+}
+
+/// Looks up a currency code in the [CurrencyMap], producing a
+/// descriptive error if the code is unknown.
+pub(crate) fn resolve_currency<'c, E: de::Error>(
+    currencies: &CurrencyMap<'c>,
+    code: &str,
+) -> Result<&'c dyn Currency, E> {
+    currencies
+        .get(code)
+        .ok_or_else(|| E::custom(format!("unknown currency code {code:?}")))
+}
+
+/// The supported wire representations of a Money.
+#[derive(Debug, Clone, Copy)]
+enum Representation {
+    /// `{"amount":"12.34","currency":"USD"}`
+    Struct,
+    /// `{"amount":1234,"currency":"USD"}`
+    MinorUnits,
+    /// `{"amount":12.34,"currency":"USD"}`
+    Number,
+    /// `"12.34 USD"`
+    String,
+    /// `["12.34","USD"]`
+    Tuple,
+}
+
+impl Representation {
+    fn serialize<C, S>(self, money: &Money<C>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        C: AsCurrency,
+        S: Serializer,
+    {
+        let amount = money.amount;
+        let code = money.currency.as_currency().code();
+        let minor_units = money.currency.as_currency().minor_units();
+        match self {
+            Representation::Struct => {
+                let mut struct_serializer = serializer.serialize_struct("money", 2)?;
+                struct_serializer.serialize_field("amount", &amount.to_string())?;
+                struct_serializer.serialize_field("currency", code)?;
+                struct_serializer.end()
+            }
+            Representation::MinorUnits => {
+                let rounded = amount.round_dp(minor_units);
+                if rounded != amount {
+                    return Err(serde::ser::Error::custom(format!(
+                        "amount {amount} has more precision than the {minor_units} minor units of {code}"
+                    )));
+                }
+                let minor_amount = (rounded * Decimal::from(10_u64.pow(minor_units)))
+                    .to_i64()
+                    .ok_or_else(|| {
+                        serde::ser::Error::custom(format!(
+                            "amount {amount} is too large to represent in minor units"
+                        ))
+                    })?;
+                let mut struct_serializer = serializer.serialize_struct("money", 2)?;
+                struct_serializer.serialize_field("amount", &minor_amount)?;
+                struct_serializer.serialize_field("currency", code)?;
+                struct_serializer.end()
+            }
+            Representation::Number => {
+                let number = amount.to_f64().ok_or_else(|| {
+                    serde::ser::Error::custom(format!(
+                        "amount {amount} can't be represented as a number"
+                    ))
+                })?;
+                let mut struct_serializer = serializer.serialize_struct("money", 2)?;
+                struct_serializer.serialize_field("amount", &number)?;
+                struct_serializer.serialize_field("currency", code)?;
+                struct_serializer.end()
+            }
+            Representation::String => serializer.collect_str(&format_args!("{amount} {code}")),
+            Representation::Tuple => {
+                let mut tuple_serializer = serializer.serialize_tuple(2)?;
+                tuple_serializer.serialize_element(&amount.to_string())?;
+                tuple_serializer.serialize_element(code)?;
+                tuple_serializer.end()
+            }
+        }
+    }
+
+    fn deserialize<'de, D>(self, deserializer: D) -> Result<RawMoney, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match self {
+            Representation::Struct => deserializer.deserialize_struct(
+                "money",
+                FIELDS,
+                RawMoneyVisitor::<StringAmount>::new(),
+            ),
+            Representation::MinorUnits => deserializer.deserialize_struct(
+                "money",
+                FIELDS,
+                RawMoneyVisitor::<MinorUnitsAmount>::new(),
+            ),
+            Representation::Number => deserializer.deserialize_struct(
+                "money",
+                FIELDS,
+                RawMoneyVisitor::<NumberAmount>::new(),
+            ),
+            Representation::String => deserializer.deserialize_str(StringMoneyVisitor),
+            Representation::Tuple => {
+                deserializer.deserialize_tuple(2, RawMoneyVisitor::<StringAmount>::new())
+            }
+        }
+    }
+
+    fn deserialize_static<'de, D, C>(self, deserializer: D) -> Result<Money<C>, D::Error>
+    where
+        D: Deserializer<'de>,
+        C: FromCurrencyCode + MinorUnits + Copy,
+    {
+        let raw = self.deserialize(deserializer)?;
+        let currency = C::from_currency_code(&raw.currency).map_err(de::Error::custom)?;
+        Ok(raw.amount.into_money(currency))
+    }
+}
+
+macro_rules! representation_module {
+    ($module:ident, $representation:ident, $example:literal) => {
+        #[doc = "Serializes Money as `"]
+        #[doc = $example]
+        #[doc = "`. For use with serde's `with`, `serialize_with`,"]
+        #[doc = " or `deserialize_with` field attributes."]
+        pub mod $module {
+            use serde::{Deserializer, Serializer};
+
+            use super::{MoneySeed, Representation};
+            use crate::{
+                currency_map::CurrencyMap, parsing::FromCurrencyCode, AsCurrency, MinorUnits, Money,
+            };
+
+            /// Serializes a Money with either a statically or dynamically-typed currency.
+            pub fn serialize<C, S>(money: &Money<C>, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                C: AsCurrency,
+                S: Serializer,
+            {
+                Representation::$representation.serialize(money, serializer)
+            }
+
+            /// Deserializes a Money with a statically-typed currency or an
+            /// [IsoCurrency](crate::iso_currencies::IsoCurrency). The serialized
+            /// currency code must match the code of `C`, or for an IsoCurrency,
+            /// be the code of any ISO currency.
+            pub fn deserialize<'de, D, C>(deserializer: D) -> Result<Money<C>, D::Error>
+            where
+                D: Deserializer<'de>,
+                C: FromCurrencyCode + MinorUnits + Copy,
+            {
+                Representation::$representation.deserialize_static(deserializer)
+            }
+
+            /// Returns a [MoneySeed] for deserializing a Money with a dynamically-typed
+            /// currency from this representation.
+            pub fn seed<'a, 'c>(currencies: &'a CurrencyMap<'c>) -> MoneySeed<'a, 'c> {
+                MoneySeed {
+                    currencies,
+                    representation: Representation::$representation,
+                }
+            }
+        }
+    };
+}
+
+representation_module!(
+    minor_units,
+    MinorUnits,
+    r#"{"amount":1234,"currency":"USD"}"#
+);
+representation_module!(number, Number, r#"{"amount":12.34,"currency":"USD"}"#);
+representation_module!(string, String, r#""12.34 USD""#);
+representation_module!(tuple, Tuple, r#"["12.34","USD"]"#);
+
+/// A serialized amount, before the currency has been resolved.
+#[derive(Debug)]
+pub(crate) enum RawAmount {
+    Decimal(Decimal),
+    MinorUnits(i64),
+}
+
+impl RawAmount {
+    fn into_money<C: MinorUnits + Copy>(self, currency: C) -> Money<C> {
+        match self {
+            RawAmount::Decimal(amount) => Money::new(amount, currency),
+            RawAmount::MinorUnits(minor_units) => Money::from_minor_units(minor_units, currency),
+        }
+    }
+}
+
+/// The serialized parts of a Money, before the currency code
+/// has been resolved to a [Currency].
+#[derive(Debug)]
+pub(crate) struct RawMoney {
+    pub(crate) amount: RawAmount,
+    pub(crate) currency: String,
+}
+
+/// Parses an amount string, producing a descriptive error if it's not a valid decimal.
+fn parse_amount<E: de::Error>(amount: &str) -> Result<Decimal, E> {
+    Decimal::from_str(amount)
+        .map_err(|e| E::custom(format!("invalid money amount {amount:?}: {e}")))
+}
+
+/// An amount serialized as a decimal string.
+struct StringAmount(Decimal);
+
+impl<'de> Deserialize<'de> for StringAmount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let amount = String::deserialize(deserializer)?;
+        Ok(StringAmount(parse_amount(&amount)?))
+    }
+}
+
+impl From<StringAmount> for RawAmount {
+    fn from(value: StringAmount) -> Self {
+        RawAmount::Decimal(value.0)
+    }
+}
+
+/// An amount serialized as an integer number of minor units.
+struct MinorUnitsAmount(i64);
+
+impl<'de> Deserialize<'de> for MinorUnitsAmount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(MinorUnitsAmount(i64::deserialize(deserializer)?))
+    }
+}
+
+impl From<MinorUnitsAmount> for RawAmount {
+    fn from(value: MinorUnitsAmount) -> Self {
+        RawAmount::MinorUnits(value.0)
+    }
+}
+
+/// An amount serialized as a number.
+struct NumberAmount(Decimal);
+
+impl<'de> Deserialize<'de> for NumberAmount {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(NumberAmountVisitor)
+    }
+}
+
+impl From<NumberAmount> for RawAmount {
+    fn from(value: NumberAmount) -> Self {
+        RawAmount::Decimal(value.0)
+    }
+}
+
+struct NumberAmountVisitor;
+
+impl Visitor<'_> for NumberAmountVisitor {
+    type Value = NumberAmount;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a numeric money amount")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(NumberAmount(Decimal::from(v)))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(NumberAmount(Decimal::from(v)))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        // The Display output of an f64 is the shortest string that
+        // round-trips, so 12.34 becomes 12.34 rather than the exact
+        // binary value of 12.339999999999999857891452847979962825775146484375.
+        Ok(NumberAmount(parse_amount(&v.to_string())?))
+    }
+}
+
+/// Visits a struct or sequence with an amount and a currency code,
+/// where the amount is deserialized as an `A`.
+struct RawMoneyVisitor<A> {
+    amount: std::marker::PhantomData<A>,
+}
+
+impl<A> RawMoneyVisitor<A> {
+    fn new() -> Self {
+        Self {
+            amount: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'de, A> Visitor<'de> for RawMoneyVisitor<A>
+where
+    A: Deserialize<'de> + Into<RawAmount>,
+{
+    type Value = RawMoney;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a money struct with amount and currency fields")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let amount: A = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let currency: String = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(RawMoney {
+            amount: amount.into(),
+            currency,
+        })
+    }
+
+    fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+    where
+        M: MapAccess<'de>,
+    {
+        let mut amount: Option<A> = None;
+        let mut currency: Option<String> = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "amount" => {
+                    if amount.is_some() {
+                        return Err(de::Error::duplicate_field("amount"));
+                    }
+                    amount = Some(map.next_value()?);
+                }
+                "currency" => {
+                    if currency.is_some() {
+                        return Err(de::Error::duplicate_field("currency"));
+                    }
+                    currency = Some(map.next_value()?);
+                }
+                _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+            }
+        }
+        let amount = amount.ok_or_else(|| de::Error::missing_field("amount"))?;
+        let currency = currency.ok_or_else(|| de::Error::missing_field("currency"))?;
+        Ok(RawMoney {
+            amount: amount.into(),
+            currency,
+        })
+    }
+}
+
+/// Visits a string containing an amount and a currency code,
+/// in any form supported by [parsing](crate::parsing).
+struct StringMoneyVisitor;
+
+impl Visitor<'_> for StringMoneyVisitor {
+    type Value = RawMoney;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a string containing an amount and currency code")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        let (amount, currency) = parse_parts(v).map_err(E::custom)?;
+        Ok(RawMoney {
+            amount: RawAmount::Decimal(amount),
+            currency: currency.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::currency_map::CurrencyMap;
+    use crate::iso_currencies::{IsoCurrency, JPY, USD};
+    use crate::serde::{minor_units, number, string, tuple, MoneySeed};
+    use crate::{Currency, Money};
+    use rust_decimal::Decimal;
+    use serde::{de::DeserializeSeed, Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Representations {
+        #[serde(with = "minor_units")]
+        minor_units: Money<USD>,
+        #[serde(with = "number")]
+        number: Money<USD>,
+        #[serde(with = "string")]
+        string: Money<JPY>,
+        #[serde(with = "tuple")]
+        tuple: Money<USD>,
+    }
+
+    fn deserialize_dyn<'c>(
+        currencies: &CurrencyMap<'c>,
+        json: &str,
+    ) -> Result<Money<&'c dyn Currency>, serde_json::Error> {
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        MoneySeed::new(currencies).deserialize(&mut deserializer)
+    }
+
+    #[test]
+    fn deserialize_static() {
+        let m: Money<USD> =
+            serde_json::from_str("{\"amount\":\"12.34\",\"currency\":\"USD\"}").unwrap();
+        assert_eq!(m, Money::new(Decimal::new(1234, 2), USD));
+
+        // field order shouldn't matter
+        let m: Money<JPY> =
+            serde_json::from_str("{\"currency\":\"JPY\",\"amount\":\"100\"}").unwrap();
+        assert_eq!(m, Money::new(100, JPY));
+    }
+
+    #[test]
+    fn deserialize_static_round_trip() {
+        let m = Money::new(Decimal::new(-123456789, 4), USD);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(serde_json::from_str::<Money<USD>>(&json).unwrap(), m);
+    }
+
+    #[test]
+    fn deserialize_static_mismatched_currency() {
+        let err = serde_json::from_str::<Money<USD>>("{\"amount\":\"1\",\"currency\":\"JPY\"}")
+            .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected currency USD but found JPY"));
+    }
+
+    #[test]
+    fn deserialize_invalid_amount() {
+        let err = serde_json::from_str::<Money<USD>>("{\"amount\":\"abc\",\"currency\":\"USD\"}")
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid money amount \"abc\""));
+    }
+
+    #[test]
+    fn deserialize_missing_field() {
+        let err = serde_json::from_str::<Money<USD>>("{\"amount\":\"1\"}").unwrap_err();
+        assert!(err.to_string().contains("missing field `currency`"));
+    }
+
+    #[test]
+    fn deserialize_dynamic() {
+        let currencies = CurrencyMap::from_collection([&USD as &dyn Currency, &JPY]);
+        let m =
+            deserialize_dyn(&currencies, "{\"amount\":\"12.34\",\"currency\":\"USD\"}").unwrap();
+        assert_eq!(m, Money::new(Decimal::new(1234, 2), USD));
+        assert_eq!(m.currency().code(), "USD");
+
+        let m = deserialize_dyn(&currencies, "{\"amount\":\"100\",\"currency\":\"JPY\"}").unwrap();
+        assert_eq!(m, Money::new(100, JPY));
+    }
+
+    #[test]
+    fn deserialize_dynamic_round_trip() {
+        let currencies = CurrencyMap::from_collection([&USD as &dyn Currency, &JPY]);
+        let m = Money::new(Decimal::new(-1050, 2), currencies.get("USD").unwrap());
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(deserialize_dyn(&currencies, &json).unwrap(), m);
+    }
+
+    #[test]
+    fn deserialize_dynamic_unknown_currency() {
+        let currencies = CurrencyMap::from_collection([&USD as &dyn Currency]);
+        let err =
+            deserialize_dyn(&currencies, "{\"amount\":\"1\",\"currency\":\"JPY\"}").unwrap_err();
+        assert!(err.to_string().contains("unknown currency code \"JPY\""));
+    }
+
+    #[test]
+    fn representations_static() {
+        let r = Representations {
+            minor_units: Money::new(Decimal::new(1234, 2), USD),
+            number: Money::new(Decimal::new(1234, 2), USD),
+            string: Money::new(-100, JPY),
+            tuple: Money::new(Decimal::new(1234, 2), USD),
+        };
+        let json = serde_json::to_string(&r).unwrap();
+        assert_eq!(
+            json,
+            "{\"minor_units\":{\"amount\":1234,\"currency\":\"USD\"},\
+            \"number\":{\"amount\":12.34,\"currency\":\"USD\"},\
+            \"string\":\"-100 JPY\",\
+            \"tuple\":[\"12.34\",\"USD\"]}"
+        );
+        assert_eq!(serde_json::from_str::<Representations>(&json).unwrap(), r);
+    }
+
+    #[test]
+    fn representations_static_mismatched_currency() {
+        let err = serde_json::from_str::<Representations>(
+            "{\"minor_units\":{\"amount\":1234,\"currency\":\"USD\"},\
+            \"number\":{\"amount\":12.34,\"currency\":\"USD\"},\
+            \"string\":\"100 USD\",\
+            \"tuple\":[\"12.34\",\"USD\"]}",
+        )
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("expected currency JPY but found USD"));
+    }
+
+    #[test]
+    fn representations_dynamic() {
+        #[derive(Serialize)]
+        struct Dynamic<'c> {
+            #[serde(serialize_with = "minor_units::serialize")]
+            amount: Money<&'c dyn Currency>,
+        }
+
+        let currencies = CurrencyMap::from_collection([&USD as &dyn Currency, &JPY]);
+        let m = Money::new(Decimal::new(1234, 2), currencies.get("USD").unwrap());
+        let json = serde_json::to_string(&Dynamic { amount: m }).unwrap();
+        assert_eq!(json, "{\"amount\":{\"amount\":1234,\"currency\":\"USD\"}}");
+
+        let cases = [
+            (
+                minor_units::seed(&currencies),
+                "{\"amount\":1234,\"currency\":\"USD\"}",
+            ),
+            (
+                number::seed(&currencies),
+                "{\"amount\":12.34,\"currency\":\"USD\"}",
+            ),
+            (string::seed(&currencies), "\"12.34 USD\""),
+            (tuple::seed(&currencies), "[\"12.34\",\"USD\"]"),
+        ];
+        for (seed, json) in cases {
+            let mut deserializer = serde_json::Deserializer::from_str(json);
+            assert_eq!(seed.deserialize(&mut deserializer).unwrap(), m);
+        }
+    }
+
+    #[test]
+    fn minor_units_uses_currency_minor_units() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Yen(#[serde(with = "minor_units")] Money<JPY>);
+
+        let json = serde_json::to_string(&Yen(Money::new(1234, JPY))).unwrap();
+        assert_eq!(json, "{\"amount\":1234,\"currency\":\"JPY\"}");
+        assert_eq!(
+            serde_json::from_str::<Yen>(&json).unwrap(),
+            Yen(Money::new(1234, JPY))
+        );
+    }
+
+    #[test]
+    fn minor_units_rejects_excess_precision() {
+        #[derive(Serialize)]
+        struct Dollars(#[serde(with = "minor_units")] Money<USD>);
+
+        let err =
+            serde_json::to_string(&Dollars(Money::new(Decimal::new(12345, 3), USD))).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("more precision than the 2 minor units"));
+    }
+
+    #[test]
+    fn string_rejects_invalid_format() {
+        let currencies = CurrencyMap::from_collection([&USD as &dyn Currency]);
+        for json in ["\"12.34\"", "\"12.34 USD extra\"", "\"abc USD\""] {
+            let mut deserializer = serde_json::Deserializer::from_str(json);
+            assert!(string::seed(&currencies)
+                .deserialize(&mut deserializer)
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn iso_currency() {
+        let m = Money::new(Decimal::new(1234, 2), IsoCurrency::JPY);
+        let json = serde_json::to_string(&m).unwrap();
+        assert_eq!(json, r#"{"amount":"12.34","currency":"JPY"}"#);
+        assert_eq!(
+            serde_json::from_str::<Money<IsoCurrency>>(&json).unwrap(),
+            m
+        );
+        assert_eq!(
+            serde_json::from_str::<Money<IsoCurrency>>(r#"{"amount":"1","currency":"XYZ"}"#)
+                .unwrap_err()
+                .to_string(),
+            r#"unknown currency code "XYZ""#
+        );
+
+        assert_eq!(
+            serde_json::to_string(&IsoCurrency::USD).unwrap(),
+            r#""USD""#
+        );
+        assert_eq!(
+            serde_json::from_str::<IsoCurrency>(r#""USD""#).unwrap(),
+            IsoCurrency::USD
+        );
+        assert!(serde_json::from_str::<IsoCurrency>(r#""usd""#).is_err());
+    }
+}
@@ -0,0 +1,356 @@
+//! Maps the entity names used in the ISO 4217 list to ISO 3166 alpha-2 codes.
+//!
+//! The list only gives the names of the entities that use each currency, in
+//! the uppercase form used by ISO 3166, such as "BAHAMAS (THE)". Names are
+//! compared after [normalize], so minor differences in punctuation don't
+//! matter. Entities that are missing from these tables are reported as
+//! warnings by the generator.
+
+/// Entities that use a currency but aren't countries.
+const SUPRANATIONAL: &[&str] = &[
+    "ARAB MONETARY FUND",
+    "EUROPEAN MONETARY CO-OPERATION FUND (EMCF)",
+    "EUROPEAN UNION",
+    "INTERNATIONAL MONETARY FUND (IMF)",
+    "MEMBER COUNTRIES OF THE AFRICAN DEVELOPMENT BANK GROUP",
+    "SISTEMA UNITARIO DE COMPENSACION REGIONAL DE PAGOS \"SUCRE\"",
+];
+
+/// Countries that no longer exist, which only appear in list three, with
+/// the alpha-2 codes they had before ISO 3166 withdrew them. CS was used
+/// twice, for Czechoslovakia and later for Serbia and Montenegro.
+const FORMER_COUNTRIES: &[(&str, &str)] = &[
+    ("BURMA", "BU"),
+    ("CZECHOSLOVAKIA", "CS"),
+    ("GERMAN DEMOCRATIC REPUBLIC", "DD"),
+    ("NETHERLANDS ANTILLES", "AN"),
+    ("SERBIA AND MONTENEGRO", "CS"),
+    ("SOUTHERN RHODESIA", "RH"),
+    ("UNION OF SOVIET SOCIALIST REPUBLICS", "SU"),
+    ("YEMEN, DEMOCRATIC", "YD"),
+    ("YUGOSLAVIA", "YU"),
+    ("ZAIRE", "ZR"),
+];
+
+const COUNTRIES: &[(&str, &str)] = &[
+    ("AFGHANISTAN", "AF"),
+    ("ÅLAND ISLANDS", "AX"),
+    ("ALBANIA", "AL"),
+    ("ALGERIA", "DZ"),
+    ("AMERICAN SAMOA", "AS"),
+    ("ANDORRA", "AD"),
+    ("ANGOLA", "AO"),
+    ("ANGUILLA", "AI"),
+    ("ANTARCTICA", "AQ"),
+    ("ANTIGUA AND BARBUDA", "AG"),
+    ("ARGENTINA", "AR"),
+    ("ARMENIA", "AM"),
+    ("ARUBA", "AW"),
+    ("AUSTRALIA", "AU"),
+    ("AUSTRIA", "AT"),
+    ("AZERBAIJAN", "AZ"),
+    ("BAHAMAS (THE)", "BS"),
+    ("BAHRAIN", "BH"),
+    ("BANGLADESH", "BD"),
+    ("BARBADOS", "BB"),
+    ("BELARUS", "BY"),
+    ("BELGIUM", "BE"),
+    ("BELIZE", "BZ"),
+    ("BENIN", "BJ"),
+    ("BERMUDA", "BM"),
+    ("BHUTAN", "BT"),
+    ("BOLIVIA (PLURINATIONAL STATE OF)", "BO"),
+    ("BONAIRE, SINT EUSTATIUS AND SABA", "BQ"),
+    ("BOSNIA AND HERZEGOVINA", "BA"),
+    ("BOTSWANA", "BW"),
+    ("BOUVET ISLAND", "BV"),
+    ("BRAZIL", "BR"),
+    ("BRITISH INDIAN OCEAN TERRITORY (THE)", "IO"),
+    ("BRUNEI DARUSSALAM", "BN"),
+    ("BULGARIA", "BG"),
+    ("BURKINA FASO", "BF"),
+    ("BURUNDI", "BI"),
+    ("CABO VERDE", "CV"),
+    ("CAMBODIA", "KH"),
+    ("CAMEROON", "CM"),
+    ("CANADA", "CA"),
+    ("CAYMAN ISLANDS (THE)", "KY"),
+    ("CENTRAL AFRICAN REPUBLIC (THE)", "CF"),
+    ("CHAD", "TD"),
+    ("CHILE", "CL"),
+    ("CHINA", "CN"),
+    ("CHRISTMAS ISLAND", "CX"),
+    ("COCOS (KEELING) ISLANDS (THE)", "CC"),
+    ("COLOMBIA", "CO"),
+    ("COMOROS (THE)", "KM"),
+    ("CONGO (THE DEMOCRATIC REPUBLIC OF THE)", "CD"),
+    ("CONGO (THE)", "CG"),
+    ("COOK ISLANDS (THE)", "CK"),
+    ("COSTA RICA", "CR"),
+    ("CÔTE D'IVOIRE", "CI"),
+    ("CROATIA", "HR"),
+    ("CUBA", "CU"),
+    ("CURAÇAO", "CW"),
+    ("CYPRUS", "CY"),
+    ("CZECHIA", "CZ"),
+    ("DENMARK", "DK"),
+    ("DJIBOUTI", "DJ"),
+    ("DOMINICA", "DM"),
+    ("DOMINICAN REPUBLIC (THE)", "DO"),
+    ("ECUADOR", "EC"),
+    ("EGYPT", "EG"),
+    ("EL SALVADOR", "SV"),
+    ("EQUATORIAL GUINEA", "GQ"),
+    ("ERITREA", "ER"),
+    ("ESTONIA", "EE"),
+    ("ESWATINI", "SZ"),
+    ("ETHIOPIA", "ET"),
+    ("FALKLAND ISLANDS (THE) [MALVINAS]", "FK"),
+    ("FAROE ISLANDS (THE)", "FO"),
+    ("FIJI", "FJ"),
+    ("FINLAND", "FI"),
+    ("FRANCE", "FR"),
+    ("FRENCH GUIANA", "GF"),
+    ("FRENCH POLYNESIA", "PF"),
+    ("FRENCH SOUTHERN TERRITORIES (THE)", "TF"),
+    ("GABON", "GA"),
+    ("GAMBIA (THE)", "GM"),
+    ("GEORGIA", "GE"),
+    ("GERMANY", "DE"),
+    ("GHANA", "GH"),
+    ("GIBRALTAR", "GI"),
+    ("GREECE", "GR"),
+    ("GREENLAND", "GL"),
+    ("GRENADA", "GD"),
+    ("GUADELOUPE", "GP"),
+    ("GUAM", "GU"),
+    ("GUATEMALA", "GT"),
+    ("GUERNSEY", "GG"),
+    ("GUINEA", "GN"),
+    ("GUINEA-BISSAU", "GW"),
+    ("GUYANA", "GY"),
+    ("HAITI", "HT"),
+    ("HEARD ISLAND AND McDONALD ISLANDS", "HM"),
+    ("HOLY SEE (THE)", "VA"),
+    ("HONDURAS", "HN"),
+    ("HONG KONG", "HK"),
+    ("HUNGARY", "HU"),
+    ("ICELAND", "IS"),
+    ("INDIA", "IN"),
+    ("INDONESIA", "ID"),
+    ("IRAN (ISLAMIC REPUBLIC OF)", "IR"),
+    ("IRAQ", "IQ"),
+    ("IRELAND", "IE"),
+    ("ISLE OF MAN", "IM"),
+    ("ISRAEL", "IL"),
+    ("ITALY", "IT"),
+    ("JAMAICA", "JM"),
+    ("JAPAN", "JP"),
+    ("JERSEY", "JE"),
+    ("JORDAN", "JO"),
+    ("KAZAKHSTAN", "KZ"),
+    ("KENYA", "KE"),
+    ("KIRIBATI", "KI"),
+    ("KOREA (THE DEMOCRATIC PEOPLE'S REPUBLIC OF)", "KP"),
+    ("KOREA (THE REPUBLIC OF)", "KR"),
+    ("KUWAIT", "KW"),
+    ("KYRGYZSTAN", "KG"),
+    ("LAO PEOPLE'S DEMOCRATIC REPUBLIC (THE)", "LA"),
+    ("LATVIA", "LV"),
+    ("LEBANON", "LB"),
+    ("LESOTHO", "LS"),
+    ("LIBERIA", "LR"),
+    ("LIBYA", "LY"),
+    ("LIECHTENSTEIN", "LI"),
+    ("LITHUANIA", "LT"),
+    ("LUXEMBOURG", "LU"),
+    ("MACAO", "MO"),
+    ("MADAGASCAR", "MG"),
+    ("MALAWI", "MW"),
+    ("MALAYSIA", "MY"),
+    ("MALDIVES", "MV"),
+    ("MALI", "ML"),
+    ("MALTA", "MT"),
+    ("MARSHALL ISLANDS (THE)", "MH"),
+    ("MARTINIQUE", "MQ"),
+    ("MAURITANIA", "MR"),
+    ("MAURITIUS", "MU"),
+    ("MAYOTTE", "YT"),
+    ("MEXICO", "MX"),
+    ("MICRONESIA (FEDERATED STATES OF)", "FM"),
+    ("MOLDOVA (THE REPUBLIC OF)", "MD"),
+    ("MONACO", "MC"),
+    ("MONGOLIA", "MN"),
+    ("MONTENEGRO", "ME"),
+    ("MONTSERRAT", "MS"),
+    ("MOROCCO", "MA"),
+    ("MOZAMBIQUE", "MZ"),
+    ("MYANMAR", "MM"),
+    ("NAMIBIA", "NA"),
+    ("NAURU", "NR"),
+    ("NEPAL", "NP"),
+    ("NETHERLANDS (THE)", "NL"),
+    ("NEW CALEDONIA", "NC"),
+    ("NEW ZEALAND", "NZ"),
+    ("NICARAGUA", "NI"),
+    ("NIGER (THE)", "NE"),
+    ("NIGERIA", "NG"),
+    ("NIUE", "NU"),
+    ("NORFOLK ISLAND", "NF"),
+    ("NORTH MACEDONIA", "MK"),
+    ("NORTHERN MARIANA ISLANDS (THE)", "MP"),
+    ("NORWAY", "NO"),
+    ("OMAN", "OM"),
+    ("PAKISTAN", "PK"),
+    ("PALAU", "PW"),
+    ("PALESTINE, STATE OF", "PS"),
+    ("PANAMA", "PA"),
+    ("PAPUA NEW GUINEA", "PG"),
+    ("PARAGUAY", "PY"),
+    ("PERU", "PE"),
+    ("PHILIPPINES (THE)", "PH"),
+    ("PITCAIRN", "PN"),
+    ("POLAND", "PL"),
+    ("PORTUGAL", "PT"),
+    ("PUERTO RICO", "PR"),
+    ("QATAR", "QA"),
+    ("RÉUNION", "RE"),
+    ("ROMANIA", "RO"),
+    ("RUSSIAN FEDERATION (THE)", "RU"),
+    ("RWANDA", "RW"),
+    ("SAINT BARTHÉLEMY", "BL"),
+    ("SAINT HELENA, ASCENSION AND TRISTAN DA CUNHA", "SH"),
+    ("SAINT KITTS AND NEVIS", "KN"),
+    ("SAINT LUCIA", "LC"),
+    ("SAINT MARTIN (FRENCH PART)", "MF"),
+    ("SAINT PIERRE AND MIQUELON", "PM"),
+    ("SAINT VINCENT AND THE GRENADINES", "VC"),
+    ("SAMOA", "WS"),
+    ("SAN MARINO", "SM"),
+    ("SAO TOME AND PRINCIPE", "ST"),
+    ("SAUDI ARABIA", "SA"),
+    ("SENEGAL", "SN"),
+    ("SERBIA", "RS"),
+    ("SEYCHELLES", "SC"),
+    ("SIERRA LEONE", "SL"),
+    ("SINGAPORE", "SG"),
+    ("SINT MAARTEN (DUTCH PART)", "SX"),
+    ("SLOVAKIA", "SK"),
+    ("SLOVENIA", "SI"),
+    ("SOLOMON ISLANDS", "SB"),
+    ("SOMALIA", "SO"),
+    ("SOUTH AFRICA", "ZA"),
+    ("SOUTH GEORGIA AND THE SOUTH SANDWICH ISLANDS", "GS"),
+    ("SOUTH SUDAN", "SS"),
+    ("SPAIN", "ES"),
+    ("SRI LANKA", "LK"),
+    ("SUDAN (THE)", "SD"),
+    ("SURINAME", "SR"),
+    ("SVALBARD AND JAN MAYEN", "SJ"),
+    ("SWEDEN", "SE"),
+    ("SWITZERLAND", "CH"),
+    ("SYRIAN ARAB REPUBLIC", "SY"),
+    ("TAIWAN (PROVINCE OF CHINA)", "TW"),
+    ("TAJIKISTAN", "TJ"),
+    ("TANZANIA, UNITED REPUBLIC OF", "TZ"),
+    ("THAILAND", "TH"),
+    ("TIMOR-LESTE", "TL"),
+    ("TOGO", "TG"),
+    ("TOKELAU", "TK"),
+    ("TONGA", "TO"),
+    ("TRINIDAD AND TOBAGO", "TT"),
+    ("TUNISIA", "TN"),
+    ("TÜRKİYE", "TR"),
+    ("TURKMENISTAN", "TM"),
+    ("TURKS AND CAICOS ISLANDS (THE)", "TC"),
+    ("TUVALU", "TV"),
+    ("UGANDA", "UG"),
+    ("UKRAINE", "UA"),
+    ("UNITED ARAB EMIRATES (THE)", "AE"),
+    (
+        "UNITED KINGDOM OF GREAT BRITAIN AND NORTHERN IRELAND (THE)",
+        "GB",
+    ),
+    ("UNITED STATES MINOR OUTLYING ISLANDS (THE)", "UM"),
+    ("UNITED STATES OF AMERICA (THE)", "US"),
+    ("URUGUAY", "UY"),
+    ("UZBEKISTAN", "UZ"),
+    ("VANUATU", "VU"),
+    ("VENEZUELA (BOLIVARIAN REPUBLIC OF)", "VE"),
+    ("VIET NAM", "VN"),
+    ("VIRGIN ISLANDS (BRITISH)", "VG"),
+    ("VIRGIN ISLANDS (U.S.)", "VI"),
+    ("WALLIS AND FUTUNA", "WF"),
+    ("WESTERN SAHARA", "EH"),
+    ("YEMEN", "YE"),
+    ("ZAMBIA", "ZM"),
+    ("ZIMBABWE", "ZW"),
+];
+
+/// Normalizes an entity name for comparison: uppercase, with typographic
+/// apostrophes replaced, "(THE)" removed, and whitespace collapsed.
+fn normalize(name: &str) -> String {
+    name.to_uppercase()
+        .replace(['’', '\u{2019}'], "'")
+        .replace("(THE)", "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the ISO 3166 alpha-2 code for an entity name in the list,
+/// including the former codes of countries that no longer exist, or
+/// None if the entity isn't a known country.
+pub fn alpha2(entity: &str) -> Option<&'static str> {
+    let entity = normalize(entity);
+    COUNTRIES
+        .iter()
+        .chain(FORMER_COUNTRIES)
+        .find(|(name, _)| normalize(name) == entity)
+        .map(|(_, code)| *code)
+}
+
+/// Returns true if the entity is known not to be a country, such as the
+/// European Union, or the placeholder entities the list uses for bond
+/// market units, metals and the testing and no-currency codes.
+pub fn is_supranational(entity: &str) -> bool {
+    let entity = normalize(entity);
+    entity.starts_with("ZZ") || SUPRANATIONAL.iter().any(|name| normalize(name) == entity)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::countries::*;
+
+    #[test]
+    fn lookup() {
+        assert_eq!(alpha2("UNITED STATES OF AMERICA (THE)"), Some("US"));
+        assert_eq!(alpha2("United States of America"), Some("US"));
+        assert_eq!(
+            alpha2("KOREA (THE DEMOCRATIC PEOPLE’S REPUBLIC OF)"),
+            Some("KP")
+        );
+        assert_eq!(alpha2("CÔTE D'IVOIRE"), Some("CI"));
+        assert_eq!(alpha2("YUGOSLAVIA"), Some("YU"));
+        assert_eq!(alpha2("EUROPEAN UNION"), None);
+        assert!(is_supranational("EUROPEAN UNION"));
+        assert!(is_supranational("ZZ08_Gold"));
+        assert!(is_supranational(
+            "EUROPEAN MONETARY CO-OPERATION FUND (EMCF)"
+        ));
+        assert!(is_supranational("INTERNATIONAL MONETARY FUND (IMF)\u{a0}"));
+        assert!(!is_supranational("NEVERLAND"));
+    }
+
+    #[test]
+    fn codes_are_unique() {
+        let mut codes: Vec<&str> = COUNTRIES.iter().map(|(_, code)| *code).collect();
+        codes.sort();
+        let count = codes.len();
+        codes.dedup();
+        assert_eq!(codes.len(), count);
+        for (_, code) in FORMER_COUNTRIES {
+            assert!(!codes.contains(code));
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! Reads the ISO 4217 list three XML, which lists withdrawn currencies.
+//!
+//! Like list one, it has one `HstrcCcyNtry` element per entity and currency,
+//! but instead of minor units each entry has the month in which the currency
+//! was withdrawn from that entity:
+//!
+//! ```xml
+//! <ISO_4217 Pblshd="2026-01-01">
+//!   <HstrcCcyTbl>
+//!     <HstrcCcyNtry>
+//!       <CtryNm>GERMANY</CtryNm>
+//!       <CcyNm>Deutsche Mark</CcyNm>
+//!       <Ccy>DEM</Ccy>
+//!       <CcyNbr>276</CcyNbr>
+//!       <WthdrwlDt>2002-03</WthdrwlDt>
+//!     </HstrcCcyNtry>
+//!   </HstrcCcyTbl>
+//! </ISO_4217>
+//! ```
+//!
+//! The oldest entries only give a range of years, like "1989 to 1990".
+use crate::{
+    countries,
+    iso_list::{self, CurrencyDef, IsoList},
+};
+
+/// List three doesn't give minor units, so these are the values from the
+/// last edition of list one that included each currency. Currencies that
+/// aren't in this table had 2.
+const MINOR_UNITS: &[(&str, u32)] = &[
+    ("ADP", 0),
+    ("BEC", 0),
+    ("BEF", 0),
+    ("BEL", 0),
+    ("BYR", 0),
+    ("ESA", 0),
+    ("ESB", 0),
+    ("ESP", 0),
+    ("GRD", 0),
+    ("ITL", 0),
+    ("LUC", 0),
+    ("LUF", 0),
+    ("LUL", 0),
+    ("MGF", 0),
+    ("PTE", 0),
+    ("TPE", 0),
+    ("TRL", 0),
+    ("XEU", 0),
+    ("XFO", 0),
+    ("XFU", 0),
+    ("XRE", 0),
+];
+
+pub fn parse(xml: &str) -> Result<IsoList, String> {
+    let published = iso_list::attribute(xml, "ISO_4217", "Pblshd")
+        .ok_or("missing ISO_4217 element with a Pblshd attribute")?;
+    let mut list = IsoList {
+        published,
+        currencies: Vec::new(),
+        unknown_entities: Vec::new(),
+    };
+
+    for entry in xml.split("<HstrcCcyNtry>").skip(1) {
+        let entry = entry.split("</HstrcCcyNtry>").next().unwrap_or_default();
+        let Some(code) = iso_list::element(entry, "Ccy") else {
+            continue;
+        };
+        let entity = iso_list::element(entry, "CtryNm").unwrap_or_default();
+        let invalid = |field: &str| format!("invalid {field} for {code}");
+        let name = iso_list::element(entry, "CcyNm").ok_or_else(|| invalid("CcyNm"))?;
+        // a few units of account were never given a number
+        let numeric_code = match iso_list::element(entry, "CcyNbr").as_deref() {
+            Some("Nil") => 0,
+            number => number
+                .and_then(|n| n.parse().ok())
+                .ok_or_else(|| invalid("CcyNbr"))?,
+        };
+        let withdrawn_on = iso_list::element(entry, "WthdrwlDt")
+            .as_deref()
+            .and_then(withdrawal_date)
+            .ok_or_else(|| invalid("WthdrwlDt"))?;
+        let is_fund = iso_list::attribute(entry, "CcyNm", "IsFund").is_some_and(|f| f == "true");
+
+        let country = countries::alpha2(&entity);
+        if country.is_none() && !countries::is_supranational(&entity) {
+            list.unknown_entities.push(entity);
+        }
+
+        match list.currencies.iter_mut().find(|c| c.code == code) {
+            Some(existing) => {
+                if let Some(country) = country {
+                    existing.countries.push(country.to_string());
+                    existing.countries.sort();
+                    existing.countries.dedup();
+                }
+                // the currency was in use until the last entity withdrew it
+                if existing.withdrawn_on.as_ref() < Some(&withdrawn_on) {
+                    existing.name = name;
+                    existing.withdrawn_on = Some(withdrawn_on);
+                }
+            }
+            None => list.currencies.push(CurrencyDef {
+                category: iso_list::category(&code),
+                minor_units: MINOR_UNITS
+                    .iter()
+                    .find(|(c, _)| *c == code)
+                    .map_or(2, |(_, units)| *units),
+                code,
+                name,
+                numeric_code,
+                countries: country.map(str::to_string).into_iter().collect(),
+                is_fund,
+                withdrawn_on: Some(withdrawn_on),
+            }),
+        }
+    }
+
+    if list.currencies.is_empty() {
+        return Err("no currencies were found in the XML".to_string());
+    }
+    Ok(list)
+}
+
+/// Converts a withdrawal date in the list to the first day of the month,
+/// like "2002-03" to "2002-03-01". For a range, the end of the range is
+/// used, and a year without a month is taken to mean January.
+fn withdrawal_date(text: &str) -> Option<String> {
+    let end = text.rsplit(" to ").next()?.trim();
+    let (year, month) = end.split_once('-').unwrap_or((end, "01"));
+    let digits = |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+    if !digits(year, 4) || !digits(month, 2) || !("01"..="12").contains(&month) {
+        return None;
+    }
+    Some(format!("{year}-{month}-01"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::historic_list::*;
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<ISO_4217 Pblshd="2026-01-01">
+  <HstrcCcyTbl>
+    <HstrcCcyNtry>
+      <CtryNm>ARMENIA</CtryNm>
+      <CcyNm>Russian Ruble</CcyNm>
+      <Ccy>RUR</Ccy>
+      <CcyNbr>810</CcyNbr>
+      <WthdrwlDt>1994-08</WthdrwlDt>
+    </HstrcCcyNtry>
+    <HstrcCcyNtry>
+      <CtryNm>BELGIUM</CtryNm>
+      <CcyNm IsFund="true">Convertible Franc</CcyNm>
+      <Ccy>BEC</Ccy>
+      <CcyNbr>993</CcyNbr>
+      <WthdrwlDt>1990-03</WthdrwlDt>
+    </HstrcCcyNtry>
+    <HstrcCcyNtry>
+      <CtryNm>RUSSIAN FEDERATION (THE)</CtryNm>
+      <CcyNm>Russian Ruble</CcyNm>
+      <Ccy>RUR</Ccy>
+      <CcyNbr>810</CcyNbr>
+      <WthdrwlDt>1998-01</WthdrwlDt>
+    </HstrcCcyNtry>
+    <HstrcCcyNtry>
+      <CtryNm>ICELAND</CtryNm>
+      <CcyNm>Old Krona</CcyNm>
+      <Ccy>ISJ</Ccy>
+      <CcyNbr>352</CcyNbr>
+      <WthdrwlDt>1989 to 1990</WthdrwlDt>
+    </HstrcCcyNtry>
+    <HstrcCcyNtry>
+      <CtryNm>ZZ01_Gold-Franc</CtryNm>
+      <CcyNm>Gold-Franc</CcyNm>
+      <Ccy>XFO</Ccy>
+      <CcyNbr>Nil</CcyNbr>
+      <WthdrwlDt>2006-10</WthdrwlDt>
+    </HstrcCcyNtry>
+    <HstrcCcyNtry>
+      <CtryNm>ATLANTIS</CtryNm>
+      <CcyNm>Drachma</CcyNm>
+      <Ccy>ATD</Ccy>
+      <CcyNbr>1</CcyNbr>
+      <WthdrwlDt>1970-01</WthdrwlDt>
+    </HstrcCcyNtry>
+  </HstrcCcyTbl>
+</ISO_4217>"#;
+
+    #[test]
+    fn parse_list() {
+        let list = parse(XML).unwrap();
+        assert_eq!(list.published, "2026-01-01");
+        assert_eq!(list.unknown_entities, vec!["ATLANTIS"]);
+
+        let codes: Vec<&str> = list.currencies.iter().map(|c| c.code.as_str()).collect();
+        assert_eq!(codes, vec!["RUR", "BEC", "ISJ", "XFO", "ATD"]);
+
+        let rur = &list.currencies[0];
+        assert_eq!(rur.countries, vec!["AM", "RU"]);
+        assert_eq!(rur.minor_units, 2);
+        assert_eq!(rur.withdrawn_on.as_deref(), Some("1998-01-01"));
+
+        let bec = &list.currencies[1];
+        assert_eq!(bec.minor_units, 0);
+        assert!(bec.is_fund);
+        assert_eq!(bec.category, None);
+
+        assert_eq!(
+            list.currencies[2].withdrawn_on.as_deref(),
+            Some("1990-01-01")
+        );
+
+        let xfo = &list.currencies[3];
+        assert_eq!(xfo.numeric_code, 0);
+        assert!(xfo.countries.is_empty());
+        assert_eq!(xfo.category.as_deref(), Some("Supranational"));
+    }
+
+    #[test]
+    fn withdrawal_dates() {
+        assert_eq!(withdrawal_date("2002-03").as_deref(), Some("2002-03-01"));
+        assert_eq!(
+            withdrawal_date("1989 to 1990").as_deref(),
+            Some("1990-01-01")
+        );
+        assert_eq!(
+            withdrawal_date("1990-07 to 1990-09").as_deref(),
+            Some("1990-09-01")
+        );
+        assert_eq!(withdrawal_date("2002-13"), None);
+        assert_eq!(withdrawal_date("soon"), None);
+    }
+
+    #[test]
+    fn invalid_list() {
+        assert!(parse("<HstrcCcyTbl></HstrcCcyTbl>").is_err());
+        assert_eq!(
+            parse(&XML.replace("1970-01", "")).unwrap_err(),
+            "invalid WthdrwlDt for ATD"
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Reads the ISO 4217 list one XML.
+//!
+//! The list has one `CcyNtry` element per entity (usually a country) and
+//! currency, so a currency used by several countries appears several times:
+//!
+//! ```xml
+//! <ISO_4217 Pblshd="2026-01-01">
+//!   <CcyTbl>
+//!     <CcyNtry>
+//!       <CtryNm>BOLIVIA (PLURINATIONAL STATE OF)</CtryNm>
+//!       <CcyNm IsFund="true">Mvdol</CcyNm>
+//!       <Ccy>BOV</Ccy>
+//!       <CcyNbr>984</CcyNbr>
+//!       <CcyMnrUnts>2</CcyMnrUnts>
+//!     </CcyNtry>
+//!   </CcyTbl>
+//! </ISO_4217>
+//! ```
+use crate::countries;
+
+/// A currency as defined by the list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrencyDef {
+    pub code: String,
+    pub name: String,
+    pub numeric_code: u32,
+    pub minor_units: u32,
+    /// ISO 3166 alpha-2 codes, sorted.
+    pub countries: Vec<String>,
+    pub is_fund: bool,
+    /// The `CurrencyCategory` variant, or None for national currencies.
+    pub category: Option<String>,
+    /// The date from which the currency was no longer in use, as
+    /// `YYYY-MM-DD`, or None for current currencies.
+    pub withdrawn_on: Option<String>,
+}
+
+/// The contents of the list.
+#[derive(Debug)]
+pub struct IsoList {
+    /// The date on which the list was published.
+    pub published: String,
+    /// The currencies, in the order they first appear in the list.
+    pub currencies: Vec<CurrencyDef>,
+    /// Names of entities with a currency but without a known ISO 3166 code.
+    pub unknown_entities: Vec<String>,
+}
+
+pub fn parse(xml: &str) -> Result<IsoList, String> {
+    let published = attribute(xml, "ISO_4217", "Pblshd")
+        .ok_or("missing ISO_4217 element with a Pblshd attribute")?;
+    let mut list = IsoList {
+        published,
+        currencies: Vec::new(),
+        unknown_entities: Vec::new(),
+    };
+
+    for entry in xml.split("<CcyNtry>").skip(1) {
+        let entry = entry.split("</CcyNtry>").next().unwrap_or_default();
+        // entities without a universal currency have no code
+        let Some(code) = element(entry, "Ccy") else {
+            continue;
+        };
+        let entity = element(entry, "CtryNm").unwrap_or_default();
+        let invalid = |field: &str| format!("invalid {field} for {code}");
+        let name = element(entry, "CcyNm").ok_or_else(|| invalid("CcyNm"))?;
+        let numeric_code = element(entry, "CcyNbr")
+            .and_then(|n| n.parse().ok())
+            .ok_or_else(|| invalid("CcyNbr"))?;
+        // units of account and metals have "N.A." minor units
+        let minor_units = match element(entry, "CcyMnrUnts").as_deref() {
+            Some("N.A.") => 0,
+            units => units
+                .and_then(|u| u.parse().ok())
+                .ok_or_else(|| invalid("CcyMnrUnts"))?,
+        };
+        let is_fund = attribute(entry, "CcyNm", "IsFund").is_some_and(|f| f == "true");
+
+        let country = countries::alpha2(&entity);
+        if country.is_none() && !countries::is_supranational(&entity) {
+            list.unknown_entities.push(entity);
+        }
+
+        match list.currencies.iter_mut().find(|c| c.code == code) {
+            Some(existing) => {
+                if let Some(country) = country {
+                    existing.countries.push(country.to_string());
+                    existing.countries.sort();
+                    existing.countries.dedup();
+                }
+            }
+            None => list.currencies.push(CurrencyDef {
+                category: category(&code),
+                code,
+                name,
+                numeric_code,
+                minor_units,
+                countries: country.map(str::to_string).into_iter().collect(),
+                is_fund,
+                withdrawn_on: None,
+            }),
+        }
+    }
+
+    if list.currencies.is_empty() {
+        return Err("no currencies were found in the XML".to_string());
+    }
+    Ok(list)
+}
+
+/// Returns the category for a currency code. ISO reserves codes starting
+/// with X for currencies that aren't issued by a single country.
+pub fn category(code: &str) -> Option<String> {
+    let category = match code {
+        "XAG" | "XAU" | "XPD" | "XPT" => "PreciousMetal",
+        "XTS" => "Testing",
+        "XXX" => "NoCurrency",
+        "EUR" => "Supranational",
+        _ if code.starts_with('X') => "Supranational",
+        _ => return None,
+    };
+    Some(category.to_string())
+}
+
+/// Returns the text of the first element with the specified tag.
+pub fn element(xml: &str, tag: &str) -> Option<String> {
+    let (_, rest) = start_tag(xml, tag)?;
+    let text = rest.split(&format!("</{tag}>")).next()?;
+    Some(unescape(text).trim().to_string())
+}
+
+/// Returns the value of an attribute on the first element with the specified tag.
+pub fn attribute(xml: &str, tag: &str, name: &str) -> Option<String> {
+    let (attributes, _) = start_tag(xml, tag)?;
+    let (_, value) = attributes.split_once(&format!("{name}=\""))?;
+    Some(unescape(value.split('"').next()?))
+}
+
+/// Finds the first start tag with the specified name, returning
+/// its attributes and the text that follows it.
+fn start_tag<'a>(xml: &'a str, tag: &str) -> Option<(&'a str, &'a str)> {
+    let open = format!("<{tag}");
+    let mut rest = xml;
+    loop {
+        let start = rest.find(&open)? + open.len();
+        rest = &rest[start..];
+        // skip tags that only start with this name, like CcyNm for Ccy
+        if rest.starts_with(|c: char| c == '>' || c.is_whitespace()) {
+            let end = rest.find('>')?;
+            return Some((&rest[..end], &rest[end + 1..]));
+        }
+    }
+}
+
+/// Replaces the predefined XML entities and numeric character references.
+fn unescape(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';') else {
+            break;
+        };
+        let entity = &rest[1..end];
+        let c = match entity {
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "amp" => Some('&'),
+            _ => entity
+                .strip_prefix("#x")
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| entity.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        };
+        match c {
+            Some(c) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iso_list::*;
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<ISO_4217 Pblshd="2026-01-01">
+  <CcyTbl>
+    <CcyNtry>
+      <CtryNm>ANTARCTICA</CtryNm>
+      <CcyNm>No universal currency</CcyNm>
+    </CcyNtry>
+    <CcyNtry>
+      <CtryNm>BOLIVIA (PLURINATIONAL STATE OF)</CtryNm>
+      <CcyNm IsFund="true">Mvdol</CcyNm>
+      <Ccy>BOV</Ccy>
+      <CcyNbr>984</CcyNbr>
+      <CcyMnrUnts>2</CcyMnrUnts>
+    </CcyNtry>
+    <CcyNtry>
+      <CtryNm>LIECHTENSTEIN</CtryNm>
+      <CcyNm>Swiss Franc</CcyNm>
+      <Ccy>CHF</Ccy>
+      <CcyNbr>756</CcyNbr>
+      <CcyMnrUnts>2</CcyMnrUnts>
+    </CcyNtry>
+    <CcyNtry>
+      <CtryNm>SWITZERLAND</CtryNm>
+      <CcyNm>Swiss Franc</CcyNm>
+      <Ccy>CHF</Ccy>
+      <CcyNbr>756</CcyNbr>
+      <CcyMnrUnts>2</CcyMnrUnts>
+    </CcyNtry>
+    <CcyNtry>
+      <CtryNm>INTERNATIONAL MONETARY FUND (IMF)&#160;</CtryNm>
+      <CcyNm>SDR (Special Drawing Right)</CcyNm>
+      <Ccy>XDR</Ccy>
+      <CcyNbr>960</CcyNbr>
+      <CcyMnrUnts>N.A.</CcyMnrUnts>
+    </CcyNtry>
+    <CcyNtry>
+      <CtryNm>NEVERLAND</CtryNm>
+      <CcyNm>Pixie Dust</CcyNm>
+      <Ccy>PXD</Ccy>
+      <CcyNbr>1</CcyNbr>
+      <CcyMnrUnts>3</CcyMnrUnts>
+    </CcyNtry>
+  </CcyTbl>
+</ISO_4217>"#;
+
+    #[test]
+    fn parse_list() {
+        let list = parse(XML).unwrap();
+        assert_eq!(list.published, "2026-01-01");
+        assert_eq!(list.unknown_entities, vec!["NEVERLAND"]);
+
+        let codes: Vec<&str> = list.currencies.iter().map(|c| c.code.as_str()).collect();
+        assert_eq!(codes, vec!["BOV", "CHF", "XDR", "PXD"]);
+
+        let bov = &list.currencies[0];
+        assert_eq!(bov.name, "Mvdol");
+        assert_eq!(bov.numeric_code, 984);
+        assert_eq!(bov.minor_units, 2);
+        assert_eq!(bov.countries, vec!["BO"]);
+        assert!(bov.is_fund);
+        assert_eq!(bov.category, None);
+
+        assert_eq!(list.currencies[1].countries, vec!["CH", "LI"]);
+        assert!(!list.currencies[1].is_fund);
+
+        let xdr = &list.currencies[2];
+        assert_eq!(xdr.minor_units, 0);
+        assert!(xdr.countries.is_empty());
+        assert_eq!(xdr.category.as_deref(), Some("Supranational"));
+    }
+
+    #[test]
+    fn unescape_entities() {
+        assert_eq!(unescape("CÔTE D&apos;IVOIRE"), "CÔTE D'IVOIRE");
+        assert_eq!(unescape("A&#160;B&#xA0;C"), "A\u{a0}B\u{a0}C");
+        assert_eq!(
+            unescape("A &amp; B &unknown; C & D"),
+            "A & B &unknown; C & D"
+        );
+    }
+
+    #[test]
+    fn invalid_list() {
+        assert!(parse("<CcyTbl></CcyTbl>").is_err());
+        assert!(parse(r#"<ISO_4217 Pblshd="2026-01-01"></ISO_4217>"#).is_err());
+        assert_eq!(
+            parse(&XML.replace("<CcyNbr>984</CcyNbr>", "<CcyNbr>x</CcyNbr>")).unwrap_err(),
+            "invalid CcyNbr for BOV"
+        );
+    }
+}
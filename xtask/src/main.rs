@@ -0,0 +1,135 @@
+//! Development tasks for doubloon, run with `cargo xtask <task>`.
+//!
+//! Tasks:
+//!
+//! - `iso-currencies <list-one.xml> [--check]`: regenerates
+//!   `src/iso_currencies.rs` from a local copy of the ISO 4217 list one XML
+//!   published by SIX, and reports the currencies that were added, removed or
+//!   changed. With `--check`, only the report is printed, and the task fails
+//!   if the module is out of date.
+//! - `iso-historic-currencies <list-three.xml> [--check]`: regenerates
+//!   `src/iso_historic_currencies.rs` in the same way from list three, which
+//!   lists withdrawn currencies. Codes that are in `src/iso_currencies.rs`
+//!   are left out, so run `iso-currencies` first.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, ExitCode},
+};
+
+mod countries;
+mod historic_list;
+mod iso_list;
+mod module;
+
+use iso_list::IsoList;
+use module::Layout;
+
+const USAGE: &str = "usage: cargo xtask iso-currencies <list-one.xml> [--check]
+       cargo xtask iso-historic-currencies <list-three.xml> [--check]";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("iso-currencies") => generate(&args[1..], &module::CURRENT, iso_list::parse),
+        Some("iso-historic-currencies") => iso_historic_currencies(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("{message}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn iso_historic_currencies(args: &[String]) -> Result<ExitCode, String> {
+    let current = module::parse_currencies(&module::CURRENT, &read(&module::CURRENT)?)?;
+    generate(args, &module::HISTORIC, |xml| {
+        let mut list = historic_list::parse(xml)?;
+        // list three also records entities that stopped using a current
+        // currency, like the euro in Serbia and Montenegro
+        list.currencies
+            .retain(|c| !current.iter().any(|current| current.code == c.code));
+        Ok(list)
+    })
+}
+
+/// Regenerates the module with the specified layout from the list at the
+/// path in `args`, which is read by `parse`.
+fn generate(
+    args: &[String],
+    layout: &Layout,
+    parse: impl FnOnce(&str) -> Result<IsoList, String>,
+) -> Result<ExitCode, String> {
+    let check = args.iter().any(|a| a == "--check");
+    let mut paths = args.iter().filter(|a| *a != "--check");
+    let (Some(xml_path), None) = (paths.next(), paths.next()) else {
+        return Err(USAGE.to_string());
+    };
+
+    let xml =
+        fs::read_to_string(xml_path).map_err(|e| format!("unable to read {xml_path}: {e}"))?;
+    let list = parse(&xml)?;
+    for entity in &list.unknown_entities {
+        eprintln!("warning: no ISO 3166 code for {entity:?}; add it to xtask/src/countries.rs");
+    }
+
+    let current = read(layout)?;
+    let changes = module::diff(
+        &module::parse_currencies(layout, &current)?,
+        &list.currencies,
+    );
+    if changes.is_empty() {
+        println!("no currencies were added, removed or changed");
+    }
+    for change in &changes {
+        println!("{change}");
+    }
+
+    if check {
+        return Ok(if changes.is_empty() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        });
+    }
+
+    let module_path = module_path(layout);
+    let generated = module::render(layout, &current, &list)?;
+    fs::write(&module_path, generated)
+        .map_err(|e| format!("unable to write {}: {e}", module_path.display()))?;
+    format(&module_path)?;
+    println!("wrote {}", module_path.display());
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Returns the path of a generated module in the doubloon crate.
+fn module_path(layout: &Layout) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("src")
+        .join(layout.file)
+}
+
+/// Reads the current contents of a generated module.
+fn read(layout: &Layout) -> Result<String, String> {
+    let path = module_path(layout);
+    fs::read_to_string(&path).map_err(|e| format!("unable to read {}: {e}", path.display()))
+}
+
+/// Runs rustfmt on the generated module, so the output matches `cargo fmt`.
+fn format(path: &Path) -> Result<(), String> {
+    let status = Command::new("rustfmt")
+        .arg("--edition")
+        .arg("2021")
+        .arg(path)
+        .status()
+        .map_err(|e| format!("unable to run rustfmt: {e}"))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("rustfmt failed on {}", path.display()))
+    }
+}
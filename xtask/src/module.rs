@@ -0,0 +1,478 @@
+//! Reads and renders the generated currency modules, `src/iso_currencies.rs`
+//! and `src/iso_historic_currencies.rs`.
+//!
+//! Each module has four sections:
+//!
+//! - a header, with the date on which the list was published;
+//! - one struct per currency, which is generated;
+//! - a hand-written part, such as the `iso_currency_enum!` macro, followed
+//!   by the sorted list of codes, which is generated;
+//! - the hand-written lookup tables, trait impls and tests.
+//!
+//! Only the generated parts are replaced, so changes to the hand-written
+//! parts are kept when a module is regenerated.
+use std::fmt;
+
+use crate::iso_list::{CurrencyDef, IsoList};
+
+const STRUCT: &str = "#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]\npub struct ";
+const PUBLISHED: &str = "published on ";
+
+/// Where the generated parts of a module are.
+pub struct Layout {
+    /// The file name of the module in `src`.
+    pub file: &'static str,
+    /// The start of the hand-written part that follows the structs.
+    after_structs: &'static str,
+    /// The text just before the list of codes.
+    codes_start: &'static str,
+    /// The text just after the list of codes.
+    codes_end: &'static str,
+    /// What is written before each code in the list.
+    code_prefix: &'static str,
+}
+
+/// The current currencies, generated from list one. The codes are passed
+/// to the macro that builds the `IsoCurrency` enum.
+pub const CURRENT: Layout = Layout {
+    file: "iso_currencies.rs",
+    after_structs: "/// Generates the [IsoCurrency] enum",
+    codes_start: "iso_currency_enum!(",
+    codes_end: ");",
+    code_prefix: "",
+};
+
+/// The historic currencies, generated from list three. The codes are
+/// the elements of `ALL_CURRENCIES`.
+pub const HISTORIC: Layout = Layout {
+    file: "iso_historic_currencies.rs",
+    after_structs: "/// All the currencies defined in this module",
+    codes_start: "pub static ALL_CURRENCIES: &[&dyn Currency] = &[",
+    codes_end: "];",
+    code_prefix: "&",
+};
+
+/// The sections of the module, split around the generated parts.
+struct Sections<'a> {
+    /// Everything before the first struct.
+    header: &'a str,
+    /// The currency structs.
+    structs: &'a str,
+    /// From the end of the structs to the start of the list of codes.
+    before_codes: &'a str,
+    /// From the end of the list of codes to the end of the module.
+    rest: &'a str,
+}
+
+fn split<'a>(layout: &Layout, module: &'a str) -> Result<Sections<'a>, String> {
+    let missing = |what: &str| format!("unable to find {what} in {}", layout.file);
+    let first = module
+        .find(STRUCT)
+        .ok_or_else(|| missing("a currency struct"))?;
+    // the struct starts with its doc comment
+    let structs_start = module[..first]
+        .trim_end_matches('\n')
+        .rfind('\n')
+        .map_or(0, |i| i + 1);
+    let structs_end = module
+        .find(layout.after_structs)
+        .ok_or_else(|| missing(layout.after_structs))?;
+    let codes_start = module[structs_end..]
+        .find(layout.codes_start)
+        .map(|i| structs_end + i + layout.codes_start.len())
+        .ok_or_else(|| missing("the list of codes"))?;
+    let codes_end = module[codes_start..]
+        .find(layout.codes_end)
+        .map(|i| codes_start + i)
+        .ok_or_else(|| missing("the end of the list of codes"))?;
+    if structs_start > structs_end {
+        return Err(missing("the currency structs before the list of codes"));
+    }
+    Ok(Sections {
+        header: &module[..structs_start],
+        structs: &module[structs_start..structs_end],
+        before_codes: &module[structs_end..codes_start],
+        rest: &module[codes_end..],
+    })
+}
+
+/// Returns the currencies defined by the structs in the module.
+pub fn parse_currencies(layout: &Layout, module: &str) -> Result<Vec<CurrencyDef>, String> {
+    split(layout, module)?
+        .structs
+        .split("pub struct ")
+        .skip(1)
+        .map(parse_struct)
+        .collect()
+}
+
+fn parse_struct(block: &str) -> Result<CurrencyDef, String> {
+    let ident = block.split(';').next().unwrap_or_default();
+    let invalid = |what: &str| format!("invalid {what} for the {ident} struct");
+    let number = |name: &str| {
+        body(block, name)
+            .and_then(|b| b.parse().ok())
+            .ok_or_else(|| invalid(name))
+    };
+    Ok(CurrencyDef {
+        code: body(block, "code")
+            .and_then(unquote)
+            .ok_or_else(|| invalid("code"))?,
+        name: body(block, "name")
+            .and_then(unquote)
+            .ok_or_else(|| invalid("name"))?,
+        numeric_code: number("numeric_code")?,
+        minor_units: number("minor_units")?,
+        countries: body(block, "countries")
+            .map(|b| {
+                b.split('"')
+                    .skip(1)
+                    .step_by(2)
+                    .map(str::to_string)
+                    .collect()
+            })
+            .unwrap_or_default(),
+        is_fund: body(block, "is_fund") == Some("true"),
+        category: body(block, "category")
+            .map(|b| b.trim_start_matches("CurrencyCategory::").to_string()),
+        withdrawn_on: match body(block, "withdrawn_on") {
+            Some(b) => Some(
+                b.strip_prefix("parse_date(")
+                    .and_then(|b| b.strip_suffix(')'))
+                    .and_then(unquote)
+                    .ok_or_else(|| invalid("withdrawn_on"))?,
+            ),
+            None => None,
+        },
+    })
+}
+
+/// Returns the trimmed body of the method with the specified name.
+fn body<'a>(block: &'a str, method: &str) -> Option<&'a str> {
+    let (_, rest) = block.split_once(&format!("fn {method}(&self)"))?;
+    let (_, rest) = rest.split_once('{')?;
+    let (body, _) = rest.split_once("\n    }")?;
+    Some(body.trim())
+}
+
+/// Renders the module for the list, keeping the hand-written
+/// sections of the current module. The result is not formatted.
+pub fn render(layout: &Layout, current: &str, list: &IsoList) -> Result<String, String> {
+    let sections = split(layout, current)?;
+    let (before, after) = sections
+        .header
+        .split_once(PUBLISHED)
+        .ok_or_else(|| format!("unable to find the publication date in {}", layout.file))?;
+    let after = &after[after.find('\n').unwrap_or(after.len())..];
+
+    let mut module = format!("{before}{PUBLISHED}{}{after}", list.published);
+    for currency in &list.currencies {
+        module.push_str(&render_struct(currency));
+        module.push('\n');
+    }
+    module.push_str(sections.before_codes);
+
+    let mut codes: Vec<String> = list
+        .currencies
+        .iter()
+        .map(|c| format!("{}{}", layout.code_prefix, c.code))
+        .collect();
+    codes.sort();
+    module.push_str(&format!("\n    {},\n", codes.join(", ")));
+    module.push_str(sections.rest);
+    Ok(module)
+}
+
+fn render_struct(currency: &CurrencyDef) -> String {
+    let CurrencyDef {
+        code,
+        name,
+        numeric_code,
+        minor_units,
+        countries,
+        is_fund,
+        category,
+        withdrawn_on,
+    } = currency;
+    let countries: Vec<String> = countries.iter().map(|c| quote(c)).collect();
+    let mut block = format!(
+        "/// {name}
+{STRUCT}{code};
+impl Currency for {code} {{
+    fn code(&self) -> &'static str {{
+        {}
+    }}
+
+    fn minor_units(&self) -> u32 {{
+        {minor_units}
+    }}
+
+    fn numeric_code(&self) -> u32 {{
+        {numeric_code}
+    }}
+
+    fn name(&self) -> &'static str {{
+        {}
+    }}
+
+    fn countries(&self) -> &'static [&'static str] {{
+        &[{}]
+    }}
+",
+        quote(code),
+        quote(name),
+        countries.join(", "),
+    );
+    if *is_fund {
+        block.push_str(
+            "
+    fn is_fund(&self) -> bool {
+        true
+    }
+",
+        );
+    }
+    if let Some(category) = category {
+        block.push_str(&format!(
+            "
+    fn category(&self) -> CurrencyCategory {{
+        CurrencyCategory::{category}
+    }}
+"
+        ));
+    }
+    if let Some(withdrawn_on) = withdrawn_on {
+        block.push_str(&format!(
+            "
+    fn withdrawn_on(&self) -> Option<SystemTime> {{
+        parse_date({})
+    }}
+",
+            quote(withdrawn_on)
+        ));
+    }
+    block.push_str("}\n");
+    block
+}
+
+/// Renders a string literal.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Parses a string literal rendered by [quote].
+fn unquote(literal: &str) -> Option<String> {
+    let inner = literal.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        result.push(if c == '\\' { chars.next()? } else { c });
+    }
+    Some(result)
+}
+
+/// A difference between the currencies in the module and those in the list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Added(CurrencyDef),
+    Removed(CurrencyDef),
+    /// The code, and the name and old and new values of each changed field.
+    Changed(String, Vec<(&'static str, String, String)>),
+}
+
+impl Change {
+    fn code(&self) -> &str {
+        match self {
+            Change::Added(currency) | Change::Removed(currency) => &currency.code,
+            Change::Changed(code, _) => code,
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added(c) => write!(f, "added {} ({})", c.code, c.name),
+            Change::Removed(c) => write!(f, "removed {} ({})", c.code, c.name),
+            Change::Changed(code, fields) => {
+                write!(f, "changed {code}:")?;
+                for (field, old, new) in fields {
+                    write!(f, "\n    {field}: {old} -> {new}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Returns the currencies that were added, removed or changed, ordered by code.
+pub fn diff(current: &[CurrencyDef], new: &[CurrencyDef]) -> Vec<Change> {
+    let mut changes: Vec<Change> = current
+        .iter()
+        .filter(|c| !new.iter().any(|n| n.code == c.code))
+        .map(|c| Change::Removed(c.clone()))
+        .collect();
+    for n in new {
+        let Some(c) = current.iter().find(|c| c.code == n.code) else {
+            changes.push(Change::Added(n.clone()));
+            continue;
+        };
+        let fields = [
+            ("name", format!("{:?}", c.name), format!("{:?}", n.name)),
+            (
+                "numeric_code",
+                c.numeric_code.to_string(),
+                n.numeric_code.to_string(),
+            ),
+            (
+                "minor_units",
+                c.minor_units.to_string(),
+                n.minor_units.to_string(),
+            ),
+            (
+                "countries",
+                format!("{:?}", c.countries),
+                format!("{:?}", n.countries),
+            ),
+            ("is_fund", c.is_fund.to_string(), n.is_fund.to_string()),
+            (
+                "category",
+                category_name(&c.category),
+                category_name(&n.category),
+            ),
+            (
+                "withdrawn_on",
+                format!("{:?}", c.withdrawn_on),
+                format!("{:?}", n.withdrawn_on),
+            ),
+        ];
+        let changed: Vec<_> = fields
+            .into_iter()
+            .filter(|(_, old, new)| old != new)
+            .collect();
+        if !changed.is_empty() {
+            changes.push(Change::Changed(n.code.clone(), changed));
+        }
+    }
+    changes.sort_by(|a, b| a.code().cmp(b.code()));
+    changes
+}
+
+fn category_name(category: &Option<String>) -> String {
+    category.clone().unwrap_or_else(|| "National".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::module::*;
+
+    const MODULE: &str = include_str!("../../src/iso_currencies.rs");
+    const HISTORIC_MODULE: &str = include_str!("../../src/iso_historic_currencies.rs");
+
+    fn list(currencies: Vec<CurrencyDef>) -> IsoList {
+        IsoList {
+            published: "2030-06-30".to_string(),
+            currencies,
+            unknown_entities: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parse_module() {
+        let currencies = parse_currencies(&CURRENT, MODULE).unwrap();
+        assert_eq!(currencies.len(), 178);
+
+        let eur = currencies.iter().find(|c| c.code == "EUR").unwrap();
+        assert_eq!(eur.name, "Euro");
+        assert_eq!(eur.numeric_code, 978);
+        assert_eq!(eur.minor_units, 2);
+        assert!(eur.countries.contains(&"DE".to_string()));
+        assert!(!eur.is_fund);
+        assert_eq!(eur.category.as_deref(), Some("Supranational"));
+
+        let bov = currencies.iter().find(|c| c.code == "BOV").unwrap();
+        assert_eq!(bov.countries, vec!["BO"]);
+        assert!(bov.is_fund);
+        assert_eq!(bov.category, None);
+        assert_eq!(bov.withdrawn_on, None);
+
+        assert!(parse_currencies(&CURRENT, "//! nothing to see here").is_err());
+    }
+
+    #[test]
+    fn render_round_trip() {
+        let mut currencies = parse_currencies(&CURRENT, MODULE).unwrap();
+        currencies.push(CurrencyDef {
+            code: "PXD".to_string(),
+            name: "Pixie \"Dust\"".to_string(),
+            numeric_code: 1,
+            minor_units: 3,
+            countries: Vec::new(),
+            is_fund: true,
+            category: Some("Testing".to_string()),
+            withdrawn_on: None,
+        });
+        let rendered = render(&CURRENT, MODULE, &list(currencies.clone())).unwrap();
+        assert!(
+            rendered.starts_with("//! ISO 4217 Currency definitions, published on 2030-06-30\n")
+        );
+        assert!(rendered.contains("XTS, XUA, XXX, YER, ZAR, ZMW, ZWG,\n);"));
+        assert!(rendered.ends_with(&MODULE[MODULE.find("/// A [CurrencyMap]").unwrap()..]));
+        assert_eq!(parse_currencies(&CURRENT, &rendered).unwrap(), currencies);
+    }
+
+    #[test]
+    fn parse_historic_module() {
+        let currencies = parse_currencies(&HISTORIC, HISTORIC_MODULE).unwrap();
+        let dem = currencies.iter().find(|c| c.code == "DEM").unwrap();
+        assert_eq!(dem.name, "Deutsche Mark");
+        assert_eq!(dem.numeric_code, 276);
+        assert_eq!(dem.countries, vec!["DE"]);
+        assert_eq!(dem.withdrawn_on.as_deref(), Some("2002-03-01"));
+
+        let xeu = currencies.iter().find(|c| c.code == "XEU").unwrap();
+        assert_eq!(xeu.category.as_deref(), Some("Supranational"));
+
+        assert!(parse_currencies(&HISTORIC, "//! nothing to see here").is_err());
+    }
+
+    #[test]
+    fn render_historic_round_trip() {
+        let mut currencies = parse_currencies(&HISTORIC, HISTORIC_MODULE).unwrap();
+        currencies.retain(|c| c.code != "DEM");
+        let rendered = render(&HISTORIC, HISTORIC_MODULE, &list(currencies.clone())).unwrap();
+        assert!(rendered.contains("published on 2030-06-30\n"));
+        assert!(!rendered.contains("&DEM"));
+        assert!(rendered.contains("&ZWR,\n];"));
+        assert_eq!(parse_currencies(&HISTORIC, &rendered).unwrap(), currencies);
+    }
+
+    #[test]
+    fn diff_currencies() {
+        let current = parse_currencies(&CURRENT, MODULE).unwrap();
+        assert!(diff(&current, &current).is_empty());
+
+        let mut new: Vec<CurrencyDef> = current
+            .iter()
+            .filter(|c| c.code != "ZWG")
+            .cloned()
+            .collect();
+        let usd = new.iter_mut().find(|c| c.code == "USD").unwrap();
+        usd.minor_units = 3;
+        usd.name = "Dollar".to_string();
+        let mut pxd = current[0].clone();
+        pxd.code = "PXD".to_string();
+        new.push(pxd);
+
+        let changes = diff(&current, &new);
+        let summary: Vec<String> = changes.iter().map(|c| c.to_string()).collect();
+        assert_eq!(
+            summary,
+            vec![
+                "added PXD (Afghani)",
+                "changed USD:\n    name: \"US Dollar\" -> \"Dollar\"\n    minor_units: 2 -> 3",
+                "removed ZWG (Zimbabwe Gold)",
+            ]
+        );
+    }
+}